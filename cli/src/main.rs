@@ -0,0 +1,322 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    io::{self, Cursor, Read, Write},
+    process::Command,
+};
+
+use anyhow::{bail, ensure, Context};
+use flate2::read::GzDecoder;
+use readlogs_core::{
+    export,
+    parsers::{self, Content, GZIP_MAGIC, ZIP_MAGIC},
+    LogLevel, Platform, RemoteObject, SearchQuery,
+};
+use strum::IntoEnumIterator;
+use zip::ZipArchive;
+
+const USAGE: &str = "\
+Usage: readlogs-cli [OPTIONS] <FILE | URL | ->
+
+Parses a Signal debug log (a file, a debuglogs.org URL, or `-` for standard input) and prints it
+to standard output. The debug log may be compressed with gzip, or be a zip archive of Signal iOS
+log files. URLs are fetched with `curl`.
+
+Options:
+  --format <json|csv|text>   What to print: the parsed debug log (an object by file name for zip
+                             archives), or its log entries as CSV or as text (default: json)
+  --level <LEVEL>            Only log entries at this level or above, for csv and text
+  --query <TEXT>             Only log entries containing the text, for csv and text
+  --platform <android|ios|desktop>
+                             Platform of the debug log, instead of detecting it
+  -h, --help                 Print this help
+";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Json,
+    Csv,
+    Text,
+}
+
+#[derive(Debug, PartialEq)]
+struct Args {
+    input: String,
+    format: Format,
+    query: SearchQuery,
+    platform: Option<Platform>,
+}
+
+/// Parses the arguments (without the name of the binary); `None` if the help was asked for.
+fn parse_args(args: impl IntoIterator<Item = String>) -> anyhow::Result<Option<Args>> {
+    let mut args = args.into_iter();
+    let mut input = None;
+    let mut format = Format::Json;
+    let mut query = SearchQuery {
+        min_log_level: LogLevel::iter().next().unwrap(),
+        ..Default::default()
+    };
+    let mut platform = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .with_context(|| format!("`{}` needs a value", arg))
+        };
+
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--format" => {
+                format = match value()?.as_str() {
+                    "json" => Format::Json,
+                    "csv" => Format::Csv,
+                    "text" => Format::Text,
+                    other => bail!("unknown format `{}`", other),
+                }
+            }
+            "--level" => {
+                let level = value()?;
+                query.min_log_level = level
+                    .parse()
+                    .ok()
+                    .with_context(|| format!("unknown level `{}`", level))?;
+            }
+            "--query" => query.string = value()?,
+            "--platform" => {
+                let name = value()?;
+                platform = Some(
+                    Platform::iter()
+                        .find(|platform| platform.to_string().eq_ignore_ascii_case(&name))
+                        .with_context(|| format!("unknown platform `{}`", name))?,
+                );
+            }
+            _ if arg.starts_with("--") => bail!("unknown option `{}`", arg),
+            _ => {
+                ensure!(input.is_none(), "more than one debug log given");
+                input = Some(arg);
+            }
+        }
+    }
+
+    Ok(Some(Args {
+        input: input.context("no debug log given")?,
+        format,
+        query,
+        platform,
+    }))
+}
+
+fn fetch(url: &str) -> anyhow::Result<Vec<u8>> {
+    let output = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--location", url])
+        .output()
+        .context("couldn't run `curl`")?;
+
+    ensure!(
+        output.status.success(),
+        "couldn't fetch {}: {}",
+        url,
+        String::from_utf8_lossy(&output.stderr).trim()
+    );
+    Ok(output.stdout)
+}
+
+fn into_text(bytes: Vec<u8>) -> String {
+    match String::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(e) => String::from_utf8_lossy(e.as_bytes()).into_owned(),
+    }
+}
+
+/// The files of the debug log, by name: the one it is, or the log files of a `zip` archive.
+fn files(name: &str, bytes: Vec<u8>) -> anyhow::Result<Vec<(String, String)>> {
+    if bytes.starts_with(&GZIP_MAGIC) {
+        let mut decompressed = vec![];
+        GzDecoder::new(bytes.as_slice())
+            .read_to_end(&mut decompressed)
+            .context("couldn't decompress the debug log")?;
+        return files(name, decompressed);
+    }
+
+    if !bytes.starts_with(&ZIP_MAGIC) {
+        return Ok(vec![(name.to_owned(), into_text(bytes))]);
+    }
+
+    let mut archive = ZipArchive::new(Cursor::new(bytes))
+        .context("couldn't read the debug log file as a `zip`")?;
+    let mut files = vec![];
+
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index)?;
+        if parsers::is_junk_entry(file.name(), file.size()) {
+            continue;
+        }
+
+        let mut bytes = vec![];
+        file.read_to_end(&mut bytes)
+            .with_context(|| format!("couldn't extract {}", file.name()))?;
+        files.push((file.name().to_owned(), into_text(bytes)));
+    }
+
+    ensure!(!files.is_empty(), "no log files in zip");
+    Ok(files)
+}
+
+fn run(args: Args) -> anyhow::Result<String> {
+    let (bytes, mut platform) = if args.input == "-" {
+        let mut bytes = vec![];
+        io::stdin().read_to_end(&mut bytes)?;
+        (bytes, args.platform)
+    } else if args.input.contains("://") || readlogs_core::needs_platform(&args.input) {
+        let reference = RemoteObject::from_url(&args.input, &[], args.platform)
+            .context("failed to parse the debug log URL")?;
+        (
+            fetch(&reference.debuglogs_url())?,
+            Some(reference.platform()),
+        )
+    } else {
+        let bytes =
+            fs::read(&args.input).with_context(|| format!("couldn't read {}", args.input))?;
+        (bytes, args.platform)
+    };
+
+    if bytes.starts_with(&ZIP_MAGIC) {
+        platform = platform.or(Some(Platform::Ios));
+    }
+
+    let mut contents = BTreeMap::new();
+    for (name, text) in files(&args.input, bytes)? {
+        let platform = platform.unwrap_or_else(|| Platform::detect(&text));
+        let content =
+            Content::parse(platform, &text).with_context(|| format!("failed to parse {}", name))?;
+        contents.insert(name, content);
+    }
+
+    let mut output = String::new();
+    match args.format {
+        Format::Json if contents.len() == 1 => {
+            let content = contents.values().next().unwrap();
+            output = export::json(content, &BTreeMap::new())?;
+        }
+        Format::Json => output = serde_json::to_string_pretty(&contents)?,
+        Format::Csv => {
+            for (index, content) in contents.values().enumerate() {
+                let csv = export::csv(content, &args.query);
+                // Only the first file keeps the header row.
+                let rows = match index {
+                    0 => csv.as_str(),
+                    _ => csv.split_once('\n').map_or("", |(_, rows)| rows),
+                };
+                output.push_str(rows);
+            }
+        }
+        Format::Text => {
+            let multiple = contents.len() > 1;
+            for (name, content) in &contents {
+                if multiple {
+                    output.push_str(&format!("==> {} <==\n", name));
+                }
+                output.push_str(&export::filtered_logs_text(content, &args.query));
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            print!("{}", USAGE);
+            return Ok(());
+        }
+        Err(e) => {
+            eprint!("error: {:#}\n\n{}", e, USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    let output = run(args)?;
+    io::stdout().lock().write_all(output.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{write::GzEncoder, Compression};
+    use test_case::test_case;
+
+    use super::*;
+
+    fn args(args: &[&str]) -> anyhow::Result<Option<Args>> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_args_ok_defaults() {
+        let args = args(&["debug.log"]).unwrap().unwrap();
+
+        assert_eq!(args.input, "debug.log");
+        assert_eq!(args.format, Format::Json);
+        assert_eq!(args.query.min_log_level, LogLevel::Trace);
+        assert_eq!(args.platform, None);
+    }
+
+    #[test]
+    fn parse_args_ok_options() {
+        let args = args(&[
+            "--format",
+            "csv",
+            "--level",
+            "warn",
+            "--query",
+            "crash",
+            "--platform",
+            "ios",
+            "-",
+        ])
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(args.input, "-");
+        assert_eq!(args.format, Format::Csv);
+        assert_eq!(args.query.min_log_level, LogLevel::Warn);
+        assert_eq!(args.query.string, "crash");
+        assert_eq!(args.platform, Some(Platform::Ios));
+    }
+
+    #[test_case(&["--help", "debug.log"] => true; "help")]
+    #[test_case(&["debug.log"] => false; "input")]
+    fn parse_args_ok_help(input: &[&str]) -> bool {
+        args(input).unwrap().is_none()
+    }
+
+    #[test_case(&[]; "no input")]
+    #[test_case(&["a.log", "b.log"]; "two inputs")]
+    #[test_case(&["--format", "xml", "debug.log"]; "unknown format")]
+    #[test_case(&["--level", "loud", "debug.log"]; "unknown level")]
+    #[test_case(&["--platform", "windows", "debug.log"]; "unknown platform")]
+    #[test_case(&["--verbose", "debug.log"]; "unknown option")]
+    #[test_case(&["debug.log", "--format"]; "missing value")]
+    fn parse_args_err(input: &[&str]) {
+        assert!(args(input).is_err());
+    }
+
+    #[test]
+    fn files_ok_plain() {
+        let files = files("debug.log", b"line\n".to_vec()).unwrap();
+        assert_eq!(files, [("debug.log".to_owned(), "line\n".to_owned())]);
+    }
+
+    #[test]
+    fn files_ok_gzip() {
+        let mut encoder = GzEncoder::new(vec![], Compression::default());
+        encoder.write_all(b"line\n").unwrap();
+
+        let files = files("debug.log.gz", encoder.finish().unwrap()).unwrap();
+        assert_eq!(files, [("debug.log.gz".to_owned(), "line\n".to_owned())]);
+    }
+}
@@ -0,0 +1,694 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt::Write,
+};
+
+use serde::Serialize;
+use strum::IntoEnumIterator;
+
+use crate::{
+    parsers::{Content, LogEntry, PlatformMetadata, Section},
+    summary::LogSummary,
+    LogLevel, RemoteObject, SearchQuery,
+};
+
+const CSV_HEADER: [&str; 4] = ["Timestamp", "Level", "Source", "Message"];
+
+const SUMMARY_INFO_KEYS: &[(&str, &[&str])] = &[
+    ("Device", &["Model", "Device"]),
+    ("Manufacturer", &["Manufacturer"]),
+    ("OS", &["Android", "OS version", "OS", "Platform"]),
+    ("Locale", &["Locale"]),
+];
+const SUMMARY_TOP_ERRORS_COUNT: usize = 5;
+const SUMMARY_MESSAGE_MAX_LENGTH: usize = 200;
+/// GitHub rejects overly long URLs, and the issue body is part of one (percent-encoded).
+const ISSUE_EXCERPT_MAX_LENGTH: usize = 2000;
+
+#[derive(Serialize)]
+struct JsonExport<'a> {
+    #[serde(flatten)]
+    content: &'a Content,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    annotations: Vec<JsonAnnotation<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonAnnotation<'a> {
+    timestamp: &'a str,
+    message: &'a str,
+    note: &'a str,
+}
+
+/// Serializes the whole parsed `content` as pretty-printed JSON, along with the notes that were
+/// attached to its log entries (see [`annotated_entries`]).
+pub fn json(
+    content: &Content,
+    annotations: &BTreeMap<String, String>,
+) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(&JsonExport {
+        content,
+        annotations: annotated_entries(content, annotations)
+            .into_iter()
+            .map(|(entry, note)| JsonAnnotation {
+                timestamp: &entry.original_timestamp,
+                message: &entry.message,
+                note,
+            })
+            .collect(),
+    })
+}
+
+/// The log entries that have notes in `annotations` (by element ID), in the order they appear in
+/// the log, with their notes.
+pub fn annotated_entries<'a>(
+    content: &'a Content,
+    annotations: &'a BTreeMap<String, String>,
+) -> Vec<(&'a LogEntry, &'a str)> {
+    content
+        .log_entries_with_ids(&annotations.keys().cloned().collect())
+        .into_iter()
+        .filter_map(|entry| Some((entry, annotations.get(&entry.element_id())?.as_str())))
+        .collect()
+}
+
+/// Renders log entries that match the `query` as plain text, preceded by a header for
+/// every section that has at least one matching entry.
+pub fn filtered_logs_text(content: &Content, query: &SearchQuery) -> String {
+    let mut output = String::new();
+
+    for section in content.scoped_log_sections(query) {
+        write_filtered_section(section, query, &mut output);
+    }
+
+    output
+}
+
+/// The original lines of the log entries with the given element IDs, in the order they appear in
+/// the `text` they were parsed from.
+pub fn raw_excerpt(content: &Content, text: &str, entry_ids: &BTreeSet<String>) -> String {
+    content
+        .log_entries_with_ids(entry_ids)
+        .iter()
+        .filter_map(|entry| text.get(entry.source.clone()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders the log entries with the given element IDs as plain text, one per line (with only the
+/// first line of their messages), in the order they appear in the log.
+pub fn bookmarks_text(content: &Content, entry_ids: &BTreeSet<String>) -> String {
+    let mut output = String::new();
+
+    for entry in content.log_entries_with_ids(entry_ids) {
+        let entry = LogEntry {
+            message: entry.message.lines().next().unwrap_or_default().to_owned(),
+            ..entry.clone()
+        };
+
+        writeln!(output, "{}", entry).unwrap();
+    }
+
+    output
+}
+
+/// Renders a short Markdown summary of the debug log (versions, device information, entry
+/// counts per level, the most frequent error messages and the notes in `annotations`), suitable
+/// for forum posts and issues.
+pub fn markdown_summary(
+    remote_object: &RemoteObject,
+    content: &Content,
+    annotations: &BTreeMap<String, String>,
+) -> String {
+    let platform = remote_object.platform();
+    let mut output = String::new();
+
+    writeln!(output, "### Signal {} debug log", platform).unwrap();
+    writeln!(output).unwrap();
+    writeln!(output, "- **Debug log:** {}", remote_object.debuglogs_url()).unwrap();
+
+    if let Some(version) = LogSummary::new(remote_object, content).version {
+        writeln!(output, "- **App version:** {}", version).unwrap();
+    }
+
+    for (label, keys) in SUMMARY_INFO_KEYS {
+        if let Some(value) = content.find_info_value(keys) {
+            writeln!(output, "- **{}:** {}", label, value).unwrap();
+        }
+    }
+
+    writeln!(output).unwrap();
+    writeln!(output, "#### Log entries").unwrap();
+    writeln!(output).unwrap();
+    writeln!(output, "| Level | Count |").unwrap();
+    writeln!(output, "| --- | ---: |").unwrap();
+
+    let mut levels = LogLevel::iter()
+        .filter(|level| level.applicable_to_platform(platform))
+        .collect::<Vec<_>>();
+    levels.reverse();
+
+    for level in levels {
+        let count = content
+            .log_entries()
+            .filter(|entry| entry.level.unwrap_or_default() == level)
+            .count();
+
+        writeln!(output, "| {} | {} |", level, count).unwrap();
+    }
+
+    let top_errors = top_error_messages(content);
+
+    if !top_errors.is_empty() {
+        writeln!(output).unwrap();
+        writeln!(output, "#### Top error messages").unwrap();
+        writeln!(output).unwrap();
+
+        for (i, (message, count)) in top_errors.iter().enumerate() {
+            writeln!(output, "{}. {} ({}×)", i + 1, inline_code(message), count).unwrap();
+        }
+    }
+
+    let annotated = annotated_entries(content, annotations);
+
+    if !annotated.is_empty() {
+        writeln!(output).unwrap();
+        writeln!(output, "#### Notes").unwrap();
+        writeln!(output).unwrap();
+
+        for (entry, note) in annotated {
+            let message = entry.message.lines().next().unwrap_or_default();
+
+            writeln!(output, "- **{}** {}", entry.original_timestamp, note).unwrap();
+            writeln!(output, "  {}", inline_code(message)).unwrap();
+        }
+    }
+
+    output
+}
+
+/// Renders the body of a GitHub issue: the Markdown summary of the debug log, followed by the
+/// `excerpt` of its lines (if any) in a code block. Long excerpts are cut after the last line that
+/// fits.
+pub fn issue_body(
+    remote_object: &RemoteObject,
+    content: &Content,
+    annotations: &BTreeMap<String, String>,
+    excerpt: &str,
+) -> String {
+    let mut output = markdown_summary(remote_object, content, annotations);
+
+    if excerpt.is_empty() {
+        return output;
+    }
+
+    let mut length = 0;
+    let lines = excerpt
+        .lines()
+        .take_while(|line| {
+            length += line.len() + 1;
+            length <= ISSUE_EXCERPT_MAX_LENGTH
+        })
+        .collect::<Vec<_>>();
+
+    writeln!(output).unwrap();
+    writeln!(output, "#### Log excerpt").unwrap();
+    writeln!(output).unwrap();
+    writeln!(output, "```").unwrap();
+    for line in &lines {
+        writeln!(output, "{}", line).unwrap();
+    }
+    writeln!(output, "```").unwrap();
+
+    if lines.len() < excerpt.lines().count() {
+        writeln!(output).unwrap();
+        writeln!(output, "(Excerpt shortened; see the full debug log.)").unwrap();
+    }
+
+    output
+}
+
+/// Formats `text` as Markdown inline code, even if it contains a backtick.
+fn inline_code(text: &str) -> String {
+    if text.contains('`') {
+        format!("`` {} ``", text)
+    } else {
+        format!("`{}`", text)
+    }
+}
+
+/// First lines of error (and more important) messages, grouped and sorted by how often they occur.
+fn top_error_messages(content: &Content) -> Vec<(String, usize)> {
+    let mut indices: HashMap<String, usize> = HashMap::new();
+    let mut messages: Vec<(String, usize)> = vec![];
+
+    for entry in content
+        .log_entries()
+        .filter(|entry| entry.level.unwrap_or_default() >= LogLevel::Error)
+    {
+        let message = entry
+            .message
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .take(SUMMARY_MESSAGE_MAX_LENGTH)
+            .collect::<String>();
+
+        match indices.get(&message) {
+            Some(&i) => messages[i].1 += 1,
+            None => {
+                indices.insert(message.clone(), messages.len());
+                messages.push((message, 1));
+            }
+        }
+    }
+
+    messages.sort_by(|(_, a), (_, b)| b.cmp(a));
+    messages.truncate(SUMMARY_TOP_ERRORS_COUNT);
+    messages
+}
+
+/// Renders log entries that match the `query` as CSV with a header row.
+pub fn csv(content: &Content, query: &SearchQuery) -> String {
+    let mut output = String::new();
+
+    write_csv_row(&mut output, &CSV_HEADER);
+
+    for section in content.scoped_log_sections(query) {
+        write_csv_section(section, query, &mut output);
+    }
+
+    output
+}
+
+fn write_csv_section(section: &Section<LogEntry>, query: &SearchQuery, output: &mut String) {
+    for entry in section.filtered(query) {
+        let source = match &entry.meta {
+            PlatformMetadata::AndroidLogcat { tag, .. }
+            | PlatformMetadata::AndroidLogger { tag, .. } => tag.clone(),
+            PlatformMetadata::Ios(Some(meta)) => format!("{}:{}", meta.file, meta.line),
+            PlatformMetadata::Desktop(Some(meta)) => meta.process.clone(),
+            PlatformMetadata::Ios(None) | PlatformMetadata::Desktop(None) => String::new(),
+        };
+
+        write_csv_row(
+            output,
+            &[
+                &entry.original_timestamp,
+                &entry
+                    .level
+                    .map(|level| level.to_string())
+                    .unwrap_or_default(),
+                &source,
+                &entry.message,
+            ],
+        );
+    }
+}
+
+fn write_csv_row(output: &mut String, fields: &[&str]) {
+    let fields = fields
+        .iter()
+        .map(|field| {
+            if field.contains(&[',', '"', '\n', '\r'][..]) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    output.push_str(&fields.join(","));
+    output.push_str("\r\n");
+}
+
+fn write_filtered_section(section: &Section<LogEntry>, query: &SearchQuery, output: &mut String) {
+    let mut entries = section.filtered(query).peekable();
+
+    if entries.peek().is_some() {
+        if !output.is_empty() {
+            output.push('\n');
+        }
+
+        writeln!(output, "========= {} =========", section.name).unwrap();
+
+        for entry in entries {
+            writeln!(output, "{}", entry).unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        parsers::{InfoEntry, Value},
+        Platform,
+    };
+
+    use super::*;
+
+    fn test_entry(level: LogLevel, message: &str) -> LogEntry {
+        LogEntry {
+            timestamp: Utc.ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789),
+            original_timestamp: "1234-01-23 12:34:56.789 UTC".to_owned(),
+            level: Some(level),
+            meta: PlatformMetadata::AndroidLogger {
+                version: "1.23.4".to_owned(),
+                thread_id: "5678".to_owned(),
+                tag: "abc".to_owned(),
+            },
+            ..LogEntry::test(message, 0)
+        }
+    }
+
+    #[test]
+    fn json_ok() {
+        let content = Content {
+            information: vec![],
+            logs: vec![Section {
+                name: "LOGGER".to_owned(),
+                content: vec![test_entry(LogLevel::Error, "Something failed")],
+                subsections: vec![],
+            }],
+        };
+
+        let value: serde_json::Value =
+            serde_json::from_str(&json(&content, &BTreeMap::new()).unwrap()).unwrap();
+
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "information": [],
+                "logs": [{
+                    "name": "LOGGER",
+                    "content": [{
+                        "time": "1234-01-23T12:34:56.789Z",
+                        "timestamp": "1234-01-23 12:34:56.789 UTC",
+                        "level": "Error",
+                        "meta": {
+                            "AndroidLogger": {
+                                "version": "1.23.4",
+                                "thread_id": "5678",
+                                "tag": "abc",
+                            },
+                        },
+                        "message": "Something failed",
+                        "source": { "start": 0, "end": 0 },
+                    }],
+                    "subsections": [],
+                }],
+            })
+        );
+
+        let value: serde_json::Value = serde_json::from_str(
+            &json(
+                &content,
+                &BTreeMap::from([("entry-0".to_owned(), "Look here".to_owned())]),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            value["annotations"],
+            serde_json::json!([{
+                "timestamp": "1234-01-23 12:34:56.789 UTC",
+                "message": "Something failed",
+                "note": "Look here",
+            }])
+        );
+    }
+
+    #[test]
+    fn csv_ok() {
+        let content = Content {
+            information: vec![],
+            logs: vec![Section {
+                name: "LOGGER".to_owned(),
+                content: vec![
+                    test_entry(LogLevel::Debug, "Too verbose"),
+                    test_entry(LogLevel::Error, "Failed, \"quoted\"\nsecond line"),
+                ],
+                subsections: vec![],
+            }],
+        };
+
+        let query = SearchQuery {
+            min_log_level: LogLevel::Info,
+            string: String::new(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            csv(&content, &query),
+            "Timestamp,Level,Source,Message\r\n\
+             1234-01-23 12:34:56.789 UTC,Error,abc,\"Failed, \"\"quoted\"\"\nsecond line\"\r\n"
+        );
+    }
+
+    #[test]
+    fn markdown_summary_ok() {
+        let content = Content {
+            information: vec![Section {
+                name: "SYSINFO".to_owned(),
+                content: vec![
+                    InfoEntry::KeyValue("Model".to_owned(), Value::Generic("Pixel".to_owned())),
+                    InfoEntry::KeyValue("Android".to_owned(), Value::Generic("12".to_owned())),
+                ],
+                subsections: vec![],
+            }],
+            logs: vec![Section {
+                name: "LOGGER".to_owned(),
+                content: vec![
+                    LogEntry {
+                        source: 5..5,
+                        ..test_entry(LogLevel::Error, "Rare failure")
+                    },
+                    test_entry(LogLevel::Warn, "Just a warning"),
+                    test_entry(LogLevel::Error, "Common failure\nwith details"),
+                    test_entry(LogLevel::Fatal, "Common failure"),
+                ],
+                subsections: vec![],
+            }],
+        };
+
+        let remote_object = RemoteObject::new_unchecked(
+            Platform::Android,
+            Some("1.23.4".to_owned()),
+            "0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123",
+        );
+
+        assert_eq!(
+            markdown_summary(
+                &remote_object,
+                &content,
+                &BTreeMap::from([("entry-5".to_owned(), "Starts here".to_owned())])
+            ),
+            "### Signal Android debug log\n\
+             \n\
+             - **Debug log:** https://debuglogs.org/android/1.23.4/0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123\n\
+             - **App version:** 1.23.4\n\
+             - **Device:** Pixel\n\
+             - **OS:** 12\n\
+             \n\
+             #### Log entries\n\
+             \n\
+             | Level | Count |\n\
+             | --- | ---: |\n\
+             | Fatal | 1 |\n\
+             | Error | 2 |\n\
+             | Warn | 1 |\n\
+             | Info | 0 |\n\
+             | Debug | 0 |\n\
+             | Verbose | 0 |\n\
+             \n\
+             #### Top error messages\n\
+             \n\
+             1. `Common failure` (2×)\n\
+             2. `Rare failure` (1×)\n\
+             \n\
+             #### Notes\n\
+             \n\
+             - **1234-01-23 12:34:56.789 UTC** Starts here\n  \
+             `Rare failure`\n"
+        );
+    }
+
+    #[test]
+    fn raw_excerpt_ok() {
+        let text = "first\nsecond\nthird";
+        let entry = |start, end| LogEntry {
+            source: start..end,
+            ..test_entry(LogLevel::Info, "")
+        };
+
+        let content = Content {
+            information: vec![],
+            logs: vec![Section {
+                name: "LOGS".to_owned(),
+                content: vec![entry(13, 18), entry(0, 5), entry(6, 12)],
+                subsections: vec![],
+            }],
+        };
+
+        assert_eq!(
+            raw_excerpt(
+                &content,
+                text,
+                &BTreeSet::from(["entry-13".to_owned(), "entry-0".to_owned()])
+            ),
+            "first\nthird"
+        );
+    }
+
+    #[test]
+    fn issue_body_ok() {
+        let content = Content {
+            information: vec![],
+            logs: vec![],
+        };
+
+        let remote_object = RemoteObject::new_unchecked(
+            Platform::Desktop,
+            Some("5.0.0".to_owned()),
+            "0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123",
+        );
+
+        let annotations = BTreeMap::new();
+        let summary = markdown_summary(&remote_object, &content, &annotations);
+
+        assert_eq!(
+            issue_body(&remote_object, &content, &annotations, ""),
+            summary
+        );
+        assert_eq!(
+            issue_body(&remote_object, &content, &annotations, "first\nsecond"),
+            format!("{}\n#### Log excerpt\n\n```\nfirst\nsecond\n```\n", summary)
+        );
+
+        let long_excerpt = "0123456789\n".repeat(ISSUE_EXCERPT_MAX_LENGTH / 10);
+        assert!(
+            issue_body(&remote_object, &content, &annotations, &long_excerpt)
+                .ends_with("```\n\n(Excerpt shortened; see the full debug log.)\n")
+        );
+    }
+
+    #[test]
+    fn bookmarks_text_ok() {
+        let entry = |start: usize, level, message| LogEntry {
+            source: start..start,
+            ..test_entry(level, message)
+        };
+
+        let content = Content {
+            information: vec![],
+            logs: vec![Section {
+                name: "LOGGER".to_owned(),
+                content: vec![
+                    entry(20, LogLevel::Error, "Failed\nwith details"),
+                    entry(0, LogLevel::Info, "Started"),
+                    entry(10, LogLevel::Debug, "Unrelated"),
+                ],
+                subsections: vec![],
+            }],
+        };
+
+        assert_eq!(
+            bookmarks_text(
+                &content,
+                &BTreeSet::from(["entry-20".to_owned(), "entry-0".to_owned()])
+            ),
+            "1234-01-23 12:34:56.789 UTC INFO [1.23.4] [5678] abc Started\n\
+             1234-01-23 12:34:56.789 UTC ERROR [1.23.4] [5678] abc Failed\n"
+        );
+    }
+
+    #[test]
+    fn filtered_logs_text_ok() {
+        let content = Content {
+            information: vec![],
+            logs: vec![
+                Section {
+                    name: "LOGCAT".to_owned(),
+                    content: vec![],
+                    subsections: vec![Section {
+                        name: "MAIN".to_owned(),
+                        content: vec![test_entry(LogLevel::Info, "Nothing to see")],
+                        subsections: vec![],
+                    }],
+                },
+                Section {
+                    name: "LOGGER".to_owned(),
+                    content: vec![
+                        test_entry(LogLevel::Debug, "Too verbose"),
+                        test_entry(LogLevel::Error, "Something failed"),
+                        test_entry(LogLevel::Warn, "Something else failed"),
+                    ],
+                    subsections: vec![],
+                },
+            ],
+        };
+
+        let query = SearchQuery {
+            min_log_level: LogLevel::Warn,
+            string: "FAILED".to_owned(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            filtered_logs_text(&content, &query),
+            "========= LOGGER =========\n\
+             1234-01-23 12:34:56.789 UTC ERROR [1.23.4] [5678] abc Something failed\n\
+             1234-01-23 12:34:56.789 UTC WARN [1.23.4] [5678] abc Something else failed\n"
+        );
+    }
+
+    #[test]
+    fn filtered_logs_text_ok_tag_and_thread() {
+        let other_tag = LogEntry {
+            meta: PlatformMetadata::AndroidLogger {
+                version: "1.23.4".to_owned(),
+                thread_id: "5678".to_owned(),
+                tag: "def".to_owned(),
+            },
+            ..test_entry(LogLevel::Error, "Other tag")
+        };
+        let other_thread = LogEntry {
+            meta: PlatformMetadata::AndroidLogger {
+                version: "1.23.4".to_owned(),
+                thread_id: "1".to_owned(),
+                tag: "abc".to_owned(),
+            },
+            ..test_entry(LogLevel::Error, "Other thread")
+        };
+
+        let content = Content {
+            information: vec![],
+            logs: vec![Section {
+                name: "LOGGER".to_owned(),
+                content: vec![
+                    other_tag,
+                    other_thread,
+                    test_entry(LogLevel::Error, "Matching"),
+                ],
+                subsections: vec![],
+            }],
+        };
+
+        let query = SearchQuery {
+            min_log_level: LogLevel::Error,
+            tag: "ABC".to_owned(),
+            thread: "5678".to_owned(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            filtered_logs_text(&content, &query),
+            "========= LOGGER =========\n\
+             1234-01-23 12:34:56.789 UTC ERROR [1.23.4] [5678] abc Matching\n"
+        );
+    }
+}
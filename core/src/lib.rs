@@ -0,0 +1,19 @@
+//! The parsers of Signal debug logs and the data they produce, without any of the app's UI, for
+//! the app and the command line tool alike.
+
+pub mod export;
+mod log_level;
+pub mod parsers;
+mod platform;
+pub mod post_processing;
+pub mod redaction;
+mod remote_object;
+mod search_query;
+pub mod summary;
+mod utils;
+
+pub use log_level::LogLevel;
+pub use platform::Platform;
+pub use remote_object::{needs_platform, remote_object, RemoteObject};
+pub use search_query::SearchQuery;
+pub use utils::*;
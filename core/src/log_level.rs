@@ -0,0 +1,63 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use strum_macros::{EnumIter, EnumString};
+
+use crate::Platform::{self, *};
+use LogLevel::*;
+
+#[derive(
+    Debug,
+    Display,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Hash,
+    EnumString,
+    EnumIter,
+    Serialize,
+    Deserialize,
+)]
+#[strum(ascii_case_insensitive)]
+pub enum LogLevel {
+    /// Desktop
+    Trace,
+    /// Android, iOS (see `parsers::ios::LEVELS` for the emoji)
+    // `serialize = "verbose"` is included for parsing of `<select>`'s option.
+    #[strum(serialize = "V", serialize = "verbose")]
+    Verbose,
+    /// Android, iOS, Desktop
+    #[strum(serialize = "D", serialize = "debug")]
+    Debug,
+    /// Android, iOS, Desktop
+    #[strum(serialize = "I", serialize = "info")]
+    Info,
+    /// Android, iOS, Desktop
+    #[strum(serialize = "W", serialize = "warn")]
+    Warn,
+    /// Android, iOS, Desktop
+    #[strum(serialize = "E", serialize = "error")]
+    Error,
+    /// Android, Desktop
+    #[strum(serialize = "F", serialize = "fatal")]
+    Fatal,
+}
+
+impl Default for LogLevel {
+    fn default() -> Self {
+        Info
+    }
+}
+
+impl LogLevel {
+    pub fn applicable_to_platform(&self, platform: Platform) -> bool {
+        matches!(
+            (self, platform),
+            (Trace, Desktop)
+                | (Verbose, Android | Ios)
+                | (Debug | Info | Warn | Error, Android | Ios | Desktop)
+                | (Fatal, Android | Desktop)
+        )
+    }
+}
@@ -1,8 +1,8 @@
 use nom::{
     branch::alt,
-    bytes::complete::{is_a, is_not, tag, take_until},
+    bytes::complete::{is_not, tag, take_until},
     character::complete::{self, digit1, multispace0, newline, space0, space1},
-    combinator::{map, not, opt, peek, success, value, verify},
+    combinator::{map, map_res, not, opt, peek, success, value, verify},
     multi::{count, many0, many1, separated_list1},
     sequence::{delimited, pair, preceded, separated_pair, tuple},
     IResult,
@@ -12,6 +12,8 @@ use crate::{parsers::*, post_processing, remote_object};
 
 const LOGCAT_SECTION_NAME: &str = "LOGCAT";
 const LOGGER_SECTION_NAME: &str = "LOGGER";
+/// Key of the time the log was captured at, in milliseconds since the Unix epoch.
+const CAPTURE_TIME_KEY: &str = "Time";
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum SectionLevel {
@@ -19,6 +21,11 @@ enum SectionLevel {
     Sub,
 }
 
+/// Either a single letter (e.g. `V`) or a spelled-out level (e.g. `VERBOSE`).
+fn level(input: &str) -> IResult<&str, LogLevel> {
+    map_res(is_not(" "), str::parse)(input)
+}
+
 fn subsection_header(input: &str) -> IResult<&str, &str> {
     preceded(pair(many1(tag("-")), tag(" ")), is_not("\n"))(input)
 }
@@ -30,20 +37,50 @@ fn thread(input: &str) -> IResult<&str, InfoEntry> {
     )(input)
 }
 
-fn jobs_inline_section(input: &str) -> IResult<&str, Section<InfoEntry>> {
+/// Lines of pipe-separated `key: value` pairs (e.g. the jobs and constraints dumps), as a table
+/// with one column per key.
+fn pipe_table(input: &str) -> IResult<&str, GenericTable> {
+    let pair = separated_pair(
+        map(is_not(":|\n"), str::trim),
+        tag(":"),
+        map(opt(is_not("|\n")), |value: Option<&str>| {
+            value.unwrap_or_default().trim()
+        }),
+    );
+
+    let row = verify(
+        separated_list1(tag("|"), pair),
+        |pairs: &Vec<(&str, &str)>| pairs.len() > 1,
+    );
+
     map(
-        separated_pair(
-            preceded(alt((tag("id: "), tag("jobSpecId: "))), is_not(" ")),
-            common::ws(tag("|")),
-            separated_list1(
-                common::ws(tag("|")),
-                common::ws(common::key_maybe_enabled_value),
-            ),
-        ),
-        |(name, pairs)| Section {
-            name: name.to_owned(),
-            content: pairs,
-            subsections: vec![],
+        many1(terminated(row, opt(newline))),
+        |rows: Vec<Vec<(&str, &str)>>| {
+            let mut header: Vec<String> = vec![];
+
+            for (key, _) in rows.iter().flatten() {
+                if !header.iter().any(|column| column == key) {
+                    header.push((*key).to_owned());
+                }
+            }
+
+            let rows = rows
+                .iter()
+                .map(|pairs| {
+                    header
+                        .iter()
+                        .map(|column| {
+                            pairs
+                                .iter()
+                                .find(|(key, _)| key == column)
+                                .map(|(_, value)| (*value).to_owned())
+                                .unwrap_or_default()
+                        })
+                        .collect()
+                })
+                .collect();
+
+            GenericTable { header, rows }
         },
     )(input)
 }
@@ -223,27 +260,25 @@ fn info_section(depth: SectionLevel) -> impl FnMut(&str) -> IResult<&str, Sectio
         )(input)?;
 
         let (remainder, content) = alt((
-            preceded(
-                peek(not(jobs_inline_section)),
-                common::multispaced0(alt((
-                    map(generic_table, |table| vec![InfoEntry::GenericTable(table)]),
-                    many1(common::multispaced0(common::key_maybe_enabled_value)),
-                    many1(common::multispaced0(thread)),
-                    map(remote_object, |ro| vec![InfoEntry::RemoteObject(ro)]),
-                    value(vec![InfoEntry::ExplicitNone], tag("None")),
-                    many1(common::multispaced0(map(
-                        preceded(
-                            peek(not(alt((
-                                section_with_indented_subsections(LocalMetrics),
-                                section_with_indented_subsections(NotificationProfiles),
-                                // section_with_indented_subsections(OwnershipInfo), // TODO: Investigate, it causes parsers::android::tests::info_section_ok::blocked_threads failure
-                            )))),
-                            is_not("\n-="),
-                        ),
-                        |s: &str| InfoEntry::Generic(s.to_owned()),
-                    ))),
+            common::multispaced0(alt((
+                map(generic_table, |table| vec![InfoEntry::GenericTable(table)]),
+                map(pipe_table, |table| vec![InfoEntry::GenericTable(table)]),
+                many1(common::multispaced0(common::key_maybe_enabled_value)),
+                many1(common::multispaced0(thread)),
+                map(remote_object, |ro| vec![InfoEntry::RemoteObject(ro)]),
+                value(vec![InfoEntry::ExplicitNone], tag("None")),
+                many1(common::multispaced0(map(
+                    preceded(
+                        peek(not(alt((
+                            section_with_indented_subsections(LocalMetrics),
+                            section_with_indented_subsections(NotificationProfiles),
+                            // section_with_indented_subsections(OwnershipInfo), // TODO: Investigate, it causes parsers::android::tests::info_section_ok::blocked_threads failure
+                        )))),
+                        is_not("\n-="),
+                    ),
+                    |s: &str| InfoEntry::Generic(s.to_owned()),
                 ))),
-            ),
+            ))),
             success(vec![]),
         ))(remainder)?;
 
@@ -267,7 +302,7 @@ fn info_section(depth: SectionLevel) -> impl FnMut(&str) -> IResult<&str, Sectio
                 ),
                 success(vec![]),
             ))(remainder)?,
-            SectionLevel::Sub => many0(common::multispaced0(jobs_inline_section))(remainder)?,
+            SectionLevel::Sub => (remainder, vec![]),
         };
 
         Ok((
@@ -291,7 +326,7 @@ fn logcat_entry(year: i32) -> impl FnMut(&str) -> IResult<&str, LogEntry> {
                 space0,
                 is_not(" "),
                 space0,
-                is_a("VDIWEF"),
+                level,
                 space0,
                 take_until(": "),
                 tag(": "),
@@ -299,40 +334,67 @@ fn logcat_entry(year: i32) -> impl FnMut(&str) -> IResult<&str, LogEntry> {
                 alt((is_not("\n"), success(""))),
             )),
             |(dt, _, process_id, _, thread_id, _, level, _, tag, _, _, message)| LogEntry {
-                timestamp: dt.to_string(),
-                level: Some(level.parse().unwrap()),
+                timestamp: DateTime::from_utc(dt, Utc),
+                original_timestamp: dt.to_string(),
+                level: Some(level),
                 meta: PlatformMetadata::AndroidLogcat {
                     process_id: process_id.to_owned(),
                     thread_id: thread_id.to_owned(),
                     tag: tag.trim().to_owned(),
                 },
                 message: message.to_owned(),
+                source: Default::default(),
+                sensitive: vec![],
+                origin: None,
             },
         )(input)
     }
 }
 
-fn logcat_section(year: i32) -> impl FnMut(&str) -> IResult<&str, Section<LogEntry>> {
+fn logcat_entries<'a>(
+    origin: &'a str,
+    year: i32,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<LogEntry>> {
+    move |input| {
+        map(
+            many0(common::multispaced0(common::with_source(
+                origin,
+                logcat_entry(year),
+            ))),
+            post_processing::collapse_log_entries,
+        )(input)
+    }
+}
+
+/// The logcat section, with a subsection for every buffer (e.g. `--------- beginning of main`);
+/// entries before the first buffer marker are in the section itself.
+fn logcat_section<'a>(
+    origin: &'a str,
+    year: i32,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Section<LogEntry>> {
     move |input| {
         preceded(
             common::multispaced0(verify(common::section_header, |name: &str| {
                 name == LOGCAT_SECTION_NAME
             })),
             map(
-                many0(map(
-                    pair(
-                        common::multispaced0(subsection_header),
-                        many0(common::multispaced0(logcat_entry(year))),
-                    ),
-                    |(name, content)| Section {
-                        name: name.to_owned(),
-                        content: post_processing::collapse_log_entries(content),
-                        subsections: vec![],
-                    },
-                )),
-                |subsections| Section {
+                pair(
+                    logcat_entries(origin, year),
+                    many0(map(
+                        pair(
+                            common::multispaced0(subsection_header),
+                            logcat_entries(origin, year),
+                        ),
+                        |(name, content)| Section {
+                            name: name.to_owned(),
+                            content,
+                            subsections: vec![],
+                        },
+                    )),
+                ),
+                |(content, subsections)| Section {
                     name: LOGCAT_SECTION_NAME.to_owned(),
-                    content: vec![],
+                    content,
                     subsections,
                 },
             ),
@@ -340,7 +402,27 @@ fn logcat_section(year: i32) -> impl FnMut(&str) -> IResult<&str, Section<LogEnt
     }
 }
 
-fn logger_metadata(input: &str) -> IResult<&str, (PlatformMetadata, String, LogLevel)> {
+/// Logcat timestamps have no year, so the year the log was captured in (according to the
+/// `information`) is assumed, or else the current one.
+fn logcat_year(information: &[Section<InfoEntry>]) -> i32 {
+    information
+        .iter()
+        .flat_map(Section::iter_all)
+        .find_map(|entry| match entry {
+            InfoEntry::KeyValue(key, Value::Generic(millis)) if key == CAPTURE_TIME_KEY => {
+                millis.parse().ok()
+            }
+            _ => None,
+        })
+        .and_then(|millis| Utc.timestamp_millis_opt(millis).single())
+        .unwrap_or_else(Utc::now)
+        .year()
+}
+
+/// When a log entry was written, in UTC and as it's shown.
+type Timestamp = (DateTime<Utc>, String);
+
+fn logger_metadata(input: &str) -> IResult<&str, (PlatformMetadata, Timestamp, LogLevel)> {
     enum LoggerTimezone<'a> {
         Parsed(FixedOffset),
         Unparsed(&'a str),
@@ -370,7 +452,7 @@ fn logger_metadata(input: &str) -> IResult<&str, (PlatformMetadata, String, LogL
             space0,
             logger_timezone,
             space0,
-            is_not(" "),
+            level,
             space0,
             take_until(": "),
             tag(": "),
@@ -383,10 +465,15 @@ fn logger_metadata(input: &str) -> IResult<&str, (PlatformMetadata, String, LogL
                     tag: tag.trim().to_owned(),
                 },
                 match tz {
-                    LoggerTimezone::Parsed(tz) => tz.from_local_datetime(&dt).unwrap().to_string(),
-                    LoggerTimezone::Unparsed(s) => dt.to_string() + " " + s,
+                    LoggerTimezone::Parsed(tz) => {
+                        let dt = tz.from_local_datetime(&dt).unwrap();
+                        (dt.with_timezone(&Utc), dt.to_string())
+                    }
+                    LoggerTimezone::Unparsed(s) => {
+                        (DateTime::from_utc(dt, Utc), dt.to_string() + " " + s)
+                    }
                 },
-                level.parse().unwrap(),
+                level,
             )
         },
     )(input)
@@ -395,24 +482,39 @@ fn logger_metadata(input: &str) -> IResult<&str, (PlatformMetadata, String, LogL
 fn logger_entry(input: &str) -> IResult<&str, LogEntry> {
     map(
         separated_pair(logger_metadata, space0, common::message(logger_metadata)),
-        |((meta, timestamp, level), message)| LogEntry {
+        |((meta, (timestamp, original_timestamp), level), message)| LogEntry {
             timestamp,
+            original_timestamp,
             level: Some(level),
             meta,
             message,
+            source: Default::default(),
+            sensitive: vec![],
+            origin: None,
         },
     )(input)
 }
 
 pub fn content(input: &str) -> IResult<&str, Content> {
-    let (remainder, (information, logcat_section, _, mut logger_entries)) = tuple((
-        preceded(multispace0, many0(info_section(SectionLevel::Base))),
-        preceded(multispace0, logcat_section(Utc::today().year())), // TODO: year...
+    let (remainder, information) =
+        preceded(multispace0, many0(info_section(SectionLevel::Base)))(input)?;
+
+    let (remainder, (logcat_section, _, mut logger_entries)) = tuple((
+        preceded(
+            multispace0,
+            logcat_section(input, logcat_year(&information)),
+        ),
         verify(common::section_header, |name: &str| {
             name == LOGGER_SECTION_NAME
         }),
-        preceded(multispace0, many0(common::multispaced0(logger_entry))),
-    ))(input)?;
+        preceded(
+            multispace0,
+            many0(common::multispaced0(common::with_source(
+                input,
+                logger_entry,
+            ))),
+        ),
+    ))(remainder)?;
 
     logger_entries = post_processing::collapse_log_entries(logger_entries);
 
@@ -439,40 +541,59 @@ mod tests {
     use super::*;
     use crate::parsing_test;
 
+    #[test_case("V" => LogLevel::Verbose; "verbose")]
+    #[test_case("VERBOSE" => LogLevel::Verbose; "verbose spelled out")]
+    #[test_case("F" => LogLevel::Fatal; "fatal")]
+    fn level_ok(input: &str) -> LogLevel {
+        parsing_test(level, input)
+    }
+
+    #[test]
+    fn level_err_unknown() {
+        assert!(level("X").is_err());
+    }
+
     #[test_case("-- Abc" => "Abc"; "basic")]
     #[test_case("--------- Long line" => "Long line"; "long")]
     fn subsection_header_ok(input: &str) -> &str {
         parsing_test(subsection_header, input)
     }
 
+    fn test_table(header: &[&str], rows: &[&[&str]]) -> GenericTable {
+        let to_owned = |items: &[&str]| items.iter().map(|s| (*s).to_owned()).collect::<Vec<_>>();
+
+        GenericTable {
+            header: to_owned(header),
+            rows: rows.iter().map(|row| to_owned(row)).collect(),
+        }
+    }
+
     #[test_case(
         "id: JOB::abcd1234-efgh-5678-ijkl-9012mnop1234 | a: TestJob | b: _test_value_ | number: 123 | negative: -1"
-        => Section {
-            name: "JOB::abcd1234-efgh-5678-ijkl-9012mnop1234".to_owned(),
-            content: vec![
-                InfoEntry::KeyValue("a".to_owned(), Value::Generic("TestJob".to_owned())),
-                InfoEntry::KeyValue("b".to_owned(), Value::Generic("_test_value_".to_owned())),
-                InfoEntry::KeyValue("number".to_owned(), Value::Generic("123".to_owned())),
-                InfoEntry::KeyValue("negative".to_owned(), Value::Generic("-1".to_owned())),
-            ],
-            subsections: vec![],
-        };
+        => test_table(
+            &["id", "a", "b", "number", "negative"],
+            &[&["JOB::abcd1234-efgh-5678-ijkl-9012mnop1234", "TestJob", "_test_value_", "123", "-1"]],
+        );
         "job"
     )]
     #[test_case(
-        "jobSpecId: JOB::abcd1234-efgh-5678-ijkl-9012mnop1234 | a: TestConstraint | anotherValue: false"
-        => Section {
-            name: "JOB::abcd1234-efgh-5678-ijkl-9012mnop1234".to_owned(),
-            content: vec![
-                InfoEntry::KeyValue("a".to_owned(), Value::Generic("TestConstraint".to_owned())),
-                InfoEntry::KeyValue("anotherValue".to_owned(), Value::Generic("false".to_owned())),
+        "jobSpecId: JOB::1 | a: TestConstraint | anotherValue: false\njobSpecId: JOB::2 | a: | extra: 1"
+        => test_table(
+            &["jobSpecId", "a", "anotherValue", "extra"],
+            &[
+                &["JOB::1", "TestConstraint", "false", ""],
+                &["JOB::2", "", "", "1"],
             ],
-            subsections: vec![],
-        };
-        "constraint"
+        );
+        "constraints, missing and empty values"
     )]
-    fn jobs_inline_section_ok(input: &str) -> Section<InfoEntry> {
-        parsing_test(jobs_inline_section, input)
+    fn pipe_table_ok(input: &str) -> GenericTable {
+        parsing_test(pipe_table, input)
+    }
+
+    #[test]
+    fn pipe_table_err_single_pair() {
+        assert!(pipe_table("Key: value").is_err());
     }
 
     #[test_case(
@@ -495,29 +616,19 @@ mod tests {
             subsections: vec![
                 Section {
                     name: "Jobs".to_owned(),
-                    content: vec![],
-                    subsections: vec![Section {
-                        name: "JOB::abcd1234-efgh-5678-ijkl-9012mnop1234".to_owned(),
-                        content: vec![
-                            InfoEntry::KeyValue("a".to_owned(), Value::Generic("TestJob".to_owned())),
-                            InfoEntry::KeyValue("b".to_owned(), Value::Generic("_test_value_".to_owned())),
-                            InfoEntry::KeyValue("number".to_owned(), Value::Generic("123".to_owned())),
-                            InfoEntry::KeyValue("negative".to_owned(), Value::Generic("-1".to_owned())),
-                        ],
-                        subsections: vec![],
-                    }],
+                    content: vec![InfoEntry::GenericTable(test_table(
+                        &["id", "a", "b", "number", "negative"],
+                        &[&["JOB::abcd1234-efgh-5678-ijkl-9012mnop1234", "TestJob", "_test_value_", "123", "-1"]],
+                    ))],
+                    subsections: vec![],
                 },
                 Section {
                     name: "Constraints".to_owned(),
-                    content: vec![],
-                    subsections: vec![Section {
-                        name: "JOB::abcd1234-efgh-5678-ijkl-9012mnop1234".to_owned(),
-                        content: vec![
-                            InfoEntry::KeyValue("a".to_owned(), Value::Generic("TestConstraint".to_owned())),
-                            InfoEntry::KeyValue("anotherValue".to_owned(), Value::Generic("false".to_owned())),
-                        ],
-                        subsections: vec![],
-                    }],
+                    content: vec![InfoEntry::GenericTable(test_table(
+                        &["jobSpecId", "a", "anotherValue"],
+                        &[&["JOB::abcd1234-efgh-5678-ijkl-9012mnop1234", "TestConstraint", "false"]],
+                    ))],
+                    subsections: vec![],
                 },
                 Section {
                     name: "Dependencies".to_owned(),
@@ -682,7 +793,7 @@ mod tests {
                                 InfoEntry::KeyValue("schedule enabled".to_owned(), Value::Generic("false".to_owned())),
                                 InfoEntry::KeyValue("schedule start".to_owned(), Value::Generic("900".to_owned())),
                                 InfoEntry::KeyValue("schedule end".to_owned(), Value::Generic("2100".to_owned())),
-                                InfoEntry::KeyValue("schedule days".to_owned(), Value::Generic("[MONDAY, TUESDAY, WEDNESDAY, THURSDAY, FRIDAY]".to_owned())),
+                                InfoEntry::KeyValue("schedule days".to_owned(), Value::List(["MONDAY", "TUESDAY", "WEDNESDAY", "THURSDAY", "FRIDAY"].iter().map(|day| (*day).to_owned()).collect())),
                             ],
                             subsections: vec![],
                         }
@@ -860,21 +971,73 @@ mod tests {
     }
 
     #[test_case("01-23 12:34:56.789 12345 12367 I abc: Log message" => LogEntry {
-        timestamp: NaiveDate::from_ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789).to_string(),
+        timestamp: Utc.ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789),
+        original_timestamp: NaiveDate::from_ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789).to_string(),
         level: Some(LogLevel::Info),
         meta: PlatformMetadata::AndroidLogcat { process_id: "12345".to_owned(), thread_id: "12367".to_owned(), tag: "abc".to_owned() },
         message: "Log message".to_owned(),
+        source: 0..0,
+        sensitive: vec![],
+        origin: None,
     }; "basic")]
     #[test_case("01-23 12:34:56.789 12345 12367 I abc: " => LogEntry {
-        timestamp: NaiveDate::from_ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789).to_string(),
+        timestamp: Utc.ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789),
+        original_timestamp: NaiveDate::from_ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789).to_string(),
         level: Some(LogLevel::Info),
         meta: PlatformMetadata::AndroidLogcat { process_id: "12345".to_owned(), thread_id: "12367".to_owned(), tag: "abc".to_owned() },
         message: "".to_owned(),
+        source: 0..0,
+        sensitive: vec![],
+        origin: None,
     }; "no message")]
+    #[test_case("01-23 12:34:56.789 12345 12367 V abc: Verbose message" => LogEntry {
+        timestamp: Utc.ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789),
+        original_timestamp: NaiveDate::from_ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789).to_string(),
+        level: Some(LogLevel::Verbose),
+        meta: PlatformMetadata::AndroidLogcat { process_id: "12345".to_owned(), thread_id: "12367".to_owned(), tag: "abc".to_owned() },
+        message: "Verbose message".to_owned(),
+        source: 0..0,
+        sensitive: vec![],
+        origin: None,
+    }; "verbose")]
     fn logcat_entry_ok(input: &str) -> LogEntry {
         parsing_test(logcat_entry(1234), input)
     }
 
+    #[test_case("========= LOGCAT ==========\n01-21 12:34:56.789  1234  5678 W abc     : Before any buffer marker\n--------- beginning of main\n01-22 12:34:56.789  1234  5678 I abc     : In main"
+    => Section {
+        name: LOGCAT_SECTION_NAME.to_owned(),
+        content: vec![
+            LogEntry {
+                timestamp: Utc.ymd(1234, 1, 21).and_hms_milli(12, 34, 56, 789),
+                original_timestamp: NaiveDate::from_ymd(1234, 1, 21).and_hms_milli(12, 34, 56, 789).to_string(),
+                level: Some(LogLevel::Warn),
+                meta: PlatformMetadata::AndroidLogcat { process_id: "1234".to_owned(), thread_id: "5678".to_owned(), tag: "abc".to_owned() },
+                message: "Before any buffer marker".to_owned(),
+                source: 28..95,
+                sensitive: vec![],
+                origin: None,
+            }
+        ],
+        subsections: vec![
+            Section {
+                name: "beginning of main".to_owned(),
+                content: vec![
+                    LogEntry {
+                        timestamp: Utc.ymd(1234, 1, 22).and_hms_milli(12, 34, 56, 789),
+                        original_timestamp: NaiveDate::from_ymd(1234, 1, 22).and_hms_milli(12, 34, 56, 789).to_string(),
+                        level: Some(LogLevel::Info),
+                        meta: PlatformMetadata::AndroidLogcat { process_id: "1234".to_owned(), thread_id: "5678".to_owned(), tag: "abc".to_owned() },
+                        message: "In main".to_owned(),
+                        source: 124..174,
+                        sensitive: vec![],
+                        origin: None,
+                    }
+                ],
+                subsections: vec![],
+            },
+        ],
+    }; "entries before buffer marker")]
     #[test_case("========= LOGCAT ==========\n--------- beginning of crash\n01-21 12:34:56.789  1234  5678 F libc    : Fatal signal 11 (SIGSEGV), code 2, fault addr 0x12345678 in tid 9876 (Abc)\n--------- beginning of main\n01-22 12:34:56.789 12345 12367 I chatty  : uid=10001(org.thoughtcrime.securesms) expire 1 line\n01-23 12:34:56.789 12345 12367 I chatty  : uid=10001(org.thoughtcrime.securesms) expire 5 lines"
     => Section {
         name: LOGCAT_SECTION_NAME.to_owned(),
@@ -884,10 +1047,14 @@ mod tests {
                 name: "beginning of crash".to_owned(),
                 content: vec![
                     LogEntry {
-                        timestamp: NaiveDate::from_ymd(1234, 1, 21).and_hms_milli(12, 34, 56, 789).to_string(),
+                        timestamp: Utc.ymd(1234, 1, 21).and_hms_milli(12, 34, 56, 789),
+                        original_timestamp: NaiveDate::from_ymd(1234, 1, 21).and_hms_milli(12, 34, 56, 789).to_string(),
                         level: Some(LogLevel::Fatal),
                         meta: PlatformMetadata::AndroidLogcat { process_id: "1234".to_owned(), thread_id: "5678".to_owned(), tag: "libc".to_owned() },
                         message: "Fatal signal 11 (SIGSEGV), code 2, fault addr 0x12345678 in tid 9876 (Abc)".to_owned(),
+                        source: 57..174,
+                        sensitive: vec![],
+                        origin: None,
                     }
                 ],
                 subsections: vec![],
@@ -896,16 +1063,24 @@ mod tests {
                 name: "beginning of main".to_owned(),
                 content: vec![
                     LogEntry {
-                        timestamp: NaiveDate::from_ymd(1234, 1, 22).and_hms_milli(12, 34, 56, 789).to_string(),
+                        timestamp: Utc.ymd(1234, 1, 22).and_hms_milli(12, 34, 56, 789),
+                        original_timestamp: NaiveDate::from_ymd(1234, 1, 22).and_hms_milli(12, 34, 56, 789).to_string(),
                         level: Some(LogLevel::Info),
                         meta: PlatformMetadata::AndroidLogcat { process_id: "12345".to_owned(), thread_id: "12367".to_owned(), tag: "chatty".to_owned() },
                         message: "uid=10001(org.thoughtcrime.securesms) expire 1 line".to_owned(),
+                        source: 203..297,
+                        sensitive: vec![],
+                        origin: None,
                     },
                     LogEntry {
-                        timestamp: NaiveDate::from_ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789).to_string(),
+                        timestamp: Utc.ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789),
+                        original_timestamp: NaiveDate::from_ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789).to_string(),
                         level: Some(LogLevel::Info),
                         meta: PlatformMetadata::AndroidLogcat { process_id: "12345".to_owned(), thread_id: "12367".to_owned(), tag: "chatty".to_owned() },
                         message: "uid=10001(org.thoughtcrime.securesms) expire 5 lines".to_owned(),
+                        source: 298..393,
+                        sensitive: vec![],
+                        origin: None,
                     },
                 ],
                 subsections: vec![],
@@ -913,31 +1088,50 @@ mod tests {
         ],
     }; "basic")]
     fn logcat_section_ok(input: &str) -> Section<LogEntry> {
-        parsing_test(logcat_section(1234), input)
+        parsing_test(logcat_section(input, 1234), input)
     }
 
     #[test_case("[1.23.4] [5678 ] 1234-01-23 12:34:56.789 GMT+01:00 I abc: Log message" => LogEntry {
-        timestamp: FixedOffset::east(1 * 3600).ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789).to_string(),
+        timestamp: FixedOffset::east(1 * 3600).ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789).with_timezone(&Utc),
+        original_timestamp: FixedOffset::east(1 * 3600).ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789).to_string(),
         level: Some(LogLevel::Info),
         meta: PlatformMetadata::AndroidLogger { version: "1.23.4".to_owned(), thread_id: "5678".to_owned(), tag: "abc".to_owned() },
         message: "Log message".to_owned(),
+        source: 0..0,
+        sensitive: vec![],
+        origin: None,
     }; "basic")]
     #[test_case("[1.23.4] [5678 ] 1234-01-23 12:34:56.789 GMT+01:00 I abc: Log message\ncontinues here!" => LogEntry {
-        timestamp: FixedOffset::east(1 * 3600).ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789).to_string(),
+        timestamp: FixedOffset::east(1 * 3600).ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789).with_timezone(&Utc),
+        original_timestamp: FixedOffset::east(1 * 3600).ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789).to_string(),
         level: Some(LogLevel::Info),
         meta: PlatformMetadata::AndroidLogger { version: "1.23.4".to_owned(), thread_id: "5678".to_owned(), tag: "abc".to_owned() },
         message: "Log message\ncontinues here!".to_owned(),
+        source: 0..0,
+        sensitive: vec![],
+        origin: None,
     }; "multiline")]
     #[test_case("[1.23.4] [5678 ] 1234-01-23 12:34:56.789 ABC I abc: Log message" => LogEntry {
-        timestamp: NaiveDate::from_ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789).to_string() + " ABC",
+        timestamp: Utc.ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789),
+        original_timestamp: NaiveDate::from_ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789).to_string() + " ABC",
         level: Some(LogLevel::Info),
         meta: PlatformMetadata::AndroidLogger { version: "1.23.4".to_owned(), thread_id: "5678".to_owned(), tag: "abc".to_owned() },
         message: "Log message".to_owned(),
+        source: 0..0,
+        sensitive: vec![],
+        origin: None,
     }; "timestamp not in GMT+hh:mm format")]
     fn logger_entry_ok(input: &str) -> LogEntry {
         parsing_test(logger_entry, input)
     }
 
+    #[test_case("" => Utc::now().year(); "no information")]
+    #[test_case("========= SYSINFO ==========\nTime          : 1634567890123\n" => 2021; "capture time")]
+    fn logcat_year_ok(input: &str) -> i32 {
+        let (_, information) = many0(info_section(SectionLevel::Base))(input).unwrap();
+        logcat_year(&information)
+    }
+
     #[test]
     fn content_ok_logcat_empty_logger_multiple() {
         let (remainder, result) = content("========= LOGCAT ==========\n========= LOGGER ==========\n[1.23.4] [5678 ] 1234-01-23 12:34:56.789 GMT+01:00 I abc: Log message\n[1.23.4] [5678 ] 1234-01-23 12:34:56.790 GMT+01:00 W abc: Log message 2").unwrap();
@@ -957,6 +1151,10 @@ mod tests {
                         content: vec![
                             LogEntry {
                                 timestamp: FixedOffset::east(1 * 3600)
+                                    .ymd(1234, 1, 23)
+                                    .and_hms_milli(12, 34, 56, 789)
+                                    .with_timezone(&Utc),
+                                original_timestamp: FixedOffset::east(1 * 3600)
                                     .ymd(1234, 1, 23)
                                     .and_hms_milli(12, 34, 56, 789)
                                     .to_string(),
@@ -967,9 +1165,16 @@ mod tests {
                                     tag: "abc".to_owned(),
                                 },
                                 message: "Log message".to_owned(),
+                                source: 56..125,
+                                sensitive: vec![],
+                                origin: None,
                             },
                             LogEntry {
                                 timestamp: FixedOffset::east(1 * 3600)
+                                    .ymd(1234, 1, 23)
+                                    .and_hms_milli(12, 34, 56, 790)
+                                    .with_timezone(&Utc),
+                                original_timestamp: FixedOffset::east(1 * 3600)
                                     .ymd(1234, 1, 23)
                                     .and_hms_milli(12, 34, 56, 790)
                                     .to_string(),
@@ -980,6 +1185,9 @@ mod tests {
                                     tag: "abc".to_owned(),
                                 },
                                 message: "Log message 2".to_owned(),
+                                source: 126..197,
+                                sensitive: vec![],
+                                origin: None,
                             },
                         ],
                         subsections: vec![],
@@ -3,11 +3,11 @@ use nom::{
     branch::alt,
     bytes::complete::{is_not, tag, take_until},
     character::complete::{digit1, multispace0, newline, space0},
-    combinator::{eof, map, not, opt, peek, value, verify},
+    combinator::{consumed, eof, map, map_opt, not, opt, peek, value, verify},
     error::ParseError,
-    multi::{many1, many_till, separated_list1},
+    multi::{many1, many_till, separated_list0, separated_list1},
     sequence::{delimited, preceded, separated_pair, terminated, tuple},
-    IResult,
+    IResult, Offset,
 };
 
 use crate::parsers::*;
@@ -56,6 +56,33 @@ pub fn bucketed_flag(input: &str) -> IResult<&str, Vec<Bucket>> {
     )(input)
 }
 
+/// A bracketed, comma-separated list of plain items, e.g. `[A, BC, DEF]`.
+pub fn list(input: &str) -> IResult<&str, Vec<String>> {
+    terminated(
+        delimited(
+            tag("["),
+            separated_list0(
+                tag(","),
+                map(is_not(",[]{}\"\n"), |item: &str| item.trim().to_owned()),
+            ),
+            tag("]"),
+        ),
+        peek(tuple((space0, alt((tag("\n"), tag("|"), eof))))),
+    )(input)
+}
+
+/// A JSON object or array spanning the rest of the line.
+pub fn json(input: &str) -> IResult<&str, serde_json::Value> {
+    map_opt(
+        verify(is_not("\n"), |s: &str| s.starts_with(['{', '['])),
+        |s: &str| {
+            serde_json::from_str::<serde_json::Value>(s.trim_end())
+                .ok()
+                .filter(|value| value.is_object() || value.is_array())
+        },
+    )(input)
+}
+
 pub fn key_maybe_enabled_value(input: &str) -> IResult<&str, InfoEntry> {
     let parse_key = terminated(
         verify(take_until(": "), |key: &str| !key.contains('\n')),
@@ -66,6 +93,8 @@ pub fn key_maybe_enabled_value(input: &str) -> IResult<&str, InfoEntry> {
 
     let parse_value = alt((
         map(bucketed_flag, Value::BucketedFlag),
+        map(list, Value::List),
+        map(json, Value::Json),
         map(is_not("\n|"), |s: &str| Value::Generic(s.trim().to_owned())),
     ));
 
@@ -162,6 +191,25 @@ where
     )
 }
 
+/// Parses a log entry using `entry` and records the byte range of `origin` (the entire input
+/// being parsed) that the entry was parsed from, not including trailing whitespace.
+pub fn with_source<'a, F>(
+    origin: &'a str,
+    mut entry: F,
+) -> impl FnMut(&'a str) -> IResult<&'a str, LogEntry>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, LogEntry>,
+{
+    move |input| {
+        let (remainder, (raw, mut entry)) = consumed(&mut entry)(input)?;
+
+        let start = origin.offset(raw);
+        entry.source = start..start + raw.trim_end().len();
+
+        Ok((remainder, entry))
+    }
+}
+
 #[cfg(test)]
 pub use tests::test_bucket;
 
@@ -265,8 +313,24 @@ mod tests {
     )); "time in value")]
     #[test_case("abc.defGhi.jkl123: [A, BC, DEF]\n" => ("\n", InfoEntry::KeyValue(
         "abc.defGhi.jkl123".to_owned(),
-        Value::Generic("[A, BC, DEF]".to_owned()),
+        Value::List(vec!["A".to_owned(), "BC".to_owned(), "DEF".to_owned()]),
     )); "array in value")]
+    #[test_case("abc.defGhi.jkl123: []" => ("", InfoEntry::KeyValue(
+        "abc.defGhi.jkl123".to_owned(),
+        Value::List(vec![]),
+    )); "empty array in value")]
+    #[test_case("abc.defGhi.jkl123: [1.2.3] and more" => ("", InfoEntry::KeyValue(
+        "abc.defGhi.jkl123".to_owned(),
+        Value::Generic("[1.2.3] and more".to_owned()),
+    )); "brackets followed by text")]
+    #[test_case(r#"abc: {"a": [1, true], "b": {"c": null}}"# => ("", InfoEntry::KeyValue(
+        "abc".to_owned(),
+        Value::Json(serde_json::json!({"a": [1, true], "b": {"c": null}})),
+    )); "json object in value")]
+    #[test_case("abc: [{\"a\": 1}, [\"b\"]]\n" => ("\n", InfoEntry::KeyValue(
+        "abc".to_owned(),
+        Value::Json(serde_json::json!([{"a": 1}, ["b"]])),
+    )); "json array in value")]
     #[test_case("abc: disabled true\n\n========= Logs =========\nINFO  1234-01-23T12:34:56.789Z This is a test message." => (
         "\n\n========= Logs =========\nINFO  1234-01-23T12:34:56.789Z This is a test message.",
         InfoEntry::KeyEnabledValue(
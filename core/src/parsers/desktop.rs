@@ -0,0 +1,348 @@
+use chrono::prelude::*;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{multispace0, newline, not_line_ending, space0},
+    combinator::{map, map_opt, opt, recognize, value, verify},
+    multi::many0,
+    sequence::{delimited, preceded, separated_pair, terminated, tuple},
+    IResult,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::parsers::*;
+
+const LOGS_SECTION_NAME: &str = "Logs";
+pub const PROCESSES: [&str; 3] = ["main", "renderer", "worker"];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogEntryMetadata {
+    pub process: String,
+}
+
+/// A log entry in the JSON-per-line (pino) format used in some sections of newer debug logs.
+#[derive(Debug, Deserialize)]
+struct JsonLogEntry {
+    level: u8,
+    time: JsonTime,
+    msg: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum JsonTime {
+    Millis(i64),
+    Text(String),
+}
+
+fn info_section(input: &str) -> IResult<&str, Section<InfoEntry>> {
+    let (remainder, name) = verify(
+        delimited(multispace0, common::section_header, opt(newline)),
+        |name: &str| name != LOGS_SECTION_NAME,
+    )(input)?;
+
+    let (remainder, entries) = many0(delimited(
+        multispace0,
+        common::key_maybe_enabled_value,
+        multispace0,
+    ))(remainder)?;
+
+    Ok((
+        remainder,
+        Section {
+            name: name.to_owned(),
+            content: entries,
+            subsections: vec![],
+        },
+    ))
+}
+
+fn level(input: &str) -> IResult<&str, LogLevel> {
+    let (remainder, s) = alt((
+        tag(LogLevel::Trace.to_string().to_uppercase().as_str()),
+        tag(LogLevel::Debug.to_string().to_uppercase().as_str()),
+        tag(LogLevel::Info.to_string().to_uppercase().as_str()),
+        tag(LogLevel::Warn.to_string().to_uppercase().as_str()),
+        tag(LogLevel::Error.to_string().to_uppercase().as_str()),
+        tag(LogLevel::Fatal.to_string().to_uppercase().as_str()),
+    ))(input)?;
+
+    Ok((remainder, s.parse().unwrap()))
+}
+
+fn metadata(input: &str) -> IResult<&str, (LogLevel, DateTime<Utc>)> {
+    separated_pair(
+        level,
+        space0,
+        map(
+            common::naive_date_time(None, "-", "T", ":", Some("."), Some("Z")),
+            |dt| DateTime::<Utc>::from_utc(dt, Utc),
+        ),
+    )(input)
+}
+
+fn process(input: &str) -> IResult<&str, LogEntryMetadata> {
+    map(
+        delimited(
+            tag("["),
+            alt((tag(PROCESSES[0]), tag(PROCESSES[1]), tag(PROCESSES[2]))),
+            tag("]"),
+        ),
+        |process: &str| LogEntryMetadata {
+            process: process.to_owned(),
+        },
+    )(input)
+}
+
+fn json_level(level: u8) -> LogLevel {
+    match level {
+        0..=10 => LogLevel::Trace,
+        11..=20 => LogLevel::Debug,
+        21..=30 => LogLevel::Info,
+        31..=40 => LogLevel::Warn,
+        41..=50 => LogLevel::Error,
+        _ => LogLevel::Fatal,
+    }
+}
+
+fn json_log_entry(input: &str) -> IResult<&str, LogEntry> {
+    map_opt(
+        terminated(recognize(preceded(tag("{"), not_line_ending)), opt(newline)),
+        |line: &str| {
+            let entry = serde_json::from_str::<JsonLogEntry>(line).ok()?;
+
+            let dt = match entry.time {
+                JsonTime::Millis(millis) => Utc.timestamp_millis_opt(millis).single()?,
+                JsonTime::Text(text) => DateTime::parse_from_rfc3339(&text).ok()?.into(),
+            };
+
+            Some(LogEntry {
+                timestamp: dt,
+                original_timestamp: dt.to_string(),
+                level: Some(json_level(entry.level)),
+                meta: PlatformMetadata::Desktop(None),
+                message: entry.msg,
+                source: Default::default(),
+                sensitive: vec![],
+                origin: None,
+            })
+        },
+    )(input)
+}
+
+fn log_entry(input: &str) -> IResult<&str, LogEntry> {
+    alt((json_log_entry, text_log_entry))(input)
+}
+
+fn text_log_entry(input: &str) -> IResult<&str, LogEntry> {
+    map(
+        tuple((
+            metadata,
+            space0,
+            opt(terminated(process, space0)),
+            common::message(alt((value((), metadata), value((), json_log_entry)))),
+        )),
+        |((lvl, dt), _, meta, message)| LogEntry {
+            timestamp: dt,
+            original_timestamp: dt.to_string(),
+            level: Some(lvl),
+            meta: PlatformMetadata::Desktop(meta),
+            message,
+            source: Default::default(),
+            sensitive: vec![],
+            origin: None,
+        },
+    )(input)
+}
+
+pub fn content(input: &str) -> IResult<&str, Content> {
+    let (remainder, (information, logs)) = separated_pair(
+        preceded(multispace0, many0(info_section)),
+        verify(common::section_header, |name: &str| {
+            name == LOGS_SECTION_NAME
+        }),
+        preceded(multispace0, many0(common::with_source(input, log_entry))),
+    )(input)?;
+
+    Ok((
+        remainder,
+        Content {
+            information,
+            logs: vec![Section {
+                name: LOGS_SECTION_NAME.to_owned(),
+                content: logs,
+                subsections: vec![],
+            }],
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::parsing_test;
+
+    use super::*;
+
+    fn test_bucket(country_code: &str, value: u32) -> Bucket {
+        Bucket {
+            country_code: country_code.to_owned(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test_case("INFO  1234-01-23T12:34:56.789Z" => (LogLevel::Info, Utc.ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789)); "basic")]
+    fn metadata_ok(input: &str) -> (LogLevel, DateTime<Utc>) {
+        parsing_test(metadata, input)
+    }
+
+    #[test_case(
+        "INFO  1234-01-23T12:34:56.789Z [renderer] Message" =>
+        (Some(LogEntryMetadata { process: "renderer".to_owned() }), "Message".to_owned());
+        "with process"
+    )]
+    #[test_case(
+        "INFO  1234-01-23T12:34:56.789Z [Storage] Message" =>
+        (None, "[Storage] Message".to_owned());
+        "unknown prefix is part of the message"
+    )]
+    fn log_entry_ok(input: &str) -> (Option<LogEntryMetadata>, String) {
+        let entry = parsing_test(log_entry, input);
+
+        match entry.meta {
+            PlatformMetadata::Desktop(meta) => (meta, entry.message),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test_case(
+        r#"{"level":50,"time":"1234-01-23T12:34:56.789Z","msg":"Something failed"}"# =>
+        (Some(LogLevel::Error), Utc.ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789), "Something failed".to_owned());
+        "time as text"
+    )]
+    #[test_case(
+        r#"{"level":20,"time":1234567890123,"msg":"Details","pid":1}"# =>
+        (Some(LogLevel::Debug), Utc.timestamp_millis(1234567890123), "Details".to_owned());
+        "time in milliseconds, extra fields"
+    )]
+    fn json_log_entry_ok(input: &str) -> (Option<LogLevel>, DateTime<Utc>, String) {
+        let entry = parsing_test(json_log_entry, input);
+        (entry.level, entry.timestamp, entry.message)
+    }
+
+    #[test]
+    fn content_ok_json_lines() {
+        let input = "========= Logs =========\nINFO  1234-01-23T12:34:56.789Z Text entry\n{\"level\":40,\"time\":\"1234-01-23T12:34:57.000Z\",\"msg\":\"JSON entry\"}\nINFO  1234-01-23T12:34:58.000Z Another text entry";
+
+        let (remainder, result) = content(input).unwrap();
+
+        assert_eq!(remainder, "", "remainder should be empty");
+        assert_eq!(
+            result
+                .log_entries()
+                .map(|entry| (
+                    entry.level,
+                    entry.message.as_str(),
+                    &input[entry.source.clone()]
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    Some(LogLevel::Info),
+                    "Text entry",
+                    "INFO  1234-01-23T12:34:56.789Z Text entry"
+                ),
+                (
+                    Some(LogLevel::Warn),
+                    "JSON entry",
+                    "{\"level\":40,\"time\":\"1234-01-23T12:34:57.000Z\",\"msg\":\"JSON entry\"}"
+                ),
+                (
+                    Some(LogLevel::Info),
+                    "Another text entry",
+                    "INFO  1234-01-23T12:34:58.000Z Another text entry"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn content_ok() {
+        let (remainder, result) = content("\n  \n\n========= Section 1 =========\nKey: 123.456 value\nAnother key: disabled\n\n========= Section 2 =========\nbucketed: enabled 1:2,3:4,*:5\n\n\n\n\n========= Section 3 =========\nabc: disabled true\n\n========= Logs =========\nINFO  1234-01-23T12:34:56.789Z This is a test message.\nDEBUG  1234-01-23T12:34:56.987Z Another message.").unwrap();
+
+        assert_eq!(remainder, "", "remainder should be empty");
+        assert_eq!(
+            result,
+            Content {
+                information: vec![
+                    Section {
+                        name: "Section 1".to_owned(),
+                        content: vec![
+                            InfoEntry::KeyValue(
+                                "Key".to_owned(),
+                                Value::Generic("123.456 value".to_owned())
+                            ),
+                            InfoEntry::KeyEnabledValue("Another key".to_owned(), false, None,),
+                        ],
+                        subsections: vec![],
+                    },
+                    Section {
+                        name: "Section 2".to_owned(),
+                        content: vec![InfoEntry::KeyEnabledValue(
+                            "bucketed".to_owned(),
+                            true,
+                            Some(Value::BucketedFlag(vec![
+                                test_bucket("1", 2),
+                                test_bucket("3", 4),
+                                test_bucket("*", 5),
+                            ])),
+                        )],
+                        subsections: vec![],
+                    },
+                    Section {
+                        name: "Section 3".to_owned(),
+                        content: vec![InfoEntry::KeyEnabledValue(
+                            "abc".to_owned(),
+                            false,
+                            Some(Value::Generic("true".to_owned())),
+                        )],
+                        subsections: vec![],
+                    },
+                ],
+                logs: vec![Section {
+                    name: LOGS_SECTION_NAME.to_owned(),
+                    content: vec![
+                        LogEntry {
+                            timestamp: Utc.ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789),
+                            original_timestamp: Utc
+                                .ymd(1234, 1, 23)
+                                .and_hms_milli(12, 34, 56, 789)
+                                .to_string(),
+                            level: Some(LogLevel::Info),
+                            meta: PlatformMetadata::Desktop(None),
+                            message: "This is a test message.".to_owned(),
+                            source: 216..270,
+                            sensitive: vec![],
+                            origin: None,
+                        },
+                        LogEntry {
+                            timestamp: Utc.ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 987),
+                            original_timestamp: Utc
+                                .ymd(1234, 1, 23)
+                                .and_hms_milli(12, 34, 56, 987)
+                                .to_string(),
+                            level: Some(LogLevel::Debug),
+                            meta: PlatformMetadata::Desktop(None),
+                            message: "Another message.".to_owned(),
+                            source: 271..319,
+                            sensitive: vec![],
+                            origin: None,
+                        }
+                    ],
+                    subsections: vec![],
+                }],
+            }
+        );
+    }
+}
@@ -1,29 +1,49 @@
 use chrono::prelude::*;
 use nom::{
     branch::alt,
-    bytes::complete::{is_a, is_not, tag, take_until},
+    bytes::complete::{is_not, tag, take_until},
     character::complete::{multispace0, space0},
     combinator::{map, opt, verify},
     multi::many0,
     sequence::{preceded, terminated, tuple},
     IResult,
 };
+use serde::{Deserialize, Serialize};
 
 use crate::{parsers::*, LogLevel};
 
 const DEFAULT_LOGS_SECTION_NAME: &str = "Logs";
 
-#[derive(Debug, Clone, PartialEq)]
+/// Emoji used by Signal iOS (in its current and past versions) to denote log levels.
+const LEVELS: [(&str, LogLevel); 8] = [
+    ("💙", LogLevel::Verbose),
+    ("💜", LogLevel::Verbose),
+    ("💚", LogLevel::Debug),
+    ("💛", LogLevel::Info),
+    ("🧡", LogLevel::Warn),
+    ("❤️", LogLevel::Error),
+    ("❤", LogLevel::Error),
+    ("‼️", LogLevel::Error),
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LogEntryMetadata {
     pub file: String,
     pub line: String,
     pub symbol: String,
 }
 
-fn level(input: &str) -> IResult<&str, LogLevel> {
-    map(is_a("💙💚💛🧡❤️"), |heart: &str| {
-        heart.parse().unwrap()
-    })(input)
+/// An emoji in place of the log level; unknown emoji are consumed, but have no level.
+fn level(input: &str) -> IResult<&str, Option<LogLevel>> {
+    map(
+        verify(is_not(" ["), |s: &str| s.chars().all(|c| !c.is_ascii())),
+        |emoji: &str| {
+            LEVELS
+                .iter()
+                .find(|(level_emoji, _)| *level_emoji == emoji)
+                .map(|(_, level)| *level)
+        },
+    )(input)
 }
 
 fn metadata(
@@ -53,7 +73,7 @@ fn metadata(
         remainder,
         (
             DateTime::<Utc>::from_utc(dt, Utc),
-            lvl,
+            lvl.flatten(),
             meta.map(|(_, file, _, line, _, symbol, _)| LogEntryMetadata {
                 file: file.to_owned(),
                 line: line.to_owned(),
@@ -67,10 +87,14 @@ fn log_entry(input: &str) -> IResult<&str, LogEntry> {
     map(
         tuple((metadata, space0, common::message(metadata))),
         |((dt, lvl, meta), _, message)| LogEntry {
-            timestamp: dt.to_string(),
+            timestamp: dt,
+            original_timestamp: dt.to_string(),
             level: lvl,
             meta: PlatformMetadata::Ios(meta),
             message,
+            source: Default::default(),
+            sensitive: vec![],
+            origin: None,
         },
     )(input)
 }
@@ -78,13 +102,15 @@ fn log_entry(input: &str) -> IResult<&str, LogEntry> {
 pub fn content(input: &str) -> IResult<&str, Content> {
     preceded(
         multispace0,
-        map(many0(log_entry), |logs| Content {
-            information: vec![],
-            logs: vec![Section {
-                name: DEFAULT_LOGS_SECTION_NAME.to_owned(),
-                content: logs,
-                subsections: vec![],
-            }],
+        map(many0(common::with_source(input, log_entry)), |logs| {
+            Content {
+                information: vec![],
+                logs: vec![Section {
+                    name: DEFAULT_LOGS_SECTION_NAME.to_owned(),
+                    content: logs,
+                    subsections: vec![],
+                }],
+            }
         }),
     )(input)
 }
@@ -115,10 +141,14 @@ mod tests {
         message: &str,
     ) -> LogEntry {
         LogEntry {
-            timestamp: test_timestamp(milliseconds).to_string(),
+            timestamp: test_timestamp(milliseconds),
+            original_timestamp: test_timestamp(milliseconds).to_string(),
             level,
             meta: PlatformMetadata::Ios(metadata),
             message: message.to_owned(),
+            source: 0..0,
+            sensitive: vec![],
+            origin: None,
         }
     }
 
@@ -146,6 +176,26 @@ mod tests {
         }));
         "meta does not have colon at the end and does not have symbol"
     )]
+    #[test_case(
+        "1234/01/23 12:34:56:789 💙 [Item.abc:123 -[Item handleSomething]]:" =>
+        (test_timestamp(789), Some(LogLevel::Verbose), test_metadata(123));
+        "verbose"
+    )]
+    #[test_case(
+        "1234/01/23 12:34:56:789 💜 [Item.abc:123 -[Item handleSomething]]:" =>
+        (test_timestamp(789), Some(LogLevel::Verbose), test_metadata(123));
+        "purple heart"
+    )]
+    #[test_case(
+        "1234/01/23 12:34:56:789 ‼️ [Item.abc:123 -[Item handleSomething]]:" =>
+        (test_timestamp(789), Some(LogLevel::Error), test_metadata(123));
+        "double exclamation mark"
+    )]
+    #[test_case(
+        "1234/01/23 12:34:56:789 🖤 [Item.abc:123 -[Item handleSomething]]:" =>
+        (test_timestamp(789), None, test_metadata(123));
+        "unknown emoji"
+    )]
     #[test_case(
         "1234/01/23 12:34:56:789" =>
         (test_timestamp(789), None, None);
@@ -232,25 +282,25 @@ mod tests {
     #[test_case(
         "1234/01/23 12:34:56:789 💚 [Item.abc:123 -[Item handleSomething]]: Debug message that spans\nmultiple lines {\n\ta: b,\n\tc: d,\n}\n1234/01/23 12:34:56:987 💛 [Item.abc:456 -[Item handleSomething]]: Another message...",
         vec![
-            test_log_message(789, Some(LogLevel::Debug), test_metadata(123), "Debug message that spans\nmultiple lines {\n\ta: b,\n\tc: d,\n}"),
-            test_log_message(987, Some(LogLevel::Info), test_metadata(456), "Another message..."),
+            LogEntry { source: 0..126, ..test_log_message(789, Some(LogLevel::Debug), test_metadata(123), "Debug message that spans\nmultiple lines {\n\ta: b,\n\tc: d,\n}") },
+            LogEntry { source: 127..214, ..test_log_message(987, Some(LogLevel::Info), test_metadata(456), "Another message...") },
         ];
         "two log messages"
     )]
     #[test_case(
         "\n\t\n  \r\n1234/01/23 12:34:56:789 💚 [Item.abc:123 -[Item handleSomething]]: Debug message that spans\nmultiple lines {\n\ta: b,\n\tc: d,\n}\n1234/01/23 12:34:56:987 💛 [Item.abc:456 -[Item handleSomething]]: Another message...",
         vec![
-            test_log_message(789, Some(LogLevel::Debug), test_metadata(123), "Debug message that spans\nmultiple lines {\n\ta: b,\n\tc: d,\n}"),
-            test_log_message(987, Some(LogLevel::Info), test_metadata(456), "Another message..."),
+            LogEntry { source: 7..133, ..test_log_message(789, Some(LogLevel::Debug), test_metadata(123), "Debug message that spans\nmultiple lines {\n\ta: b,\n\tc: d,\n}") },
+            LogEntry { source: 134..221, ..test_log_message(987, Some(LogLevel::Info), test_metadata(456), "Another message...") },
         ];
         "starts with multispace"
     )]
     #[test_case(
         "1234/01/23 12:34:56:123  ❤️ [Item.abc:123 -[Item handleSomething]]: Test 1\n1234/01/23 12:34:56:789  -[Abc def]:123 test\n1234/01/23 12:34:56:987  💚 [Item.abc:456 -[Item handleSomething]]: Test 2",
         vec![
-            test_log_message(123, Some(LogLevel::Error), test_metadata(123), "Test 1"),
-            test_log_message(789, None, None, "-[Abc def]:123 test"),
-            test_log_message(987, Some(LogLevel::Debug), test_metadata(456), "Test 2"),
+            LogEntry { source: 0..78, ..test_log_message(123, Some(LogLevel::Error), test_metadata(123), "Test 1") },
+            LogEntry { source: 79..123, ..test_log_message(789, None, None, "-[Abc def]:123 test") },
+            LogEntry { source: 124..200, ..test_log_message(987, Some(LogLevel::Debug), test_metadata(456), "Test 2") },
         ];
         "no log level in the middle"
     )]
@@ -0,0 +1,244 @@
+use chrono::prelude::*;
+use derive_more::Display;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_till1, take_until},
+    character::complete::space1,
+    combinator::{map, rest, value},
+    sequence::{delimited, preceded, terminated},
+    IResult,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{impl_from_str, parsers::common};
+
+#[derive(Debug, Display, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum AppId {
+    Signal,
+    #[display(fmt = "NSE")]
+    NotificationServiceExtension,
+    #[display(fmt = "SAE")]
+    ShareAppExtension,
+    /// Another process, e.g. an extension that was added later, by its bundle ID (without the
+    /// prefix of the main app, if it has that).
+    #[display(fmt = "{}", _0)]
+    Other(String),
+    /// The file's name couldn't be parsed.
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LogFilename {
+    /// Seems to be 12-hour time in the timezone of the submitter,
+    /// but without indication of whether it's AM or PM.
+    pub submission_time: NaiveDateTime,
+    pub folder_id: String,
+    pub app_id: AppId,
+    pub file_time: DateTime<Utc>,
+    pub extension: String,
+    /// Path of the file in the archive, which is unique even if nothing else could be parsed.
+    pub path: String,
+}
+
+impl LogFilename {
+    /// Parses the path of a file in a debug log archive, ignoring any directories the folder is
+    /// nested in; if that fails, the file's app is unknown and both its times are when it was last
+    /// modified.
+    pub fn from_path(path: &str, modified: NaiveDateTime) -> Self {
+        let components = path.split('/').collect::<Vec<_>>();
+        let relevant = components[components.len().saturating_sub(2)..].join("/");
+
+        match relevant.parse::<LogFilename>() {
+            Ok(filename) => LogFilename {
+                path: path.to_owned(),
+                ..filename
+            },
+            Err(_) => {
+                let (folder_id, name) = path.rsplit_once('/').unwrap_or(("", path));
+
+                LogFilename {
+                    submission_time: modified,
+                    folder_id: folder_id.to_owned(),
+                    app_id: AppId::Unknown,
+                    file_time: DateTime::<Utc>::from_utc(modified, Utc),
+                    extension: name
+                        .rsplit_once('.')
+                        .map(|(_, extension)| extension)
+                        .unwrap_or_default()
+                        .to_owned(),
+                    path: path.to_owned(),
+                }
+            }
+        }
+    }
+
+    /// Short name of the file among the others of the debug log: its time, or its name if its
+    /// app is unknown.
+    pub fn title(&self) -> String {
+        match &self.app_id {
+            AppId::Unknown => self.path.rsplit('/').next().unwrap_or_default().to_owned(),
+            _ => self.file_time.to_string(),
+        }
+    }
+}
+
+/// Whether the entry of a debug log archive is no log file: a directory, an empty file, or
+/// metadata added when the archive was created on macOS.
+pub fn is_junk_entry(path: &str, size: u64) -> bool {
+    let name = path.rsplit('/').next().unwrap_or_default();
+
+    path.ends_with('/')
+        || size == 0
+        || path.starts_with("__MACOSX/")
+        || name.starts_with("._")
+        || name == ".DS_Store"
+}
+
+const MAIN_APP_ID: &str = "org.whispersystems.signal";
+
+fn app_id_with_space(input: &str) -> IResult<&str, AppId> {
+    alt((
+        preceded(
+            tag(MAIN_APP_ID),
+            alt((
+                value(AppId::Signal, space1),
+                terminated(
+                    alt((
+                        value(AppId::NotificationServiceExtension, tag(".SignalNSE")),
+                        value(
+                            AppId::NotificationServiceExtension,
+                            tag(".NotificationServiceExtension"),
+                        ),
+                        value(AppId::ShareAppExtension, tag(".shareextension")),
+                    )),
+                    space1,
+                ),
+            )),
+        ),
+        map(terminated(take_till1(|c| c == ' '), space1), |id: &str| {
+            AppId::Other(
+                id.strip_prefix(MAIN_APP_ID)
+                    .and_then(|suffix| suffix.strip_prefix('.'))
+                    .unwrap_or(id)
+                    .to_owned(),
+            )
+        }),
+    ))(input)
+}
+
+fn log_filename(input: &str) -> IResult<&str, LogFilename> {
+    let (remainder, submission_time) =
+        common::naive_date_time(None, ".", " ", ".", None, None)(input)?;
+    let (remainder, folder_id) = delimited(tag(" "), take_until("/"), tag("/"))(remainder)?;
+    let (remainder, app_id) = app_id_with_space(remainder)?;
+    let (remainder, file_time) =
+        common::naive_date_time(None, "-", "--", "-", Some("-"), None)(remainder)?;
+    let (remainder, extension) = preceded(tag("."), rest)(remainder)?;
+
+    Ok((
+        remainder,
+        LogFilename {
+            submission_time,
+            folder_id: folder_id.to_owned(),
+            app_id,
+            file_time: DateTime::<Utc>::from_utc(file_time, Utc),
+            extension: extension.to_owned(),
+            path: input.to_owned(),
+        },
+    ))
+}
+
+impl_from_str!(log_filename => LogFilename);
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    use crate::utils::parsing_test;
+
+    #[test]
+    fn partial_ord() {
+        let a = LogFilename {
+            submission_time: NaiveDate::from_ymd(1234, 1, 23).and_hms(12, 34, 56),
+            folder_id: "ABCD1234-1AB2-3CDE-456F-789AB0CD1E2F".to_owned(),
+            app_id: AppId::Signal,
+            file_time: Utc.ymd(1234, 1, 22).and_hms_milli(6, 54, 32, 109),
+            extension: "log".to_owned(),
+            path: "a".to_owned(),
+        };
+
+        let b = LogFilename {
+            app_id: AppId::NotificationServiceExtension,
+            file_time: Utc.ymd(1234, 1, 22).and_hms_milli(6, 54, 32, 111),
+            ..a.clone()
+        };
+
+        let c = LogFilename {
+            file_time: Utc.ymd(1234, 1, 22).and_hms_milli(6, 54, 32, 123),
+            ..a.clone()
+        };
+
+        // a: Signal ...109
+        // c: Signal ...123
+        // b: NSE    ...111
+
+        assert!(a < c);
+        assert!(c < b);
+    }
+
+    #[test_case("org.whispersystems.signal " => AppId::Signal)]
+    #[test_case("org.whispersystems.signal.SignalNSE " => AppId::NotificationServiceExtension)]
+    #[test_case("org.whispersystems.signal.NotificationServiceExtension " => AppId::NotificationServiceExtension)]
+    #[test_case("org.whispersystems.signal.shareextension " => AppId::ShareAppExtension)]
+    #[test_case("org.whispersystems.signal.SignalWidgetExtension " => AppId::Other("SignalWidgetExtension".to_owned()); "other extension")]
+    #[test_case("org.signal.Signal " => AppId::Other("org.signal.Signal".to_owned()); "other prefix")]
+    fn app_id_with_space_ok(input: &str) -> AppId {
+        parsing_test(app_id_with_space, input)
+    }
+
+    #[test]
+    fn log_filename_ok() {
+        let input = "1234.01.23 12.34.56 ABCD1234-1AB2-3CDE-456F-789AB0CD1E2F/org.whispersystems.signal 1234-01-22--06-54-32-109.log";
+        let (remainder, result) = log_filename(input).unwrap();
+
+        assert_eq!(remainder, "", "remainder should be empty");
+
+        assert_eq!(
+            result,
+            LogFilename {
+                submission_time: NaiveDate::from_ymd(1234, 1, 23).and_hms(12, 34, 56),
+                folder_id: "ABCD1234-1AB2-3CDE-456F-789AB0CD1E2F".to_owned(),
+                app_id: AppId::Signal,
+                file_time: Utc.ymd(1234, 1, 22).and_hms_milli(6, 54, 32, 109),
+                extension: "log".to_owned(),
+                path: input.to_owned(),
+            }
+        );
+    }
+
+    #[test_case("1234.01.23 12.34.56 ABCD/org.whispersystems.signal 1234-01-22--06-54-32-109.log" => (AppId::Signal, "ABCD".to_owned(), "1234-01-22 06:54:32.109 UTC".to_owned()); "plain")]
+    #[test_case("Signal Logs/1234.01.23 12.34.56 ABCD/org.whispersystems.signal 1234-01-22--06-54-32-109.log" => (AppId::Signal, "ABCD".to_owned(), "1234-01-22 06:54:32.109 UTC".to_owned()); "nested")]
+    #[test_case("Signal Logs/notes.txt" => (AppId::Unknown, "Signal Logs".to_owned(), "notes.txt".to_owned()); "unknown")]
+    #[test_case("1234.01.23 12.34.56 ABCD/org.whispersystems.signal.SignalWidgetExtension 1234-01-22--06-54-32-109.log" => (AppId::Other("SignalWidgetExtension".to_owned()), "ABCD".to_owned(), "1234-01-22 06:54:32.109 UTC".to_owned()); "other app")]
+    #[test_case("notes" => (AppId::Unknown, "".to_owned(), "notes".to_owned()); "unknown without folder")]
+    fn from_path_ok(path: &str) -> (AppId, String, String) {
+        let filename =
+            LogFilename::from_path(path, NaiveDate::from_ymd(2021, 1, 2).and_hms(3, 4, 5));
+        assert_eq!(filename.path, path);
+
+        let title = filename.title();
+        (filename.app_id, filename.folder_id, title)
+    }
+
+    #[test_case("1234.01.23 12.34.56 ABCD/org.whispersystems.signal 1234-01-22--06-54-32-109.log", 10 => false; "log file")]
+    #[test_case("1234.01.23 12.34.56 ABCD/", 0 => true; "directory")]
+    #[test_case("1234.01.23 12.34.56 ABCD/empty.log", 0 => true; "empty")]
+    #[test_case("__MACOSX/1234.01.23 12.34.56 ABCD/._org.whispersystems.signal 1234-01-22--06-54-32-109.log", 10 => true; "macos metadata")]
+    #[test_case("1234.01.23 12.34.56 ABCD/.DS_Store", 10 => true; "ds store")]
+    fn is_junk_entry_ok(path: &str, size: u64) -> bool {
+        is_junk_entry(path, size)
+    }
+}
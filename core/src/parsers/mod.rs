@@ -0,0 +1,962 @@
+use std::{collections::BTreeSet, fmt, ops::Range};
+
+use anyhow::anyhow;
+use chrono::prelude::*;
+use derive_more::Display;
+use nom::{combinator::eof, sequence::terminated};
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use crate::{
+    post_processing, redaction::Identifier, LogLevel, Platform, RemoteObject, SearchQuery,
+};
+
+mod android;
+mod common;
+mod desktop;
+mod ios;
+mod ios_filename;
+
+pub use desktop::PROCESSES as DESKTOP_PROCESSES;
+pub use ios_filename::*;
+
+/// First bytes of `gzip` data, as which debug logs may be uploaded or saved.
+pub const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+/// First bytes of a `zip` archive, as which Signal iOS debug logs are uploaded.
+pub const ZIP_MAGIC: [u8; 4] = [0x50, 0x4b, 0x03, 0x04];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Content {
+    pub information: Vec<Section<InfoEntry>>,
+    pub logs: Vec<Section<LogEntry>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Section<C> {
+    pub name: String,
+    pub content: Vec<C>,
+    pub subsections: Vec<Section<C>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum InfoEntry {
+    KeyValue(String, Value),
+    KeyEnabledValue(String, bool, Option<Value>),
+    RemoteObject(RemoteObject),
+    ExplicitNone,
+    GenericTable(GenericTable),
+    Generic(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Value {
+    Generic(String),
+    BucketedFlag(Vec<Bucket>),
+    List(Vec<String>),
+    Json(serde_json::Value),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GenericTable {
+    pub header: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Default for Value {
+    fn default() -> Self {
+        Value::Generic(Default::default())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bucket {
+    pub country_code: String,
+    pub value: String,
+}
+
+impl Bucket {
+    /// Rollout percentage of this bucket; values are in parts per million.
+    pub fn percentage(&self) -> Option<f64> {
+        self.value
+            .parse::<u32>()
+            .ok()
+            .map(|parts_per_million| f64::from(parts_per_million) / 10_000.0)
+    }
+}
+
+/// Minimum number of entries sharing a key prefix for them to be shown as a collapsible group.
+const MIN_GROUP_SIZE: usize = 3;
+
+/// A column of the logs table, which can be hidden.
+#[derive(
+    Debug, Display, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumIter, Serialize, Deserialize,
+)]
+pub enum LogColumn {
+    /// Number of the entry in the file, see [`Content::log_entry_by_number`].
+    #[display(fmt = "#")]
+    Number,
+    Origin,
+    Timestamp,
+    /// Time elapsed since the previous entry of the section.
+    Delta,
+    Level,
+    Process,
+    Thread,
+    Tag,
+    Version,
+    File,
+    Line,
+    Symbol,
+    Message,
+}
+
+impl LogColumn {
+    /// Whether the column is only shown once chosen, rather than until it's hidden.
+    pub fn is_optional(self) -> bool {
+        matches!(self, LogColumn::Delta)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogEntry {
+    /// When the entry was written; timestamps without a (known) time zone are assumed to be in
+    /// UTC.
+    #[serde(
+        rename = "time",
+        serialize_with = "serialize_time",
+        deserialize_with = "deserialize_time"
+    )]
+    pub timestamp: DateTime<Utc>,
+    /// The timestamp as it's shown, in the time zone it was written in (if any).
+    #[serde(rename = "timestamp")]
+    pub original_timestamp: String,
+    pub level: Option<LogLevel>,
+    pub meta: PlatformMetadata, // TODO: don't repeat in every log message if there is no real metadata?
+    pub message: String,
+    /// Byte range of the original text that this entry was parsed from.
+    pub source: Range<usize>,
+    /// Byte ranges of `message` that look like identifiers, see
+    /// [`post_processing::annotate_sensitive_data`].
+    #[serde(skip)]
+    pub sensitive: Vec<(Range<usize>, Identifier)>,
+    /// The app (or app extension) that wrote this entry, if entries of several are combined.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origin: Option<AppId>,
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl LogEntry {
+    /// An entry with the `message`, parsed from the (empty) byte range at `start`, written at
+    /// the Unix epoch without a level or metadata; tests override the fields they care about.
+    pub fn test(message: &str, start: usize) -> Self {
+        Self {
+            timestamp: Utc.timestamp(0, 0),
+            original_timestamp: String::new(),
+            level: None,
+            meta: PlatformMetadata::Desktop(None),
+            message: message.to_owned(),
+            source: start..start,
+            sensitive: vec![],
+            origin: None,
+        }
+    }
+}
+
+/// Serializes the time in RFC 3339 format, with milliseconds.
+fn serialize_time<S: serde::Serializer>(
+    time: &DateTime<Utc>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&time.to_rfc3339_opts(SecondsFormat::Millis, true))
+}
+
+/// Deserializes a time written by [`serialize_time`].
+fn deserialize_time<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<DateTime<Utc>, D::Error> {
+    let time = String::deserialize(deserializer)?;
+
+    DateTime::parse_from_rfc3339(&time)
+        .map(|time| time.with_timezone(&Utc))
+        .map_err(serde::de::Error::custom)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PlatformMetadata {
+    AndroidLogcat {
+        process_id: String,
+        thread_id: String,
+        tag: String,
+    },
+    AndroidLogger {
+        version: String,
+        thread_id: String,
+        tag: String,
+    },
+    Ios(Option<ios::LogEntryMetadata>),
+    Desktop(Option<desktop::LogEntryMetadata>),
+}
+
+impl Content {
+    pub fn parse(platform: Platform, text: &str) -> anyhow::Result<Self> {
+        let parsing_fn = match platform {
+            Platform::Android => android::content,
+            Platform::Ios => ios::content,
+            Platform::Desktop => desktop::content,
+        };
+
+        let (_, mut output) =
+            terminated(parsing_fn, eof)(text).map_err(|error| anyhow!("{:#?}", error))?;
+
+        post_processing::annotate_sensitive_data(&mut output);
+
+        Ok(output)
+    }
+
+    /// Finds the value of the first information entry whose key is one of `keys`
+    /// (compared case-insensitively), in the order the `keys` are given.
+    pub fn find_info_value(&self, keys: &[&str]) -> Option<String> {
+        keys.iter().find_map(|key| {
+            self.information
+                .iter()
+                .flat_map(Section::iter_all)
+                .find_map(|entry| match entry {
+                    InfoEntry::KeyValue(k, Value::Generic(v))
+                    | InfoEntry::KeyEnabledValue(k, _, Some(Value::Generic(v)))
+                        if k.eq_ignore_ascii_case(key) && !v.is_empty() =>
+                    {
+                        Some(v.clone())
+                    }
+                    _ => None,
+                })
+        })
+    }
+
+    /// Iterates over all log entries of all sections, depth-first.
+    pub fn log_entries(&self) -> impl Iterator<Item = &LogEntry> {
+        self.logs.iter().flat_map(Section::iter_all)
+    }
+
+    /// All log entries of all sections, depth-first, in the same order as [`Self::log_entries`].
+    pub fn log_entries_mut(&mut self) -> impl Iterator<Item = &mut LogEntry> {
+        self.logs.iter_mut().flat_map(Section::iter_all_mut)
+    }
+
+    /// All log entries of all sections in the order they were written, so that entries of
+    /// different sections (e.g. logcat and the app's own log) that cover the same time are
+    /// interleaved; entries written at the same time keep their order.
+    pub fn log_entries_by_time(&self) -> Vec<&LogEntry> {
+        let mut entries = self.log_entries().collect::<Vec<_>>();
+        entries.sort_by_key(|entry| entry.timestamp);
+        entries
+    }
+
+    /// The log entry with the 1-based `number`: entries are numbered through all sections,
+    /// depth-first, so that numbers increase within every section and are unique in the file.
+    pub fn log_entry_by_number(&self, number: usize) -> Option<&LogEntry> {
+        self.log_entries().nth(number.checked_sub(1)?)
+    }
+
+    /// Iterates over log sections that the `query` applies to, depth-first.
+    pub fn scoped_log_sections<'a>(
+        &'a self,
+        query: &'a SearchQuery,
+    ) -> impl Iterator<Item = &'a Section<LogEntry>> {
+        self.logs
+            .iter()
+            .flat_map(move |section| section.sections_in_scope(&query.section))
+    }
+
+    /// Iterates over log entries of all sections that match the `query`, depth-first.
+    pub fn filtered_log_entries<'a>(
+        &'a self,
+        query: &'a SearchQuery,
+    ) -> impl Iterator<Item = &'a LogEntry> {
+        self.scoped_log_sections(query)
+            .flat_map(move |section| section.filtered(query))
+    }
+
+    /// Log entries that are displayed on the Logs tab, i.e. match the `query` and aren't in
+    /// collapsed sections, in the order they're displayed in.
+    pub fn displayed_log_entries<'a>(
+        &'a self,
+        query: &'a SearchQuery,
+        collapsed_sections: &BTreeSet<String>,
+        newest_first: bool,
+    ) -> Vec<&'a LogEntry> {
+        fn collect<'a>(
+            section: &'a Section<LogEntry>,
+            query: &'a SearchQuery,
+            collapsed_sections: &BTreeSet<String>,
+            newest_first: bool,
+            in_scope: bool,
+            output: &mut Vec<&'a LogEntry>,
+        ) {
+            if collapsed_sections.contains(&section.name) {
+                return;
+            }
+
+            let in_scope = in_scope || section.name == query.section;
+            if in_scope && newest_first {
+                output.extend(section.filtered(query).rev());
+            } else if in_scope {
+                output.extend(section.filtered(query));
+            }
+
+            for subsection in &section.subsections {
+                collect(
+                    subsection,
+                    query,
+                    collapsed_sections,
+                    newest_first,
+                    in_scope,
+                    output,
+                );
+            }
+        }
+
+        let mut output = vec![];
+
+        for section in &self.logs {
+            collect(
+                section,
+                query,
+                collapsed_sections,
+                newest_first,
+                query.section.is_empty(),
+                &mut output,
+            );
+        }
+
+        output
+    }
+
+    /// The log entry that was parsed from the text at byte `offset`.
+    pub fn log_entry_at(&self, offset: usize) -> Option<&LogEntry> {
+        self.log_entries()
+            .find(|entry| entry.source.contains(&offset) || entry.source.start == offset)
+    }
+
+    /// The log entries with the given element IDs, in the order they were parsed from the text.
+    pub fn log_entries_with_ids(&self, ids: &BTreeSet<String>) -> Vec<&LogEntry> {
+        if ids.is_empty() {
+            return vec![];
+        }
+
+        let mut entries = self
+            .log_entries()
+            .filter(|entry| ids.contains(&entry.element_id()))
+            .collect::<Vec<_>>();
+        entries.sort_by_key(|entry| entry.source.start);
+        entries
+    }
+
+    /// The first information entry with the given `key`.
+    pub fn find_info_entry(&self, key: &str) -> Option<&InfoEntry> {
+        self.information
+            .iter()
+            .flat_map(Section::iter_all)
+            .find(|entry| entry.key() == Some(key))
+    }
+
+    /// IDs of all key prefix groups in the information sections.
+    pub fn info_group_ids(&self) -> Vec<String> {
+        self.information
+            .iter()
+            .flat_map(Section::iter_sections)
+            .flat_map(|section| {
+                section
+                    .groups()
+                    .into_iter()
+                    .filter_map(|(prefix, _)| prefix.map(|prefix| section.group_id(prefix)))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+impl<C> Section<C> {
+    /// Iterates over the content of this section and all of its subsections, depth-first.
+    pub fn iter_all(&self) -> Box<dyn Iterator<Item = &C> + '_> {
+        Box::new(
+            self.content
+                .iter()
+                .chain(self.subsections.iter().flat_map(Section::iter_all)),
+        )
+    }
+
+    /// Iterates mutably over the content of this section and all of its subsections,
+    /// depth-first.
+    pub fn iter_all_mut(&mut self) -> Box<dyn Iterator<Item = &mut C> + '_> {
+        Box::new(
+            self.content
+                .iter_mut()
+                .chain(self.subsections.iter_mut().flat_map(Section::iter_all_mut)),
+        )
+    }
+
+    /// Iterates over this section and all of its subsections, depth-first.
+    pub fn iter_sections(&self) -> Box<dyn Iterator<Item = &Section<C>> + '_> {
+        Box::new(
+            std::iter::once(self).chain(self.subsections.iter().flat_map(Section::iter_sections)),
+        )
+    }
+}
+
+impl Section<InfoEntry> {
+    /// Whether this section or any of its subsections has an entry that matches `filter`.
+    pub fn matches(&self, filter: &str) -> bool {
+        self.iter_all().any(|entry| entry.matches(filter))
+    }
+
+    /// Key-value entries of this section, grouped by the part of their key before the first `.`;
+    /// entries whose prefix is shared by too few others are in the `None` group, which is first.
+    pub fn groups(&self) -> Vec<(Option<&str>, Vec<&InfoEntry>)> {
+        fn prefix(entry: &InfoEntry) -> Option<&str> {
+            entry
+                .key()
+                .and_then(|key| key.split_once('.'))
+                .map(|(prefix, _)| prefix)
+        }
+
+        let mut groups: Vec<(Option<&str>, Vec<&InfoEntry>)> = vec![(None, vec![])];
+
+        for entry in &self.content {
+            let group_prefix = prefix(entry).filter(|group_prefix| {
+                self.content
+                    .iter()
+                    .filter(|other| prefix(other) == Some(group_prefix))
+                    .count()
+                    >= MIN_GROUP_SIZE
+            });
+
+            match groups.iter_mut().find(|(p, _)| *p == group_prefix) {
+                Some((_, entries)) => entries.push(entry),
+                None => groups.push((group_prefix, vec![entry])),
+            }
+        }
+
+        groups
+    }
+
+    /// ID of the group of entries with the key `prefix` in this section.
+    pub fn group_id(&self, prefix: &str) -> String {
+        format!("{}/{}", self.name, prefix)
+    }
+}
+
+impl InfoEntry {
+    pub fn key(&self) -> Option<&str> {
+        match self {
+            InfoEntry::KeyValue(key, _) | InfoEntry::KeyEnabledValue(key, _, _) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Whether the key or value of this entry contains `filter` (case-insensitively);
+    /// an empty `filter` matches all entries.
+    pub fn matches(&self, filter: &str) -> bool {
+        let filter = filter.trim().to_ascii_lowercase();
+
+        if filter.is_empty() {
+            return true;
+        }
+
+        let contains = |text: &str| text.to_ascii_lowercase().contains(&filter);
+
+        match self {
+            InfoEntry::KeyValue(key, value) => contains(key) || contains(&value.to_string()),
+            InfoEntry::KeyEnabledValue(key, enabled, value) => {
+                contains(key)
+                    || contains(if *enabled { "enabled" } else { "disabled" })
+                    || value.iter().any(|value| contains(&value.to_string()))
+            }
+            InfoEntry::GenericTable(table) => table
+                .header
+                .iter()
+                .chain(table.rows.iter().flatten())
+                .any(|item| contains(item)),
+            InfoEntry::Generic(text) => contains(text),
+            InfoEntry::RemoteObject(_) | InfoEntry::ExplicitNone => false,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Generic(value) => write!(f, "{}", value),
+            Value::BucketedFlag(buckets) => write!(
+                f,
+                "{}",
+                buckets
+                    .iter()
+                    .map(|bucket| format!("{}:{}", bucket.country_code, bucket.value))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+            Value::List(items) => write!(f, "[{}]", items.join(", ")),
+            Value::Json(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl Section<LogEntry> {
+    /// This section and all of its subsections, if it's named `scope` (or `scope` is empty);
+    /// otherwise, the subsections that are in the scope, depth-first.
+    pub fn sections_in_scope<'a>(
+        &'a self,
+        scope: &'a str,
+    ) -> Box<dyn Iterator<Item = &'a Section<LogEntry>> + 'a> {
+        if scope.is_empty() || self.name == scope {
+            self.iter_sections()
+        } else {
+            Box::new(
+                self.subsections
+                    .iter()
+                    .flat_map(move |subsection| subsection.sections_in_scope(scope)),
+            )
+        }
+    }
+
+    /// Entries of this section (not including subsections) that match the `query`,
+    /// regardless of its section scope.
+    pub fn filtered<'a>(
+        &'a self,
+        query: &'a SearchQuery,
+    ) -> impl DoubleEndedIterator<Item = &'a LogEntry> + Clone {
+        self.filtered_indices(query)
+            .map(move |index| &self.content[index])
+    }
+
+    /// Like [`Self::filtered`], but yields the indices of the entries in `content`.
+    pub fn filtered_indices<'a>(
+        &'a self,
+        query: &'a SearchQuery,
+    ) -> impl DoubleEndedIterator<Item = usize> + Clone + 'a {
+        let s = query.string.to_lowercase();
+        let tag = query.tag.trim();
+        let thread = query.thread.trim();
+        let process = query.process.as_str();
+
+        self.content
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| (entry, index))
+            .filter(|(entry, _)| entry.level.unwrap_or_default() >= query.min_log_level)
+            .filter(move |(entry, _)| {
+                tag.is_empty() || matches!(entry.meta.tag(), Some(t) if t.eq_ignore_ascii_case(tag))
+            })
+            .filter(move |(entry, _)| {
+                thread.is_empty() || matches!(entry.meta.thread_id(), Some(t) if t.trim() == thread)
+            })
+            .filter(move |(entry, _)| process.is_empty() || entry.meta.process() == Some(process))
+            .filter(move |(entry, _)| {
+                entry.original_timestamp.to_lowercase().contains(&s)
+                    || entry.message.to_lowercase().contains(&s)
+                    || entry.meta.contains(&s)
+            })
+            .map(|(_, index)| index)
+    }
+
+    /// Columns of the table of the entries of this section (not including its subsections), in
+    /// the order they're shown in; none if there are no entries.
+    pub fn columns(&self) -> Vec<LogColumn> {
+        let first = match self.content.first() {
+            Some(first) => first,
+            None => return vec![],
+        };
+
+        let mut columns = vec![LogColumn::Number];
+
+        if self.content.iter().any(|entry| entry.origin.is_some()) {
+            columns.push(LogColumn::Origin);
+        }
+
+        columns.extend([LogColumn::Timestamp, LogColumn::Delta, LogColumn::Level]);
+        // Assumes that all entries of a section have the same kind of metadata
+        columns.extend(first.meta.columns(self.shows_process()));
+        columns.push(LogColumn::Message);
+
+        columns
+    }
+
+    /// Whether the table has a Process column (only applicable to Signal Desktop).
+    pub fn shows_process(&self) -> bool {
+        self.content
+            .iter()
+            .any(|entry| entry.meta.process().is_some())
+    }
+}
+
+impl LogEntry {
+    /// ID of this entry within its file; views of several files prefix it with the file's, to
+    /// tell their entries apart.
+    pub fn element_id(&self) -> String {
+        format!("entry-{}", self.source.start)
+    }
+}
+
+impl fmt::Display for LogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.original_timestamp)?;
+
+        if let Some(level) = self.level {
+            write!(f, " {}", level.to_string().to_uppercase())?;
+        }
+
+        let meta = self.meta.to_string();
+        if !meta.is_empty() {
+            write!(f, " {}", meta)?;
+        }
+
+        write!(f, " {}", self.message)
+    }
+}
+
+impl fmt::Display for PlatformMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlatformMetadata::AndroidLogcat {
+                process_id,
+                thread_id,
+                tag,
+            } => write!(f, "{} {} {}", process_id, thread_id, tag),
+            PlatformMetadata::AndroidLogger {
+                version,
+                thread_id,
+                tag,
+            } => write!(f, "[{}] [{}] {}", version, thread_id, tag),
+            PlatformMetadata::Ios(Some(meta)) if meta.symbol.is_empty() => {
+                write!(f, "[{}:{}]", meta.file, meta.line)
+            }
+            PlatformMetadata::Ios(Some(meta)) => {
+                write!(f, "[{}:{} {}]", meta.file, meta.line, meta.symbol)
+            }
+            PlatformMetadata::Desktop(Some(meta)) => write!(f, "[{}]", meta.process),
+            PlatformMetadata::Ios(None) | PlatformMetadata::Desktop(None) => Ok(()),
+        }
+    }
+}
+
+impl PlatformMetadata {
+    pub fn tag(&self) -> Option<&str> {
+        match self {
+            PlatformMetadata::AndroidLogcat { tag, .. }
+            | PlatformMetadata::AndroidLogger { tag, .. } => Some(tag),
+            PlatformMetadata::Ios(_) | PlatformMetadata::Desktop(_) => None,
+        }
+    }
+
+    pub fn thread_id(&self) -> Option<&str> {
+        match self {
+            PlatformMetadata::AndroidLogcat { thread_id, .. }
+            | PlatformMetadata::AndroidLogger { thread_id, .. } => Some(thread_id),
+            PlatformMetadata::Ios(_) | PlatformMetadata::Desktop(_) => None,
+        }
+    }
+
+    pub fn process(&self) -> Option<&str> {
+        match self {
+            PlatformMetadata::Desktop(Some(meta)) => Some(&meta.process),
+            _ => None,
+        }
+    }
+
+    pub fn contains(&self, s: &str) -> bool {
+        match &self {
+            PlatformMetadata::AndroidLogcat {
+                process_id,
+                thread_id,
+                tag,
+            } => process_id.contains(s) || thread_id.contains(s) || tag.contains(s),
+            PlatformMetadata::AndroidLogger {
+                version,
+                thread_id,
+                tag,
+            } => version.contains(s) || thread_id.contains(s) || tag.contains(s),
+            PlatformMetadata::Ios(Some(meta)) => {
+                meta.file.to_lowercase().contains(s)
+                    || meta.line.to_lowercase().contains(s)
+                    || meta.symbol.to_lowercase().contains(s)
+            }
+            PlatformMetadata::Desktop(Some(meta)) => meta.process.contains(s),
+            PlatformMetadata::Ios(None) | PlatformMetadata::Desktop(None) => false,
+        }
+    }
+
+    /// Names and values of the metadata fields, in the order of the table columns.
+    pub fn fields(&self) -> Vec<(&'static str, &str)> {
+        match self {
+            PlatformMetadata::AndroidLogcat {
+                process_id,
+                thread_id,
+                tag,
+            } => vec![("Process", process_id), ("Thread", thread_id), ("Tag", tag)],
+            PlatformMetadata::AndroidLogger {
+                version,
+                thread_id,
+                tag,
+            } => vec![("Version", version), ("Thread", thread_id), ("Tag", tag)],
+            PlatformMetadata::Ios(Some(meta)) => vec![
+                ("File", &meta.file),
+                ("Line", &meta.line),
+                ("Symbol", &meta.symbol),
+            ],
+            PlatformMetadata::Desktop(Some(meta)) => vec![("Process", &meta.process)],
+            PlatformMetadata::Ios(None) | PlatformMetadata::Desktop(None) => vec![],
+        }
+    }
+
+    /// Table columns for the metadata, in the order of [`Self::fields`].
+    pub fn columns(&self, show_process: bool) -> Vec<LogColumn> {
+        match self {
+            PlatformMetadata::AndroidLogcat { .. } => {
+                vec![LogColumn::Process, LogColumn::Thread, LogColumn::Tag]
+            }
+            PlatformMetadata::AndroidLogger { .. } => {
+                vec![LogColumn::Version, LogColumn::Thread, LogColumn::Tag]
+            }
+            PlatformMetadata::Ios(_) => vec![LogColumn::File, LogColumn::Line, LogColumn::Symbol],
+            PlatformMetadata::Desktop(_) if show_process => vec![LogColumn::Process],
+            PlatformMetadata::Desktop(_) => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("1000000" => Some(100.0); "full rollout")]
+    #[test_case("2500" => Some(0.25); "partial rollout")]
+    #[test_case("abc" => None; "not a number")]
+    fn bucket_percentage_ok(value: &str) -> Option<f64> {
+        Bucket {
+            country_code: "*".to_owned(),
+            value: value.to_owned(),
+        }
+        .percentage()
+    }
+
+    #[test_case("" => true; "empty filter")]
+    #[test_case("  CAPABILITY " => true; "key, case-insensitive")]
+    #[test_case("supported" => true; "value")]
+    #[test_case("abc" => false; "no match")]
+    fn info_entry_matches_ok(filter: &str) -> bool {
+        InfoEntry::KeyValue(
+            "Capability Name".to_owned(),
+            Value::Generic("NOT_SUPPORTED".to_owned()),
+        )
+        .matches(filter)
+    }
+
+    #[test]
+    fn section_groups_ok() {
+        let key_value = |key: &str| InfoEntry::KeyValue(key.to_owned(), Value::default());
+
+        let section = Section {
+            name: "FLAGS".to_owned(),
+            content: vec![
+                key_value("android.a"),
+                key_value("plain"),
+                key_value("android.b"),
+                key_value("global.a"),
+                key_value("android.c"),
+            ],
+            subsections: vec![],
+        };
+
+        assert_eq!(
+            section
+                .groups()
+                .into_iter()
+                .map(|(prefix, entries)| (
+                    prefix,
+                    entries
+                        .iter()
+                        .filter_map(|entry| entry.key())
+                        .collect::<Vec<_>>()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (None, vec!["plain", "global.a"]),
+                (Some("android"), vec!["android.a", "android.b", "android.c"]),
+            ]
+        );
+        assert_eq!(section.group_id("android"), "FLAGS/android");
+    }
+
+    #[test_case("" => vec!["a", "b", "c"]; "all sections")]
+    #[test_case("LOGGER" => vec!["b", "c"]; "section with subsection")]
+    #[test_case("Previous" => vec!["c"]; "subsection")]
+    #[test_case("Unknown" => Vec::<String>::new(); "unknown section")]
+    fn filtered_log_entries_ok(section: &str) -> Vec<String> {
+        let entry = |message: &str| LogEntry {
+            level: Some(LogLevel::Info),
+            ..LogEntry::test(message, 0)
+        };
+
+        let content = Content {
+            information: vec![],
+            logs: vec![
+                Section {
+                    name: "LOGCAT".to_owned(),
+                    content: vec![entry("a")],
+                    subsections: vec![],
+                },
+                Section {
+                    name: "LOGGER".to_owned(),
+                    content: vec![entry("b")],
+                    subsections: vec![Section {
+                        name: "Previous".to_owned(),
+                        content: vec![entry("c")],
+                        subsections: vec![],
+                    }],
+                },
+            ],
+        };
+
+        let query = SearchQuery {
+            min_log_level: LogLevel::Info,
+            section: section.to_owned(),
+            ..Default::default()
+        };
+
+        content
+            .filtered_log_entries(&query)
+            .map(|entry| entry.message.clone())
+            .collect()
+    }
+
+    #[test_case(0 => None; "zero")]
+    #[test_case(1 => Some("a".to_owned()); "first")]
+    #[test_case(3 => Some("c".to_owned()); "in subsection")]
+    #[test_case(4 => Some("d".to_owned()); "after subsection")]
+    #[test_case(5 => None; "too large")]
+    fn log_entry_by_number_ok(number: usize) -> Option<String> {
+        let entry = |message: &str| LogEntry {
+            level: Some(LogLevel::Info),
+            ..LogEntry::test(message, 0)
+        };
+
+        let content = Content {
+            information: vec![],
+            logs: vec![
+                Section {
+                    name: "LOGCAT".to_owned(),
+                    content: vec![entry("a"), entry("b")],
+                    subsections: vec![Section {
+                        name: "Previous".to_owned(),
+                        content: vec![entry("c")],
+                        subsections: vec![],
+                    }],
+                },
+                Section {
+                    name: "LOGGER".to_owned(),
+                    content: vec![entry("d")],
+                    subsections: vec![],
+                },
+            ],
+        };
+
+        content
+            .log_entry_by_number(number)
+            .map(|entry| entry.message.clone())
+    }
+
+    #[test]
+    fn section_columns_ok() {
+        let entry = |meta| LogEntry {
+            meta,
+            ..LogEntry::test("", 0)
+        };
+        let section = |content| Section {
+            name: "LOGS".to_owned(),
+            content,
+            subsections: vec![],
+        };
+
+        assert_eq!(section(vec![]).columns(), vec![]);
+        assert_eq!(
+            section(vec![entry(PlatformMetadata::Desktop(None))]).columns(),
+            vec![
+                LogColumn::Number,
+                LogColumn::Timestamp,
+                LogColumn::Delta,
+                LogColumn::Level,
+                LogColumn::Message
+            ]
+        );
+        assert_eq!(
+            section(vec![LogEntry {
+                origin: Some(AppId::Signal),
+                ..entry(PlatformMetadata::Ios(None))
+            }])
+            .columns(),
+            vec![
+                LogColumn::Number,
+                LogColumn::Origin,
+                LogColumn::Timestamp,
+                LogColumn::Delta,
+                LogColumn::Level,
+                LogColumn::File,
+                LogColumn::Line,
+                LogColumn::Symbol,
+                LogColumn::Message,
+            ]
+        );
+    }
+
+    #[test]
+    fn platform_metadata_fields_ok() {
+        assert_eq!(
+            PlatformMetadata::AndroidLogger {
+                version: "1.2.3".to_owned(),
+                thread_id: "45".to_owned(),
+                tag: "Job".to_owned(),
+            }
+            .fields(),
+            vec![("Version", "1.2.3"), ("Thread", "45"), ("Tag", "Job")]
+        );
+        assert_eq!(PlatformMetadata::Desktop(None).fields(), vec![]);
+    }
+
+    #[test]
+    fn content_json_round_trip_ok() {
+        let content = Content {
+            information: vec![Section {
+                name: "System".to_owned(),
+                content: vec![
+                    InfoEntry::KeyValue(
+                        "Config".to_owned(),
+                        Value::Json(serde_json::json!({ "enabled": true })),
+                    ),
+                    InfoEntry::KeyEnabledValue("Flag".to_owned(), false, None),
+                ],
+                subsections: vec![],
+            }],
+            logs: vec![Section {
+                name: "Logs".to_owned(),
+                content: vec![LogEntry {
+                    timestamp: Utc.ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789),
+                    level: Some(LogLevel::Warn),
+                    meta: PlatformMetadata::Ios(Some(ios::LogEntryMetadata {
+                        file: "Item.abc".to_owned(),
+                        line: "12".to_owned(),
+                        symbol: "-[Item handleSomething]".to_owned(),
+                    })),
+                    origin: Some(AppId::NotificationServiceExtension),
+                    ..LogEntry::test("Something happened", 3)
+                }],
+                subsections: vec![],
+            }],
+        };
+
+        let json = serde_json::to_string(&content).unwrap();
+
+        assert_eq!(serde_json::from_str::<Content>(&json).unwrap(), content);
+    }
+}
@@ -0,0 +1,56 @@
+use derive_more::{Display, IsVariant};
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+#[derive(Debug, Display, Clone, Copy, PartialEq, IsVariant, EnumIter, Serialize, Deserialize)]
+pub enum Platform {
+    Android,
+    #[display(fmt = "iOS")]
+    Ios,
+    Desktop,
+}
+
+impl Platform {
+    /// Guesses the platform of a debug log from its text, by the sections only Signal Android
+    /// and Signal Desktop logs have; anything else is taken as a single Signal iOS log file.
+    pub fn detect(text: &str) -> Platform {
+        let section_names = text.lines().filter_map(|line| {
+            let line = line.trim();
+            (line.starts_with('=') && line.ends_with('='))
+                .then(|| line.trim_matches(|c: char| c == '=' || c.is_whitespace()))
+        });
+
+        for name in section_names {
+            match name {
+                "LOGCAT" | "LOGGER" => return Platform::Android,
+                "Logs" => return Platform::Desktop,
+                _ => {}
+            }
+        }
+
+        Platform::Ios
+    }
+
+    pub const fn debuglogs_url_ending(&self) -> &'static str {
+        match &self {
+            Platform::Android => "",
+            Platform::Ios => ".zip",
+            Platform::Desktop => ".gz",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("========= SYSINFO =========\nTime: 123\n\n========= LOGCAT =========\n" => Platform::Android; "android")]
+    #[test_case("========= System info =========\nUser agent: abc\n\n========= Logs =========\n" => Platform::Desktop; "desktop")]
+    #[test_case("2021/01/23 12:34:56:789 💛 [Item.swift:1 f()]: Message\n" => Platform::Ios; "ios")]
+    #[test_case("" => Platform::Ios; "empty")]
+    fn detect_ok(text: &str) -> Platform {
+        Platform::detect(text)
+    }
+}
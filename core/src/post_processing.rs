@@ -1,6 +1,9 @@
 use std::borrow::Cow;
 
-use crate::parsers::LogEntry;
+use crate::{
+    parsers::{Content, LogEntry, Section},
+    redaction,
+};
 
 pub fn collapse_log_entries(entries: Vec<LogEntry>) -> Vec<LogEntry> {
     let mut first_entry_of_group: Option<Cow<'_, LogEntry>> = None;
@@ -15,6 +18,7 @@ pub fn collapse_log_entries(entries: Vec<LogEntry>) -> Vec<LogEntry> {
                 {
                     first_entry.to_mut().message =
                         first_entry.message.clone() + "\n" + &entry.message;
+                    first_entry.to_mut().source.end = entry.source.end;
                     None
                 } else {
                     let result = first_entry.clone().into_owned();
@@ -36,9 +40,26 @@ pub fn collapse_log_entries(entries: Vec<LogEntry>) -> Vec<LogEntry> {
     entries
 }
 
+/// Marks parts of log messages that look like identifiers, so that they can be highlighted.
+pub fn annotate_sensitive_data(content: &mut Content) {
+    fn annotate_section(section: &mut Section<LogEntry>) {
+        for entry in &mut section.content {
+            entry.sensitive = redaction::find_identifiers(&entry.message);
+        }
+
+        for subsection in &mut section.subsections {
+            annotate_section(subsection);
+        }
+    }
+
+    for section in &mut content.logs {
+        annotate_section(section);
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{parsers::PlatformMetadata, LogLevel};
+    use crate::{parsers::PlatformMetadata, redaction::Identifier, LogLevel};
     use chrono::prelude::*;
 
     use super::*;
@@ -46,7 +67,8 @@ mod tests {
     #[test]
     fn collapse_log_entries_ok_android_logcat() {
         let entry1 = LogEntry {
-            timestamp: Utc
+            timestamp: Utc.ymd(1234, 1, 22).and_hms_milli(12, 34, 56, 789),
+            original_timestamp: Utc
                 .ymd(1234, 1, 22)
                 .and_hms_milli(12, 34, 56, 789)
                 .to_string(),
@@ -56,7 +78,8 @@ mod tests {
                 thread_id: "12367".to_owned(),
                 tag: "abc".to_owned(),
             },
-            message: "Part 1".to_owned(),
+            source: 0..10,
+            ..LogEntry::test("Part 1", 0)
         };
 
         let entries = vec![
@@ -66,10 +89,12 @@ mod tests {
             },
             LogEntry {
                 message: "Part 2".to_owned(),
+                source: 11..21,
                 ..entry1.clone()
             },
             LogEntry {
                 message: "Part 3".to_owned(),
+                source: 22..32,
                 ..entry1.clone()
             },
         ];
@@ -80,8 +105,42 @@ mod tests {
             result,
             vec![LogEntry {
                 message: "Part 1\nPart 2\nPart 3".to_owned(),
+                source: 0..32,
                 ..entry1
             }]
         );
     }
+
+    #[test]
+    fn annotate_sensitive_data_ok() {
+        let entry = LogEntry {
+            timestamp: Utc.ymd(1234, 1, 23).and_hms_milli(12, 34, 56, 789),
+            original_timestamp: "1234-01-23 12:34:56.789 UTC".to_owned(),
+            level: Some(LogLevel::Info),
+            ..LogEntry::test("Sending to +14155550123 in __signal_group__abcdef", 0)
+        };
+
+        let mut content = Content {
+            information: vec![],
+            logs: vec![Section {
+                name: "Logs".to_owned(),
+                content: vec![],
+                subsections: vec![Section {
+                    name: "Subsection".to_owned(),
+                    content: vec![entry],
+                    subsections: vec![],
+                }],
+            }],
+        };
+
+        annotate_sensitive_data(&mut content);
+
+        assert_eq!(
+            content.logs[0].subsections[0].content[0].sensitive,
+            vec![
+                (11..23, Identifier::PhoneNumber),
+                (27..49, Identifier::GroupId),
+            ]
+        );
+    }
 }
@@ -0,0 +1,231 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+const GROUP_ID_PREFIX: &str = "__signal_group__";
+const GROUP_ID_V2_PREFIX: &str = "v2__!";
+const UUID_LENGTH: usize = 36;
+const PHONE_NUMBER_DIGITS: std::ops::RangeInclusive<usize> = 7..=15;
+const KEPT_CHARACTERS: usize = 3;
+const KEPT_PHONE_NUMBER_DIGITS: usize = 2;
+
+/// The kind of identifier that was found in a text.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Identifier {
+    #[display(fmt = "Phone number")]
+    PhoneNumber,
+    #[display(fmt = "E-mail address")]
+    Email,
+    #[display(fmt = "UUID")]
+    Uuid,
+    #[display(fmt = "Group ID")]
+    GroupId,
+}
+
+/// Masks identifiers that may still be present in `text` (phone numbers, e-mail addresses,
+/// UUIDs/ACIs and group IDs), keeping a few trailing characters so that occurrences of the same
+/// identifier can still be told apart.
+pub fn redact(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut copied_until = 0;
+
+    for (range, identifier) in find_identifiers(text) {
+        output.push_str(&text[copied_until..range.start]);
+        output.push_str(&mask(&text[range.clone()], identifier));
+        copied_until = range.end;
+    }
+
+    output.push_str(&text[copied_until..]);
+    output
+}
+
+/// Redacts `text` only if `enabled`, otherwise returns it unchanged.
+pub fn redact_if(enabled: bool, text: String) -> String {
+    if enabled {
+        redact(&text)
+    } else {
+        text
+    }
+}
+
+/// Finds all identifiers in `text`, in order and without overlaps.
+pub fn find_identifiers(text: &str) -> Vec<(std::ops::Range<usize>, Identifier)> {
+    let bytes = text.as_bytes();
+    let mut found = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let previous = i.checked_sub(1).map(|j| bytes[j]);
+
+        let matched = group_id_length(&bytes[i..])
+            .map(|length| (length, Identifier::GroupId))
+            .or_else(|| uuid_length(&bytes[i..], previous).map(|length| (length, Identifier::Uuid)))
+            .or_else(|| {
+                phone_number_length(&bytes[i..], previous)
+                    .map(|length| (length, Identifier::PhoneNumber))
+            })
+            .or_else(|| {
+                email_length(&bytes[i..], previous).map(|length| (length, Identifier::Email))
+            });
+
+        match matched {
+            Some((length, identifier)) => {
+                found.push((i..i + length, identifier));
+                i += length;
+            }
+            None => i += 1,
+        }
+    }
+
+    found
+}
+
+fn mask(identifier: &str, kind: Identifier) -> String {
+    let keep_last = |s: &str, keep: usize| {
+        let masked = s.len().saturating_sub(keep);
+        "*".repeat(masked) + &s[masked..]
+    };
+
+    match kind {
+        Identifier::PhoneNumber => {
+            "+".to_owned() + &keep_last(&identifier[1..], KEPT_PHONE_NUMBER_DIGITS)
+        }
+        Identifier::Email => "***@***".to_owned(),
+        Identifier::Uuid => {
+            let kept = &identifier[UUID_LENGTH - KEPT_CHARACTERS..];
+            "********-****-****-****-*********".to_owned() + kept
+        }
+        Identifier::GroupId => {
+            let prefix_length = GROUP_ID_PREFIX.len()
+                + if identifier[GROUP_ID_PREFIX.len()..].starts_with(GROUP_ID_V2_PREFIX) {
+                    GROUP_ID_V2_PREFIX.len()
+                } else {
+                    0
+                };
+
+            identifier[..prefix_length].to_owned()
+                + &keep_last(&identifier[prefix_length..], KEPT_CHARACTERS)
+        }
+    }
+}
+
+fn is_word(byte: Option<u8>) -> bool {
+    matches!(byte, Some(byte) if byte.is_ascii_alphanumeric() || byte == b'_')
+}
+
+fn group_id_length(bytes: &[u8]) -> Option<usize> {
+    let rest = bytes.strip_prefix(GROUP_ID_PREFIX.as_bytes())?;
+    let (prefix_length, rest) = match rest.strip_prefix(GROUP_ID_V2_PREFIX.as_bytes()) {
+        Some(rest) => (GROUP_ID_PREFIX.len() + GROUP_ID_V2_PREFIX.len(), rest),
+        None => (GROUP_ID_PREFIX.len(), rest),
+    };
+
+    let id_length = rest
+        .iter()
+        .take_while(|byte| byte.is_ascii_alphanumeric())
+        .count();
+
+    if id_length > 0 {
+        Some(prefix_length + id_length)
+    } else {
+        None
+    }
+}
+
+fn uuid_length(bytes: &[u8], previous: Option<u8>) -> Option<usize> {
+    if is_word(previous) || bytes.len() < UUID_LENGTH {
+        return None;
+    }
+
+    let is_uuid = bytes[..UUID_LENGTH]
+        .iter()
+        .enumerate()
+        .all(|(i, byte)| match i {
+            8 | 13 | 18 | 23 => *byte == b'-',
+            _ => byte.is_ascii_hexdigit(),
+        });
+
+    if is_uuid && !is_word(bytes.get(UUID_LENGTH).copied()) {
+        Some(UUID_LENGTH)
+    } else {
+        None
+    }
+}
+
+fn phone_number_length(bytes: &[u8], previous: Option<u8>) -> Option<usize> {
+    if bytes.first() != Some(&b'+') || is_word(previous) {
+        return None;
+    }
+
+    let digits = bytes[1..]
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+
+    if PHONE_NUMBER_DIGITS.contains(&digits) && !is_word(bytes.get(1 + digits).copied()) {
+        Some(1 + digits)
+    } else {
+        None
+    }
+}
+
+fn email_length(bytes: &[u8], previous: Option<u8>) -> Option<usize> {
+    let is_local_byte = |byte: &u8| byte.is_ascii_alphanumeric() || b"._%+-".contains(byte);
+    let is_domain_byte = |byte: &u8| byte.is_ascii_alphanumeric() || b".-".contains(byte);
+
+    if matches!(previous, Some(byte) if is_local_byte(&byte)) {
+        return None;
+    }
+
+    let local_length = bytes.iter().take_while(|b| is_local_byte(b)).count();
+
+    if local_length == 0 || bytes.get(local_length) != Some(&b'@') {
+        return None;
+    }
+
+    let domain = &bytes[local_length + 1..];
+    let mut domain_length = domain.iter().take_while(|b| is_domain_byte(b)).count();
+
+    while domain_length > 0 && domain[domain_length - 1] == b'.' {
+        domain_length -= 1;
+    }
+
+    let domain = &domain[..domain_length];
+    let has_dot = domain.contains(&b'.') && domain.first() != Some(&b'.');
+
+    if has_dot {
+        Some(local_length + 1 + domain_length)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("Sent to +14155550123." => "Sent to +*********23."; "phone number")]
+    #[test_case("Version +12345 (456)" => "Version +12345 (456)"; "too short for a phone number")]
+    #[test_case("Contact: user.name+tag@example.co.uk." => "Contact: ***@***."; "email")]
+    #[test_case("a@b is not an email" => "a@b is not an email"; "not an email")]
+    #[test_case(
+        "Recipient 01234567-89ab-cdef-0123-456789abcdef, ok" =>
+        "Recipient ********-****-****-****-*********def, ok";
+        "uuid"
+    )]
+    #[test_case(
+        "Group __signal_group__v2__!0123456789abcdef updated" =>
+        "Group __signal_group__v2__!*************def updated";
+        "group id v2"
+    )]
+    #[test_case(
+        "Group __signal_group__0123456789abcdef updated" =>
+        "Group __signal_group__*************def updated";
+        "group id v1"
+    )]
+    #[test_case("Nothing to see here ✅" => "Nothing to see here ✅"; "no identifiers")]
+    fn redact_ok(input: &str) -> String {
+        redact(input)
+    }
+}
@@ -1,3 +1,6 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, ensure, Context};
 use nom::{
     branch::alt,
     bytes::complete::{is_a, is_not, tag},
@@ -5,18 +8,23 @@ use nom::{
     sequence::{preceded, separated_pair, terminated, tuple},
     IResult,
 };
+use serde::{Deserialize, Serialize};
 
-use crate::{impl_from_str, Platform};
+use crate::Platform;
 
 pub const KEY_LENGTH: usize = 64;
 pub const BASE_DEBUGLOGS_URL: &str = "https://debuglogs.org/";
 pub const BASE_WORKER_URL: &str = "https://getlogs.warp.workers.dev/";
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RemoteObject {
     platform: Platform,
     version: Option<String>,
     key: String,
+    /// Where the debug log is fetched from directly, if it isn't on debuglogs.org; its platform
+    /// is only known once it's fetched.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direct_url: Option<String>,
 }
 
 impl RemoteObject {
@@ -25,9 +33,86 @@ impl RemoteObject {
             platform,
             version,
             key: key.to_owned(),
+            direct_url: None,
         }
     }
 
+    /// Parses a debug log URL, which is either on debuglogs.org (or just its key, for which the
+    /// `platform` has to be given if it has no extension) or (with any path) on one of the
+    /// `allowed_hosts`; an allowed host without a port allows any port. The key of a debug log on
+    /// another host is the name of its file, and its platform is guessed from the extension.
+    pub fn from_url(
+        url: &str,
+        allowed_hosts: &[&str],
+        platform: Option<Platform>,
+    ) -> anyhow::Result<Self> {
+        if let Some(platform) = platform.filter(|_| needs_platform(url)) {
+            return format!(
+                "{}{}{}",
+                BASE_DEBUGLOGS_URL,
+                key_only(url),
+                platform.debuglogs_url_ending()
+            )
+            .parse();
+        }
+
+        ensure!(
+            !needs_platform(url),
+            "only the key of the debug log was given; choose its platform"
+        );
+        if let Ok(remote_object) = url.parse::<RemoteObject>() {
+            return Ok(remote_object);
+        }
+
+        let (scheme, rest) = url.split_once("://").context("not a URL")?;
+        ensure!(
+            scheme.eq_ignore_ascii_case("https") || scheme.eq_ignore_ascii_case("http"),
+            "only HTTP(S) URLs are supported"
+        );
+
+        let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+        let hostname = host.split(':').next().unwrap_or_default();
+        ensure!(
+            allowed_hosts
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(host)
+                    || allowed.eq_ignore_ascii_case(hostname)),
+            "neither a debuglogs.org URL nor on an allowed host (see the Settings)"
+        );
+
+        let path = rest[host.len()..]
+            .split(['?', '#'])
+            .next()
+            .unwrap_or_default();
+        let filename = path.rsplit('/').next().unwrap_or_default();
+        let (key, extension) = filename.split_once('.').unwrap_or((filename, ""));
+
+        let platform = match extension {
+            "zip" => Platform::Ios,
+            "gz" | "log.gz" => Platform::Desktop,
+            _ => Platform::Android,
+        };
+
+        Ok(Self {
+            direct_url: Some(url.to_owned()),
+            ..Self::new_unchecked(
+                platform,
+                None,
+                if key.is_empty() { "debuglog" } else { key },
+            )
+        })
+    }
+
+    /// Whether the debug log is fetched from another host than debuglogs.org.
+    pub fn is_direct(&self) -> bool {
+        self.direct_url.is_some()
+    }
+
+    /// Sets the platform of a debug log from another host, once it's known from its content.
+    pub fn with_platform(self, platform: Platform) -> Self {
+        Self { platform, ..self }
+    }
+
     pub fn platform(&self) -> Platform {
         self.platform
     }
@@ -40,7 +125,12 @@ impl RemoteObject {
         &self.key
     }
 
+    /// URL the debug log was shared with, which is on another host for direct ones.
     pub fn debuglogs_url(&self) -> String {
+        if let Some(direct_url) = &self.direct_url {
+            return direct_url.clone();
+        }
+
         match &self.version {
             Some(version) => format!(
                 "{}{}/{}/{}{}",
@@ -60,6 +150,10 @@ impl RemoteObject {
     }
 
     pub fn fetchable_url(&self) -> String {
+        if let Some(direct_url) = &self.direct_url {
+            return direct_url.clone();
+        }
+
         format!(
             "{}{}/{}{}",
             BASE_WORKER_URL,
@@ -125,7 +219,49 @@ pub fn remote_object(input: &str) -> IResult<&str, RemoteObject> {
     )(input)
 }
 
-impl_from_str!(remote_object => RemoteObject);
+/// Drops the query (e.g. tracking parameters added by mail clients) and fragment of the input.
+fn key_only(input: &str) -> String {
+    input
+        .trim()
+        .split(['?', '#'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+/// Whether the input is only the key of a debug log on debuglogs.org, without an extension that
+/// would tell its platform.
+pub fn needs_platform(input: &str) -> bool {
+    let key = key_only(input);
+    key.len() == KEY_LENGTH && key.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Parses a debuglogs.org URL as it's commonly shared: possibly with query parameters or a
+/// fragment, without the scheme, or as only the key and extension (a key alone is taken as
+/// Signal Android's, which has none).
+impl FromStr for RemoteObject {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = key_only(input);
+        let path = input
+            .strip_prefix("https://")
+            .or_else(|| input.strip_prefix("http://"))
+            .unwrap_or(&input);
+        let path = path.strip_prefix("debuglogs.org/").unwrap_or(path);
+
+        let url = format!("{}{}", BASE_DEBUGLOGS_URL, path);
+        let (remainder, remote_object) = remote_object(&url)
+            .map_err(|e| anyhow!("couldn't parse `{}` as a debuglogs.org URL: {:?}", url, e))?;
+        ensure!(
+            remainder.is_empty(),
+            "unexpected `{}` after the debuglogs.org URL",
+            remainder
+        );
+
+        Ok(remote_object)
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -296,4 +432,53 @@ mod tests {
     fn fetchable_url_new(input: RemoteObject) -> String {
         input.fetchable_url()
     }
+
+    #[test_case(
+        "https://debuglogs.org/0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123.ZIP", &[] =>
+        (Platform::Ios, "0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123".to_owned(), "https://getlogs.warp.workers.dev/ios/0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123".to_owned());
+        "debuglogs"
+    )]
+    #[test_case(
+        "https://Logs.example.com/shared/Debug-Log.zip?token=abc", &["logs.example.com"] =>
+        (Platform::Ios, "Debug-Log".to_owned(), "https://Logs.example.com/shared/Debug-Log.zip?token=abc".to_owned());
+        "allowed host"
+    )]
+    #[test_case(
+        "http://localhost:8080/log.txt", &["localhost"] =>
+        (Platform::Android, "log".to_owned(), "http://localhost:8080/log.txt".to_owned());
+        "any port"
+    )]
+    #[test_case(
+        "https://pastebin.com/raw/AbC123", &["pastebin.com"] =>
+        (Platform::Android, "AbC123".to_owned(), "https://pastebin.com/raw/AbC123".to_owned());
+        "no extension"
+    )]
+    fn from_url_ok(url: &str, allowed_hosts: &[&str]) -> (Platform, String, String) {
+        let remote_object = RemoteObject::from_url(url, allowed_hosts, None).unwrap();
+        (
+            remote_object.platform(),
+            remote_object.key().to_owned(),
+            remote_object.fetchable_url(),
+        )
+    }
+
+    #[test_case("0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123", &[]; "key without platform")]
+    #[test_case("https://pastebin.com/raw/AbC123", &[]; "not allowed")]
+    #[test_case("https://localhost:8080/log.txt", &["localhost:8000"]; "other port")]
+    #[test_case("ftp://logs.example.com/log.txt", &["logs.example.com"]; "other scheme")]
+    #[test_case("logs.example.com/log.txt", &["logs.example.com"]; "no scheme")]
+    fn from_url_err(url: &str, allowed_hosts: &[&str]) {
+        assert!(RemoteObject::from_url(url, allowed_hosts, None).is_err());
+    }
+
+    #[test_case("https://debuglogs.org/ios/1.2.3/0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123.zip?utm_source=mail&utm_medium=x#top", None => "https://debuglogs.org/ios/1.2.3/0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123.zip".to_owned(); "query and fragment")]
+    #[test_case(" debuglogs.org/0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123.gz ", None => "https://debuglogs.org/0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123.gz".to_owned(); "no scheme")]
+    #[test_case("0123456789ABCDEFabcd0123456789abcdefabcd0123456789abcdefabcd0123.zip", None => "https://debuglogs.org/0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123.zip".to_owned(); "key with extension")]
+    #[test_case("0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123", Some(Platform::Desktop) => "https://debuglogs.org/0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123.gz".to_owned(); "key with platform")]
+    #[test_case("https://debuglogs.org/0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123.zip", Some(Platform::Desktop) => "https://debuglogs.org/0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123.zip".to_owned(); "platform only for keys")]
+    fn from_url_ok_normalized(url: &str, platform: Option<Platform>) -> String {
+        RemoteObject::from_url(url, &[], platform)
+            .unwrap()
+            .debuglogs_url()
+    }
 }
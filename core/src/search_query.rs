@@ -0,0 +1,28 @@
+use crate::LogLevel;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SearchQuery {
+    pub min_log_level: LogLevel,
+    pub string: String,
+    /// Exact (case-insensitive) tag of Android log entries; empty to not filter by tag.
+    pub tag: String,
+    /// Exact thread ID of Android log entries; empty to not filter by thread.
+    pub thread: String,
+    /// Process of Signal Desktop log entries; empty to not filter by process.
+    pub process: String,
+    /// Name of the log section (including its subsections) to search in; empty to search all.
+    pub section: String,
+}
+
+impl Default for SearchQuery {
+    fn default() -> Self {
+        Self {
+            min_log_level: LogLevel::Error,
+            string: Default::default(),
+            tag: Default::default(),
+            thread: Default::default(),
+            process: Default::default(),
+            section: Default::default(),
+        }
+    }
+}
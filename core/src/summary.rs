@@ -0,0 +1,93 @@
+use chrono::prelude::*;
+
+use crate::{parsers::Content, RemoteObject};
+
+const VERSION_KEYS: &[&str] = &["App", "App version", "Version"];
+const DEVICE_KEYS: &[&str] = &["Model", "Device"];
+const OS_KEYS: &[&str] = &["Android", "OS version", "OS", "Platform"];
+const LOCALE_KEYS: &[&str] = &["Locale"];
+/// Keys of the time the log was captured at, in milliseconds since the Unix epoch.
+const CAPTURE_TIME_KEYS: &[&str] = &["Time"];
+
+/// Values that nearly every triage starts with, extracted from the parsed debug log.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LogSummary {
+    pub version: Option<String>,
+    pub device: Option<String>,
+    pub os: Option<String>,
+    pub locale: Option<String>,
+    /// Capture time from the information sections, or else the time of the last log entry.
+    pub captured: Option<String>,
+}
+
+impl LogSummary {
+    pub fn new(remote_object: &RemoteObject, content: &Content) -> Self {
+        let captured = content
+            .find_info_value(CAPTURE_TIME_KEYS)
+            .and_then(|millis| millis.parse().ok())
+            .and_then(|millis| Utc.timestamp_millis_opt(millis).single())
+            .map(|dt| dt.to_string())
+            .or_else(|| {
+                content
+                    .log_entries()
+                    .map(|entry| entry.timestamp)
+                    .max()
+                    .map(|dt| dt.to_string())
+            });
+
+        Self {
+            version: remote_object
+                .version()
+                .clone()
+                .or_else(|| content.find_info_value(VERSION_KEYS)),
+            device: content.find_info_value(DEVICE_KEYS),
+            os: content.find_info_value(OS_KEYS),
+            locale: content.find_info_value(LOCALE_KEYS),
+            captured,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parsers::{InfoEntry, Section, Value},
+        Platform,
+    };
+
+    use super::*;
+
+    #[test]
+    fn log_summary_ok() {
+        let key_value = |key: &str, value: &str| {
+            InfoEntry::KeyValue(key.to_owned(), Value::Generic(value.to_owned()))
+        };
+
+        let content = Content {
+            information: vec![Section {
+                name: "SYSINFO".to_owned(),
+                content: vec![
+                    key_value("Time", "1234567890123"),
+                    key_value("Model", "Pixel"),
+                    key_value("Android", "12"),
+                ],
+                subsections: vec![],
+            }],
+            logs: vec![],
+        };
+
+        assert_eq!(
+            LogSummary::new(
+                &RemoteObject::new_unchecked(Platform::Android, Some("1.2.3".to_owned()), "abc"),
+                &content,
+            ),
+            LogSummary {
+                version: Some("1.2.3".to_owned()),
+                device: Some("Pixel".to_owned()),
+                os: Some("12".to_owned()),
+                locale: None,
+                captured: Some(Utc.timestamp_millis(1234567890123).to_string()),
+            }
+        );
+    }
+}
@@ -0,0 +1,75 @@
+// Keeps the app shell available offline. Debug logs themselves are kept in IndexedDB by the app,
+// so requests to other origins (debuglogs.org, the worker, CORS proxies) aren't handled here.
+
+// Bump to drop everything that was cached by an older version of this file.
+const CACHE_NAME = "readlogs-v1";
+
+self.addEventListener("install", (event) => {
+  event.waitUntil(
+    caches.open(CACHE_NAME)
+      .then((cache) => cache.add("/"))
+      .then(() => self.skipWaiting()),
+  );
+});
+
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    caches.keys()
+      .then((names) => Promise.all(
+        names.filter((name) => name !== CACHE_NAME).map((name) => caches.delete(name)),
+      ))
+      .then(() => self.clients.claim()),
+  );
+});
+
+// Fetches the request, keeping a copy of a successful response in the cache.
+async function fetchAndCache(request, key) {
+  const response = await fetch(request);
+
+  if (response.ok) {
+    const cache = await caches.open(CACHE_NAME);
+    await cache.put(key, response.clone());
+  }
+
+  return response;
+}
+
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  const url = new URL(request.url);
+
+  if (request.method !== "GET" || url.origin !== self.location.origin) {
+    return;
+  }
+
+  if (request.mode === "navigate") {
+    // The page is fetched first, so that a new build (with new file names of the scripts it
+    // loads) is picked up; the cached one is used offline, whatever the query (e.g. a permalink).
+    event.respondWith(
+      fetchAndCache(request, "/").catch(() => caches.match("/")),
+    );
+    return;
+  }
+
+  if (url.pathname.startsWith("/readlogs-worker")) {
+    // The files of the worker that extracts `zip` archives keep their names across builds, so
+    // they're fetched first as well, to match the page's scripts.
+    event.respondWith(
+      fetchAndCache(request, request).catch(() => caches.match(request)),
+    );
+    return;
+  }
+
+  // Everything else is served from the cache right away and updated for the next time.
+  event.respondWith(
+    caches.match(request).then((cached) => {
+      const fetched = fetchAndCache(request, request);
+      if (cached) {
+        event.waitUntil(fetched.catch(() => {}));
+        return cached;
+      }
+
+      return fetched;
+    }),
+  );
+});
@@ -0,0 +1,6 @@
+use gloo_worker::Registrable;
+use readlogs::extraction::ArchiveExtractor;
+
+fn main() {
+    ArchiveExtractor::registrar().register();
+}
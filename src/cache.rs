@@ -0,0 +1,169 @@
+use js_sys::{Array, Object, Promise, Reflect, Uint8Array};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{IdbDatabase, IdbRequest, IdbTransaction, IdbTransactionMode};
+
+use crate::Body;
+
+const DATABASE_NAME: &str = "readlogs";
+const DATABASE_VERSION: u32 = 1;
+/// Object store with the fetched debug logs, by the [`key`] of their URL.
+const BODIES_STORE: &str = "bodies";
+/// Object store with the URL and size of every fetched debug log, which can be read without
+/// reading the debug logs themselves.
+const ENTRIES_STORE: &str = "entries";
+
+/// Key of the debug log fetched from the URL: a 64-bit FNV-1a hash of it, which (unlike the
+/// standard library's hasher) stays the same across builds.
+pub fn key(url: &str) -> String {
+    let hash = url.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+
+    format!("{:016x}", hash)
+}
+
+/// How much is stored in the cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Usage {
+    pub logs: usize,
+    pub bytes: usize,
+}
+
+/// Waits for the request to finish, returning its result.
+async fn wait(request: &IdbRequest) -> Result<JsValue, JsValue> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        let on_success = Closure::once_into_js(move |event: JsValue| {
+            let _ = resolve.call1(&JsValue::NULL, &event);
+        });
+        let on_error = Closure::once_into_js(move |event: JsValue| {
+            let _ = reject.call1(&JsValue::NULL, &event);
+        });
+
+        request.set_onsuccess(Some(on_success.unchecked_ref()));
+        request.set_onerror(Some(on_error.unchecked_ref()));
+    });
+
+    JsFuture::from(promise).await?;
+    request.result()
+}
+
+/// Opens the database, creating its object stores the first time.
+async fn open() -> Result<IdbDatabase, JsValue> {
+    let factory = web_sys::window()
+        .ok_or("no window")?
+        .indexed_db()?
+        .ok_or("IndexedDB isn't available")?;
+
+    let request = factory.open_with_u32(DATABASE_NAME, DATABASE_VERSION)?;
+    let upgrading = request.clone();
+    let on_upgrade_needed = Closure::once_into_js(move |_: JsValue| {
+        if let Ok(database) = upgrading
+            .result()
+            .and_then(JsValue::dyn_into::<IdbDatabase>)
+        {
+            let _ = database.create_object_store(BODIES_STORE);
+            let _ = database.create_object_store(ENTRIES_STORE);
+        }
+    });
+    request.set_onupgradeneeded(Some(on_upgrade_needed.unchecked_ref()));
+
+    wait(&request).await?.dyn_into()
+}
+
+/// A transaction over both object stores.
+fn transaction(
+    database: &IdbDatabase,
+    mode: IdbTransactionMode,
+) -> Result<IdbTransaction, JsValue> {
+    database.transaction_with_str_sequence_and_mode(
+        &Array::of2(&BODIES_STORE.into(), &ENTRIES_STORE.into()),
+        mode,
+    )
+}
+
+/// The debug log fetched from the URL before, if it's still cached.
+pub async fn get(url: &str) -> Option<Body> {
+    let database = open().await.ok()?;
+    let store = database
+        .transaction_with_str(BODIES_STORE)
+        .ok()?
+        .object_store(BODIES_STORE)
+        .ok()?;
+    let value = wait(&store.get(&key(url).into()).ok()?).await.ok()?;
+
+    match value.as_string() {
+        Some(text) => Some(Body::Text(text)),
+        None if value.is_instance_of::<Uint8Array>() => {
+            Some(Body::Binary(Uint8Array::new(&value).to_vec()))
+        }
+        None => None,
+    }
+}
+
+/// Stores the debug log fetched from the URL, replacing what was cached for it before.
+pub async fn put(url: &str, body: &Body) -> Result<(), JsValue> {
+    let (value, size): (JsValue, _) = match body {
+        Body::Text(text) => (text.into(), text.len()),
+        Body::Binary(bytes) => (Uint8Array::from(bytes.as_slice()).into(), bytes.len()),
+    };
+
+    let entry = Object::new();
+    Reflect::set(&entry, &"url".into(), &url.into())?;
+    Reflect::set(&entry, &"size".into(), &(size as f64).into())?;
+
+    let key = JsValue::from(key(url));
+    let transaction = transaction(&open().await?, IdbTransactionMode::Readwrite)?;
+    transaction
+        .object_store(BODIES_STORE)?
+        .put_with_key(&value, &key)?;
+    wait(
+        &transaction
+            .object_store(ENTRIES_STORE)?
+            .put_with_key(&entry, &key)?,
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub async fn usage() -> Result<Usage, JsValue> {
+    let store = open()
+        .await?
+        .transaction_with_str(ENTRIES_STORE)?
+        .object_store(ENTRIES_STORE)?;
+    let entries: Array = wait(&store.get_all()?).await?.dyn_into()?;
+
+    let bytes = entries
+        .iter()
+        .filter_map(|entry| Reflect::get(&entry, &"size".into()).ok()?.as_f64())
+        .sum::<f64>();
+
+    Ok(Usage {
+        logs: entries.length() as usize,
+        bytes: bytes as usize,
+    })
+}
+
+/// Removes all cached debug logs.
+pub async fn clear() -> Result<(), JsValue> {
+    let transaction = transaction(&open().await?, IdbTransactionMode::Readwrite)?;
+    transaction.object_store(BODIES_STORE)?.clear()?;
+    wait(&transaction.object_store(ENTRIES_STORE)?.clear()?).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("" => "cbf29ce484222325"; "empty")]
+    #[test_case("a" => "af63dc4c8601ec8c"; "one byte")]
+    #[test_case("foobar" => "85944171f73967e8"; "several bytes")]
+    fn key_ok(url: &str) -> String {
+        key(url)
+    }
+}
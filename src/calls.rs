@@ -0,0 +1,318 @@
+use chrono::{DateTime, Duration, Utc};
+use yew::prelude::*;
+
+use crate::{
+    components::{Button, ButtonSize, Message},
+    format_delta, format_duration, i18n,
+    parsers::{Content, LogEntry},
+    tr, Tab, Text,
+};
+
+/// Parts of (lowercase) log messages that mark the start of an outgoing call.
+const OUTGOING_PATTERNS: [&str; 3] = ["handleoutgoingcall", "startoutgoingcall", "outgoing call"];
+/// Parts of (lowercase) log messages that mark the start of an incoming call.
+const INCOMING_PATTERNS: [&str; 4] = [
+    "handlereceivedoffer",
+    "startincomingcall",
+    "incoming call",
+    "received offer",
+];
+/// Parts of (lowercase) log messages that mark a call whose media is connected.
+const CONNECTED_PATTERNS: [&str; 3] = ["handlecallconnected", "call connected", "ice connected"];
+/// Parts of (lowercase) log messages that mark a call that failed, and why.
+const FAILURE_PATTERNS: [&str; 6] = [
+    "handleendedfailure",
+    "handleendedtimeout",
+    "handleendedremotebusy",
+    "ice failed",
+    "ice connection failed",
+    "call failed",
+];
+/// Parts of (lowercase) log messages that mark a call that ended otherwise.
+const ENDED_PATTERNS: [&str; 4] = ["handleended", "handlelocalhangup", "call ended", "hangup"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CallDirection {
+    Incoming,
+    Outgoing,
+}
+
+impl CallDirection {
+    /// Name of a call in this direction, in the current language.
+    pub fn label(&self) -> &'static str {
+        tr(match self {
+            CallDirection::Incoming => Text::IncomingCall,
+            CallDirection::Outgoing => Text::OutgoingCall,
+        })
+    }
+}
+
+/// What a log entry says about a call.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CallEvent {
+    Start(CallDirection),
+    Connected,
+    Failed,
+    Ended,
+}
+
+/// A call, from the log entry that started it to the one that ended it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Call {
+    pub direction: CallDirection,
+    /// Element ID of the log entry that started the call.
+    pub entry_id: String,
+    pub started: DateTime<Utc>,
+    pub original_timestamp: String,
+    pub connected: Option<DateTime<Utc>>,
+    /// `None` if the call didn't end before the log did.
+    pub ended: Option<DateTime<Utc>>,
+    /// First line of the log entry about the failure, if the call failed.
+    pub failure: Option<String>,
+}
+
+impl Call {
+    /// All calls in the log entries of `content`, in the order they were started; entries are
+    /// walked in time order, as a call is logged by several sections.
+    pub fn all(content: &Content) -> Vec<Call> {
+        let mut output = vec![];
+        let mut current: Option<Call> = None;
+
+        for entry in content.log_entries_by_time() {
+            let event = match Self::event(entry) {
+                Some(event) => event,
+                None => continue,
+            };
+
+            match (event, &mut current) {
+                (CallEvent::Start(direction), _) => {
+                    output.extend(current.take());
+                    current = Some(Call {
+                        direction,
+                        entry_id: entry.element_id(),
+                        started: entry.timestamp,
+                        original_timestamp: entry.original_timestamp.clone(),
+                        connected: None,
+                        ended: None,
+                        failure: None,
+                    });
+                }
+                (CallEvent::Connected, Some(call)) => {
+                    call.connected.get_or_insert(entry.timestamp);
+                }
+                (CallEvent::Failed | CallEvent::Ended, Some(call)) => {
+                    if event == CallEvent::Failed {
+                        call.failure =
+                            Some(entry.message.lines().next().unwrap_or_default().to_owned());
+                    }
+                    call.ended = Some(entry.timestamp);
+                    output.extend(current.take());
+                }
+                (_, None) => {}
+            }
+        }
+
+        output.extend(current);
+        output
+    }
+
+    fn event(entry: &LogEntry) -> Option<CallEvent> {
+        let message = entry.message.to_lowercase();
+        let matches = |patterns: &[&str]| patterns.iter().any(|pattern| message.contains(pattern));
+
+        if matches(&OUTGOING_PATTERNS) {
+            Some(CallEvent::Start(CallDirection::Outgoing))
+        } else if matches(&INCOMING_PATTERNS) {
+            Some(CallEvent::Start(CallDirection::Incoming))
+        } else if matches(&CONNECTED_PATTERNS) {
+            Some(CallEvent::Connected)
+        } else if matches(&FAILURE_PATTERNS) {
+            Some(CallEvent::Failed)
+        } else if matches(&ENDED_PATTERNS) {
+            Some(CallEvent::Ended)
+        } else {
+            None
+        }
+    }
+
+    /// How long it took until the call was connected.
+    pub fn setup_duration(&self) -> Option<Duration> {
+        self.connected.map(|connected| connected - self.started)
+    }
+
+    /// How long the call lasted once it was connected.
+    pub fn duration(&self) -> Option<Duration> {
+        Some(self.ended? - self.connected?)
+    }
+
+    /// How the call went, e.g. `set up in 1.2s, lasted 3 min 5 s`.
+    pub fn outcome(&self) -> String {
+        let elapsed =
+            |duration: Duration| format_delta(duration).trim_start_matches('+').to_owned();
+
+        match (&self.failure, self.setup_duration(), self.ended) {
+            (Some(failure), _, Some(ended)) => {
+                i18n::call_failed(&elapsed(ended - self.started), failure)
+            }
+            (_, Some(setup), _) => i18n::call_connected(
+                &elapsed(setup),
+                self.duration().map(format_duration).as_deref(),
+            ),
+            (_, None, Some(ended)) => i18n::call_ended_unconnected(&elapsed(ended - self.started)),
+            (_, None, None) => tr(Text::CallNotConnected).to_owned(),
+        }
+    }
+}
+
+/// A breakdown of the calls, with buttons that call `on_show` with the tab and element ID of the
+/// log entry that started one.
+pub fn view_calls(calls: &[Call], on_show: &Callback<(Tab, String)>) -> Html {
+    if calls.is_empty() {
+        return html! {};
+    }
+
+    let failed = calls.iter().filter(|call| call.failure.is_some()).count();
+
+    let view_call = |call: &Call| {
+        let id = call.entry_id.clone();
+
+        html! {
+            <p>
+                <strong>{ i18n::call_at(call.direction.label(), &call.original_timestamp) }</strong>
+                { call.outcome() }
+                <Button
+                    classes={classes!("rounded-2xl", "ml-2")}
+                    size={ButtonSize::Small}
+                    icon={classes!("fas", "fa-stream")}
+                    text={tr(Text::ShowEntry)}
+                    on_click={on_show.reform(move |_| (Tab::Logs, id.clone()))}
+                />
+            </p>
+        }
+    };
+
+    html! {
+        <Message
+            classes={classes!("mb-4")}
+            heading={i18n::calls_heading(failed, calls.len())}
+            warning={failed > 0}
+        >
+            { for calls.iter().map(view_call) }
+        </Message>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use test_case::test_case;
+
+    use crate::parsers::Section;
+
+    use super::*;
+
+    #[test_case("handleOutgoingCall(): call_id: 123" => Some(CallEvent::Start(CallDirection::Outgoing)); "outgoing")]
+    #[test_case("handleReceivedOffer(): id: 123" => Some(CallEvent::Start(CallDirection::Incoming)); "incoming")]
+    #[test_case("handleCallConnected(): call_id: 123" => Some(CallEvent::Connected); "connected")]
+    #[test_case("handleEndedFailure(): call_id: 123" => Some(CallEvent::Failed); "failed")]
+    #[test_case("handleEndedRemoteHangup(): call_id: 123" => Some(CallEvent::Ended); "ended")]
+    #[test_case("Retrieving profile" => None; "other")]
+    fn event_ok(message: &str) -> Option<CallEvent> {
+        Call::event(&LogEntry::test(message, 0))
+    }
+
+    #[test]
+    fn all_ok() {
+        let entry = |start: usize, millis: i64, message: &str| LogEntry {
+            timestamp: Utc.timestamp_millis(millis),
+            ..LogEntry::test(message, start)
+        };
+
+        let content = Content {
+            information: vec![],
+            logs: vec![Section {
+                name: "LOGCAT".to_owned(),
+                content: vec![
+                    entry(0, 0, "handleCallConnected(): before any call"),
+                    entry(10, 1_000, "handleOutgoingCall(): call_id: 1"),
+                    entry(20, 2_500, "handleCallConnected(): call_id: 1"),
+                    entry(30, 62_500, "handleLocalHangup(): call_id: 1"),
+                    entry(40, 100_000, "handleReceivedOffer(): call_id: 2"),
+                    entry(50, 130_000, "ICE failed\nmore"),
+                    entry(60, 200_000, "handleReceivedOffer(): call_id: 3"),
+                    entry(70, 205_000, "handleEndedRemoteHangup(): call_id: 3"),
+                    entry(80, 300_000, "handleOutgoingCall(): call_id: 4"),
+                ],
+                subsections: vec![],
+            }],
+        };
+
+        assert_eq!(
+            Call::all(&content)
+                .iter()
+                .map(|call| (call.direction, call.entry_id.clone(), call.outcome()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    CallDirection::Outgoing,
+                    "entry-10".to_owned(),
+                    "set up in 1.5s, lasted 1 min".to_owned()
+                ),
+                (
+                    CallDirection::Incoming,
+                    "entry-40".to_owned(),
+                    "failed after 30s: ICE failed".to_owned()
+                ),
+                (
+                    CallDirection::Incoming,
+                    "entry-60".to_owned(),
+                    "ended without connecting after 5.0s".to_owned()
+                ),
+                (
+                    CallDirection::Outgoing,
+                    "entry-80".to_owned(),
+                    "not connected by the end of the log".to_owned()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn all_ok_across_sections() {
+        let entry = |start: usize, millis: i64, message: &str| LogEntry {
+            timestamp: Utc.timestamp_millis(millis),
+            ..LogEntry::test(message, start)
+        };
+        let section = |name: &str, content| Section {
+            name: name.to_owned(),
+            content,
+            subsections: vec![],
+        };
+
+        let content = Content {
+            information: vec![],
+            logs: vec![
+                section(
+                    "LOGCAT",
+                    vec![entry(0, 1_000, "handleOutgoingCall(): call_id: 1")],
+                ),
+                section(
+                    "LOGGER",
+                    vec![
+                        entry(10, 500, "handleLocalHangup(): call_id: 0"),
+                        entry(20, 2_000, "handleCallConnected(): call_id: 1"),
+                        entry(30, 5_000, "handleLocalHangup(): call_id: 1"),
+                    ],
+                ),
+            ],
+        };
+
+        assert_eq!(
+            Call::all(&content)
+                .iter()
+                .map(Call::outcome)
+                .collect::<Vec<_>>(),
+            vec!["set up in 1.0s, lasted 3 s".to_owned()]
+        );
+    }
+}
@@ -9,6 +9,9 @@ pub struct BadgeProps {
 
     #[prop_or_default]
     pub text: String,
+    /// Accessible label, in case `text` alone is not descriptive enough.
+    #[prop_or_default]
+    pub label: Option<String>,
 }
 
 #[function_component(Badge)]
@@ -16,7 +19,7 @@ pub fn badge(props: &BadgeProps) -> Html {
     let classes = classes!(props.classes.clone(), "rounded-2xl", "px-4", "py-1",);
 
     html! {
-        <span class={classes}>
+        <span class={classes} aria-label={props.label.clone()}>
             { &props.text }
             { props.children.clone() }
         </span>
@@ -30,13 +30,21 @@ pub struct ButtonProps {
     #[prop_or_default]
     pub text: Option<String>,
 
-    #[prop_or(false)]
-    pub active: bool,
+    /// Whether what the button toggles is on, if it toggles something; announced as its pressed
+    /// state, or as the selected tab if its `role` is `tab`.
+    #[prop_or_default]
+    pub active: Option<bool>,
     #[prop_or(false)]
     pub disabled: bool,
 
     #[prop_or_default]
     pub href: Option<String>,
+    /// Tooltip, e.g. to explain an icon-only button.
+    #[prop_or_default]
+    pub title: Option<String>,
+    /// ARIA role, if the button acts as something else, e.g. a `tab`.
+    #[prop_or_default]
+    pub role: Option<&'static str>,
 }
 
 #[function_component(Button)]
@@ -62,13 +70,13 @@ pub fn button(props: &ButtonProps) -> Html {
         "dark:hover:bg-brand-dark-primary-hover",
         "hover:text-brand-text-primary-hover",
         "dark:hover:text-brand-dark-text-primary-hover",
-        "focus:outline-none",
-        "focus:ring",
-        "focus:ring-brand-focus",
-        "dark:focus:ring-brand-dark-focus",
-        "focus:ring-opacity-50",
-        "focus:border-brand-focus",
-        "dark:focus:border-brand-dark-focus",
+        "focus-visible:outline-none",
+        "focus-visible:ring",
+        "focus-visible:ring-brand-focus",
+        "dark:focus-visible:ring-brand-dark-focus",
+        "focus-visible:ring-opacity-50",
+        "focus-visible:border-brand-focus",
+        "dark:focus-visible:border-brand-dark-focus",
         "first:rounded-l-2xl",
         "first:border-r-0",
         "last:rounded-r-2xl",
@@ -88,7 +96,7 @@ pub fn button(props: &ButtonProps) -> Html {
         }
     });
 
-    classes.push(if props.active {
+    classes.push(if props.active == Some(true) {
         classes!(
             "bg-brand-primary-active",
             "dark:bg-brand-dark-primary-active",
@@ -111,11 +119,27 @@ pub fn button(props: &ButtonProps) -> Html {
         (None, None) => html! {},
     };
 
+    let state = props.active.map(|active| active.to_string());
+    let (pressed, selected) = match props.role {
+        Some("tab") => (None, state),
+        _ => (state, None),
+    };
+    // Within a link, the link is what gets focused; of tabs, only the selected one is, and the
+    // others are reached with the arrow keys
+    let unfocusable =
+        props.href.is_some() || (props.role == Some("tab") && props.active == Some(false));
+
     let button = html! {
         <button
             class={classes}
             onclick={props.on_click.clone()}
             disabled={props.disabled}
+            title={props.title.clone()}
+            aria-label={props.text.is_none().then(|| props.title.clone()).flatten()}
+            role={props.role}
+            aria-pressed={pressed}
+            aria-selected={selected}
+            tabindex={unfocusable.then_some("-1")}
         >
             { inner }
         </button>
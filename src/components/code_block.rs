@@ -2,7 +2,10 @@ use std::rc::Rc;
 
 use yew::prelude::*;
 
-use crate::components::{Button, ButtonSize};
+use crate::{
+    components::{Button, ButtonSize, CopyButton},
+    i18n, tr, Text,
+};
 
 const LINE_LIMIT_COLLAPSED: usize = 100;
 
@@ -15,6 +18,9 @@ pub struct CodeBlockProps {
 
     #[prop_or_else(|| Rc::new(String::new()))]
     pub text: Rc<String>,
+    /// Whether identifiers should be redacted from the copied text.
+    #[prop_or_default]
+    pub redact: bool,
 }
 
 #[function_component(CodeBlock)]
@@ -59,16 +65,16 @@ pub fn code_block(props: &CodeBlockProps) -> Html {
                     "mx-auto",
                     "p-4",
                 )}>
-                    { format!("Only the first {} lines are currently shown above. ", LINE_LIMIT_COLLAPSED) }
+                    { i18n::lines_shown(LINE_LIMIT_COLLAPSED) }
 
                     <Button
                         classes={classes!("rounded-2xl")}
                         size={ButtonSize::Small}
-                        text="Show all"
+                        text={tr(Text::ShowAll)}
                         on_click={Callback::from(move |_| expanded.set(true))}
                     />
 
-                    { " (may take a little while)" }
+                    { tr(Text::MayTakeAWhile) }
                 </div>
             }
         };
@@ -76,14 +82,30 @@ pub fn code_block(props: &CodeBlockProps) -> Html {
         (Rc::new(text), footer)
     };
 
+    let copy_button = if props.text.is_empty() {
+        html! {}
+    } else {
+        html! {
+            <CopyButton
+                classes={classes!("absolute", "top-2", "right-2", "rounded-2xl")}
+                text={Rc::clone(&props.text)}
+                redact={props.redact}
+            />
+        }
+    };
+
     html! {
         <>
-            <pre class={classes}>
-                <code>
-                    { text }
-                    { props.children.clone() }
-                </code>
-            </pre>
+            <div class="relative">
+                { copy_button }
+
+                <pre class={classes}>
+                    <code>
+                        { text }
+                        { props.children.clone() }
+                    </code>
+                </pre>
+            </div>
 
             { footer }
         </>
@@ -0,0 +1,94 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::{
+    components::{Input, Table, TableCell},
+    i18n, tr, RemoteConfigChange, Text,
+};
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct ConfigChangeLogProps {
+    pub changes: Rc<Vec<RemoteConfigChange>>,
+}
+
+#[function_component(ConfigChangeLog)]
+pub fn config_change_log(props: &ConfigChangeLogProps) -> Html {
+    let query = use_state_eq(String::new);
+
+    let visible = props
+        .changes
+        .iter()
+        .filter(|change| change.matches(&query))
+        .collect::<Vec<_>>();
+
+    html! {
+        <>
+            <div class={classes!("flex", "flex-wrap", "gap-2", "items-center", "mb-4")}>
+                <Input
+                    classes={classes!("rounded-2xl", "py-1")}
+                    value={(*query).clone()}
+                    on_change={Callback::from({
+                        let query = query.clone();
+                        move |value| query.set(value)
+                    })}
+                    placeholder={tr(Text::SearchFlags)}
+                />
+
+                <span>{ i18n::changes_count(visible.len(), props.changes.len()) }</span>
+            </div>
+
+            <Table
+                classes={classes!("font-mono", "text-sm")}
+                header={vec![
+                    "#".to_owned(),
+                    tr(Text::Timestamp).to_owned(),
+                    tr(Text::Store).to_owned(),
+                    tr(Text::Flag).to_owned(),
+                    tr(Text::From).to_owned(),
+                    tr(Text::To).to_owned(),
+                    tr(Text::FinalValue).to_owned(),
+                ]}
+                rows={visible.iter().copied().map(view_change).collect::<Vec<_>>()}
+            />
+        </>
+    }
+}
+
+/// Cell of a value, which is missing if the flag was added or removed.
+fn view_value(value: &Option<String>) -> TableCell {
+    match value {
+        Some(value) => TableCell::from(value),
+        None => TableCell::new(
+            "",
+            html! { <span class="opacity-60">{ tr(Text::NoValue) }</span> },
+        ),
+    }
+}
+
+/// Cells of the row of `change`.
+fn view_change(change: &RemoteConfigChange) -> Vec<TableCell> {
+    let final_value = if change.is_final() {
+        view_value(&change.final_value)
+    } else {
+        let cell = view_value(&change.final_value);
+        TableCell::new(
+            cell.sort_key,
+            html! {
+                <span class="opacity-60" title={tr(Text::DiffersFromFinalValue)}>
+                    { cell.content }
+                </span>
+            },
+        )
+    };
+
+    vec![
+        TableCell::new(change.number.to_string(), html! { change.number }),
+        TableCell::from(&change.original_timestamp),
+        TableCell::from(&change.store),
+        TableCell::from(&change.key),
+        view_value(&change.from),
+        view_value(&change.to),
+        final_value,
+    ]
+}
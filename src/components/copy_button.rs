@@ -0,0 +1,43 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::{
+    components::{Button, ButtonSize, Toaster},
+    redaction, tr, Text,
+};
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct CopyButtonProps {
+    #[prop_or_default]
+    pub classes: Classes,
+
+    pub text: Rc<String>,
+    /// Whether identifiers should be redacted from the copied text.
+    #[prop_or_default]
+    pub redact: bool,
+
+    #[prop_or_else(|| tr(Text::Copy).to_owned())]
+    pub label: String,
+}
+
+/// Copies the text to the clipboard, confirming it with a toast.
+#[function_component(CopyButton)]
+pub fn copy_button(props: &CopyButtonProps) -> Html {
+    let toaster = use_context::<Toaster>().unwrap_or_default();
+
+    let text = Rc::clone(&props.text);
+    let redact = props.redact;
+    let on_click =
+        Callback::from(move |_| toaster.copy(redaction::redact_if(redact, text.to_string())));
+
+    html! {
+        <Button
+            classes={props.classes.clone()}
+            size={ButtonSize::Small}
+            icon={classes!("fas", "fa-copy")}
+            text={props.label.clone()}
+            {on_click}
+        />
+    }
+}
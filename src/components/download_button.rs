@@ -7,7 +7,10 @@ use wasm_bindgen::JsCast;
 use web_sys::{window, HtmlElement};
 use yew::prelude::*;
 
-use crate::components::{Button, ButtonSize};
+use crate::{
+    components::{Button, ButtonSize, ToastKind, Toaster},
+    tr, Text,
+};
 
 #[derive(Debug, Clone, PartialEq, Properties)]
 pub struct DownloadButtonProps {
@@ -29,6 +32,8 @@ pub struct DownloadButtonProps {
 
     pub filename: String,
     pub content: Rc<String>,
+    #[prop_or_else(|| "text/plain".to_owned())]
+    pub mime_type: String,
 }
 
 #[derive(Debug)]
@@ -50,7 +55,8 @@ impl Component for DownloadButton {
             .set_attribute(
                 "href",
                 &format!(
-                    "data:text/plain;charset=utf-8,{}",
+                    "data:{};charset=utf-8,{}",
+                    ctx.props().mime_type,
                     percent_encode(ctx.props().content.as_bytes(), NON_ALPHANUMERIC)
                 ),
             )
@@ -65,6 +71,13 @@ impl Component for DownloadButton {
         element.click();
         document.body().unwrap().remove_child(&element).unwrap();
 
+        if let Some((toaster, _)) = ctx.link().context::<Toaster>(Callback::noop()) {
+            toaster.show(
+                ToastKind::Success,
+                format!("{} {}", tr(Text::Downloaded), ctx.props().filename),
+            );
+        }
+
         false
     }
 
@@ -75,7 +88,7 @@ impl Component for DownloadButton {
                 size={ctx.props().size}
                 icon={ctx.props().icon.clone()}
                 text={ctx.props().text.clone()}
-                active={ctx.props().active}
+                active={ctx.props().active.then_some(true)}
                 disabled={ctx.props().disabled}
                 on_click={ctx.link().callback(identity)}
             />
@@ -1,10 +1,12 @@
-use std::rc::Rc;
+use std::{collections::BTreeSet, rc::Rc};
 
 use yew::prelude::*;
 
 use crate::{
     components::{Icon, Message, Table, TableItem, TableRow},
+    format_size, i18n,
     parsers::{AppId, LogFilename},
+    tr, FileSummary, Text,
 };
 
 #[derive(Debug, Clone, PartialEq, Properties)]
@@ -12,14 +14,14 @@ pub struct FilePickerProps {
     #[prop_or_default]
     pub classes: Classes,
 
-    pub files: Vec<Rc<LogFilename>>,
-    pub selected_file: Rc<LogFilename>,
-    pub on_file_selected: Callback<Rc<LogFilename>>,
+    pub files: Vec<(Rc<LogFilename>, FileSummary)>,
+    pub selected_files: BTreeSet<Rc<LogFilename>>,
+    pub on_selection_changed: Callback<BTreeSet<Rc<LogFilename>>>,
 }
 
 #[function_component(FilePicker)]
 pub fn file_picker(props: &FilePickerProps) -> Html {
-    let submission_time = props.files[0].submission_time;
+    let submission_time = props.files[0].0.submission_time;
 
     html! {
         <Message
@@ -28,22 +30,79 @@ pub fn file_picker(props: &FilePickerProps) -> Html {
         >
             <Table classes={classes!("font-mono")}>
                 <tbody>
-                    { for props.files.iter().map(|file| view_file_row(props, Rc::clone(file))) }
+                    { view_file_row(props, None) }
+                    {
+                        for props.files.iter().map(|(file, summary)| {
+                            view_file_row(props, Some((Rc::clone(file), summary)))
+                        })
+                    }
                 </tbody>
             </Table>
         </Message>
     }
 }
 
-fn view_file_row(props: &FilePickerProps, file: Rc<LogFilename>) -> Html {
-    let active = props.selected_file == file;
-    let app_id = file.app_id;
-    let file_time = file.file_time.to_string();
+/// Clicking a row selects only that file (or all files, in case of the combined row), while
+/// the checkbox adds it to or removes it from the selection.
+fn view_file_row(props: &FilePickerProps, file: Option<(Rc<LogFilename>, &FileSummary)>) -> Html {
+    let all_files = props
+        .files
+        .iter()
+        .map(|(file, _)| Rc::clone(file))
+        .collect::<BTreeSet<_>>();
 
-    let icon = match app_id {
-        AppId::Signal => "fa-square",
-        AppId::NotificationServiceExtension => "fa-bell",
-        AppId::ShareAppExtension => "fa-share",
+    let summary = match &file {
+        Some((_, summary)) => html! {
+            <>
+                <TableItem>{ format_size(summary.size) }</TableItem>
+                <TableItem>
+                    {
+                        match summary.entry_count {
+                            Some(count) => i18n::entries_count(count),
+                            None => tr(Text::NotParsed).to_owned(),
+                        }
+                    }
+                </TableItem>
+                <TableItem>
+                    {
+                        match &summary.time_range {
+                            Some((first, last)) => format!("{} – {}", first, last),
+                            None => String::new(),
+                        }
+                    }
+                </TableItem>
+            </>
+        },
+        None => html! {
+            <>
+                <TableItem/>
+                <TableItem/>
+                <TableItem/>
+            </>
+        },
+    };
+
+    let file = file.map(|(file, _)| file);
+
+    let (active, icon, app, file_time) = match &file {
+        Some(file) => (
+            props.selected_files.contains(file),
+            match &file.app_id {
+                AppId::Signal => "fa-square",
+                AppId::NotificationServiceExtension => "fa-bell",
+                AppId::ShareAppExtension => "fa-share",
+                AppId::Other(_) => "fa-puzzle-piece",
+                AppId::Unknown => "fa-question",
+            },
+            file.app_id.to_string(),
+            file.title(),
+        ),
+        None => (
+            props.selected_files == all_files,
+            "fa-layer-group",
+            tr(Text::Combined).to_owned(),
+            tr(Text::AllFilesChronologically).to_owned(),
+        ),
     };
 
     let mut classes = classes!(
@@ -65,14 +124,48 @@ fn view_file_row(props: &FilePickerProps, file: Rc<LogFilename>) -> Html {
         classes!()
     });
 
+    let (on_click, checkbox) = match file {
+        Some(file) => {
+            let mut toggled = props.selected_files.clone();
+            if !toggled.remove(&file) {
+                toggled.insert(Rc::clone(&file));
+            }
+
+            let on_toggle = props.on_selection_changed.reform(move |event: MouseEvent| {
+                event.stop_propagation();
+                toggled.clone()
+            });
+
+            (
+                props
+                    .on_selection_changed
+                    .reform(move |_| BTreeSet::from([Rc::clone(&file)])),
+                html! {
+                    <input
+                        type="checkbox"
+                        class="cursor-pointer"
+                        checked={active}
+                        aria-label={tr(Text::IncludeInSelection)}
+                        onclick={on_toggle}
+                    />
+                },
+            )
+        }
+        None => (
+            props
+                .on_selection_changed
+                .reform(move |_| all_files.clone()),
+            html! {},
+        ),
+    };
+
     html! {
-        <TableRow
-            {classes}
-            on_click={props.on_file_selected.clone().reform(move |_| Rc::clone(&file))}
-        >
+        <TableRow {classes} {on_click} focusable={true}>
+            <TableItem>{ checkbox }</TableItem>
             <TableItem><Icon icon={classes!("fas", icon)} /></TableItem>
-            <TableItem>{ app_id }</TableItem>
+            <TableItem>{ app }</TableItem>
             <TableItem>{ file_time }</TableItem>
+            { summary }
         </TableRow>
     }
 }
@@ -0,0 +1,97 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::{
+    components::{Button, ButtonSize, Input, Table, TableCell},
+    parsers::ValueView,
+    tr, FeatureFlag, FlagFilter, Text,
+};
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct FlagExplorerProps {
+    pub flags: Rc<Vec<FeatureFlag>>,
+}
+
+#[function_component(FlagExplorer)]
+pub fn flag_explorer(props: &FlagExplorerProps) -> Html {
+    let query = use_state_eq(String::new);
+    let filter = use_state_eq(FlagFilter::default);
+    let only_non_default = use_state_eq(|| false);
+
+    let filter_button = |variant: FlagFilter| {
+        let filter = filter.clone();
+
+        html! {
+            <Button
+                size={ButtonSize::Small}
+                active={*filter == variant}
+                text={variant.to_string()}
+                on_click={Callback::from(move |_| filter.set(variant))}
+            />
+        }
+    };
+
+    let visible = props
+        .flags
+        .iter()
+        .filter(|flag| flag.matches(&query, *filter, *only_non_default))
+        .collect::<Vec<_>>();
+
+    html! {
+        <>
+            <div class={classes!("flex", "flex-wrap", "gap-2", "items-center", "mb-4")}>
+                <Input
+                    classes={classes!("rounded-2xl", "py-1")}
+                    value={(*query).clone()}
+                    on_change={Callback::from({
+                        let query = query.clone();
+                        move |value| query.set(value)
+                    })}
+                    placeholder={tr(Text::SearchFlags)}
+                />
+
+                { filter_button(FlagFilter::All) }
+                { filter_button(FlagFilter::Enabled) }
+                { filter_button(FlagFilter::Disabled) }
+
+                <Button
+                    size={ButtonSize::Small}
+                    active={*only_non_default}
+                    text={tr(Text::OnlyNonDefault)}
+                    on_click={Callback::from({
+                        let only_non_default = only_non_default.clone();
+                        move |_| only_non_default.set(!*only_non_default)
+                    })}
+                />
+
+                <span>{ format!("{} of {} flags", visible.len(), props.flags.len()) }</span>
+            </div>
+
+            <Table
+                classes={classes!("font-mono", "text-sm")}
+                header={[Text::Section, Text::Flag, Text::State, Text::Value].map(|text| tr(text).to_owned()).to_vec()}
+                rows={visible.iter().copied().map(view_flag).collect::<Vec<_>>()}
+            />
+        </>
+    }
+}
+
+/// Cells of the row of `flag`.
+fn view_flag(flag: &FeatureFlag) -> Vec<TableCell> {
+    let state = match flag.enabled {
+        Some(true) => "enabled",
+        Some(false) => "disabled",
+        None => "",
+    };
+
+    vec![
+        TableCell::from(&flag.section),
+        TableCell::from(&flag.key),
+        TableCell::new(state, html! { state }),
+        match &flag.value {
+            Some(value) => TableCell::new(value.to_string(), value.view()),
+            None => TableCell::new("", html! {}),
+        },
+    ]
+}
@@ -19,7 +19,7 @@ pub fn icon(props: &IconProps) -> Html {
     );
 
     html! {
-        <span class={classes}>
+        <span class={classes} aria-hidden="true">
             <span class={props.icon.clone()} />
         </span>
     }
@@ -12,6 +12,8 @@ pub struct InputProps {
     pub on_change: Callback<String>,
     #[prop_or_else(Callback::noop)]
     pub on_submit_maybe: Callback<bool>, // FIXME: Hacky
+    #[prop_or_else(Callback::noop)]
+    pub on_paste: Callback<Event>,
 
     #[prop_or_default]
     pub value: String,
@@ -56,6 +58,7 @@ pub fn input(props: &InputProps) -> Html {
             class={classes}
             placeholder={props.placeholder.clone()}
             onkeypress={props.on_submit_maybe.clone().reform(|e: KeyboardEvent| e.key() == "Enter")}
+            onpaste={props.on_paste.clone()}
             disabled={props.disabled}
             autofocus={props.autofocus}
         />
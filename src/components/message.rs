@@ -16,6 +16,8 @@ pub struct MessageProps {
 
     #[prop_or(false)]
     pub error: bool,
+    #[prop_or(false)]
+    pub warning: bool,
 }
 
 #[function_component(Message)]
@@ -35,6 +37,8 @@ pub fn message(props: &MessageProps) -> Html {
 
     classes.push(if props.error {
         classes!("bg-red-100")
+    } else if props.warning {
+        classes!("bg-yellow-100", "dark:bg-yellow-900")
     } else {
         classes!("bg-brand-bg-message", "dark:bg-brand-dark-bg-message")
     });
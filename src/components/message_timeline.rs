@@ -0,0 +1,110 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::{
+    components::{Badge, Button, ButtonSize, Table, TableCell},
+    format_delta, i18n, tr, MessageTrace, Tab, Text,
+};
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct MessageTimelineProps {
+    pub traces: Rc<Vec<MessageTrace>>,
+    /// Called with the tab and element ID of a log entry of the trace to show.
+    pub on_show: Callback<(Tab, String)>,
+}
+
+#[function_component(MessageTimeline)]
+pub fn message_timeline(props: &MessageTimelineProps) -> Html {
+    let traced = use_state_eq(|| None::<usize>);
+
+    let trace_button = |(index, trace): (usize, &MessageTrace)| {
+        let traced = traced.clone();
+        let active = *traced == Some(index);
+
+        html! {
+            <Button
+                classes={classes!("rounded-2xl")}
+                size={ButtonSize::Small}
+                {active}
+                icon={classes!("fas", "fa-route")}
+                text={i18n::trace_label(&trace.id)}
+                on_click={Callback::from(move |_| traced.set(if active { None } else { Some(index) }))}
+            />
+        }
+    };
+
+    let timeline = match (*traced).and_then(|index| props.traces.get(index)) {
+        Some(trace) => {
+            let start = trace.steps[0].timestamp;
+
+            let rows = trace
+                .steps
+                .iter()
+                .map(|step| {
+                    let stage = step.stage.map(|stage| stage.label().to_owned()).unwrap_or_default();
+                    let id = step.entry_id.clone();
+
+                    vec![
+                        TableCell::new(
+                            (step.timestamp - start).num_milliseconds().to_string(),
+                            html! { format_delta(step.timestamp - start) },
+                        ),
+                        TableCell::from(&step.original_timestamp),
+                        TableCell::new(
+                            stage.clone(),
+                            if stage.is_empty() {
+                                html! {}
+                            } else {
+                                html! {
+                                    <Badge
+                                        classes={classes!("bg-brand-bg-message", "dark:bg-brand-dark-bg-message")}
+                                        text={stage}
+                                    />
+                                }
+                            },
+                        ),
+                        TableCell::from(&step.summary),
+                        TableCell::new(
+                            "",
+                            html! {
+                                <Button
+                                    classes={classes!("rounded-2xl")}
+                                    size={ButtonSize::Small}
+                                    icon={classes!("fas", "fa-stream")}
+                                    text={tr(Text::Show)}
+                                    on_click={props.on_show.reform(move |_| (Tab::Logs, id.clone()))}
+                                />
+                            },
+                        ),
+                    ]
+                })
+                .collect::<Vec<_>>();
+
+            html! {
+                <Table
+                    classes={classes!("font-mono", "text-sm", "mb-4")}
+                    header={vec![
+                        tr(Text::Elapsed).to_owned(),
+                        tr(Text::Timestamp).to_owned(),
+                        tr(Text::Stage).to_owned(),
+                        tr(Text::Message).to_owned(),
+                        String::new(),
+                    ]}
+                    {rows}
+                />
+            }
+        }
+        None => html! {},
+    };
+
+    html! {
+        <>
+            <div class={classes!("flex", "flex-wrap", "gap-2", "mb-4")}>
+                { for props.traces.iter().enumerate().map(trace_button) }
+            </div>
+
+            { timeline }
+        </>
+    }
+}
@@ -1,29 +1,47 @@
 mod badge;
 mod button;
 mod code_block;
+mod config_change_log;
+mod copy_button;
 mod download_button;
 mod file_picker;
+mod flag_explorer;
 mod icon;
 mod input;
 mod link;
 mod message;
+mod message_timeline;
+mod modal;
+mod resize_handle;
 mod table;
 mod table_item;
 mod table_row;
+mod thread_viewer;
 mod title;
+mod toast;
 mod toolbar;
+mod virtual_code_block;
 
 pub use badge::Badge;
 pub use button::{Button, ButtonSize};
 pub use code_block::CodeBlock;
+pub use config_change_log::ConfigChangeLog;
+pub use copy_button::CopyButton;
 pub use download_button::DownloadButton;
 pub use file_picker::FilePicker;
+pub use flag_explorer::FlagExplorer;
 pub use icon::Icon;
 pub use input::Input;
 pub use link::Link;
 pub use message::Message;
-pub use table::Table;
+pub use message_timeline::MessageTimeline;
+pub use modal::Modal;
+pub use resize_handle::ResizeHandle;
+pub use table::{Table, TableCell};
 pub use table_item::TableItem;
 pub use table_row::TableRow;
+pub use thread_viewer::ThreadViewer;
 pub use title::{Title, TitleLevel};
+pub use toast::{Toast, ToastKind, Toaster, Toasts, TOAST_DURATION_MS};
 pub use toolbar::Toolbar;
+pub use virtual_code_block::{TextSize, VirtualCodeBlock};
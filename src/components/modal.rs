@@ -0,0 +1,90 @@
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+use crate::{
+    components::{Button, ButtonSize, Message},
+    tr, Text,
+};
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct ModalProps {
+    #[prop_or_default]
+    pub classes: Classes,
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub heading: Option<String>,
+
+    pub on_close: Callback<MouseEvent>,
+}
+
+/// A dialog in front of the dimmed page, closed with its button or by clicking next to it. It is
+/// focused when opened, so that the keyboard focus continues within it.
+#[function_component(Modal)]
+pub fn modal(props: &ModalProps) -> Html {
+    let dialog = use_node_ref();
+
+    {
+        let dialog = dialog.clone();
+
+        use_effect_with_deps(
+            move |_| {
+                if let Some(dialog) = dialog.cast::<HtmlElement>() {
+                    let _ = dialog.focus();
+                }
+                || ()
+            },
+            (),
+        );
+    }
+
+    let on_click_outside = {
+        let on_close = props.on_close.clone();
+
+        Callback::from(move |event: MouseEvent| {
+            if event.target() == event.current_target() {
+                on_close.emit(event);
+            }
+        })
+    };
+
+    html! {
+        <div
+            class={classes!(
+                "fixed",
+                "inset-0",
+                "z-30",
+                "flex",
+                "items-center",
+                "justify-center",
+                "bg-black",
+                "bg-opacity-50",
+            )}
+            onclick={on_click_outside}
+        >
+            <div
+                ref={dialog}
+                role="dialog"
+                aria-modal="true"
+                aria-label={props.heading.clone()}
+                tabindex="-1"
+                class="focus:outline-none"
+            >
+                <Message
+                    classes={classes!(props.classes.clone(), "max-h-[90vh]", "overflow-y-auto")}
+                    heading={props.heading.clone()}
+                >
+                    { props.children.clone() }
+
+                    <Button
+                        classes={classes!("rounded-2xl")}
+                        size={ButtonSize::Small}
+                        text={tr(Text::Close)}
+                        on_click={props.on_close.clone()}
+                    />
+                </Message>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,86 @@
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+use crate::{tr, Text};
+
+/// Narrowest width, in pixels, that a column can be dragged to.
+const MIN_WIDTH: i32 = 40;
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct ResizeHandleProps {
+    /// Called with the new width of the parent element in pixels while dragging, and with `None`
+    /// on a double-click, to reset it.
+    pub on_resize: Callback<Option<u32>>,
+}
+
+/// A grip along the right edge of its (positioned) parent element, e.g. a table header cell, that
+/// resizes it when dragged.
+#[function_component(ResizeHandle)]
+pub fn resize_handle(props: &ResizeHandleProps) -> Html {
+    // Horizontal position of the pointer and width of the parent when dragging started.
+    let drag = use_state_eq(|| None::<(i32, i32)>);
+
+    let on_mouse_down = {
+        let drag = drag.clone();
+
+        Callback::from(move |event: MouseEvent| {
+            event.prevent_default();
+
+            let width = event
+                .target()
+                .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                .and_then(|handle| handle.parent_element())
+                .map(|parent| parent.client_width());
+
+            if let Some(width) = width {
+                drag.set(Some((event.client_x(), width)));
+            }
+        })
+    };
+
+    // While dragging, an invisible overlay keeps receiving the pointer's movements wherever it is.
+    let overlay = match *drag {
+        Some((start, width)) => {
+            let on_resize = props.on_resize.clone();
+            let on_mouse_move = Callback::from(move |event: MouseEvent| {
+                let width = (width + event.client_x() - start).max(MIN_WIDTH);
+                on_resize.emit(Some(width as u32));
+            });
+            let on_mouse_up = {
+                let drag = drag.clone();
+                Callback::from(move |_| drag.set(None))
+            };
+
+            html! {
+                <div
+                    class={classes!("fixed", "inset-0", "z-40", "cursor-col-resize")}
+                    onmousemove={on_mouse_move}
+                    onmouseleave={on_mouse_up.clone()}
+                    onmouseup={on_mouse_up}
+                />
+            }
+        }
+        None => html! {},
+    };
+
+    html! {
+        <>
+            <span
+                class={classes!(
+                    "absolute",
+                    "top-0",
+                    "right-0",
+                    "w-1",
+                    "h-full",
+                    "cursor-col-resize",
+                    "hover:bg-brand-primary-hover",
+                    "dark:hover:bg-brand-dark-primary-hover",
+                )}
+                title={tr(Text::DragToResize)}
+                onmousedown={on_mouse_down}
+                ondblclick={props.on_resize.reform(|_| None)}
+            />
+            { overlay }
+        </>
+    }
+}
@@ -1,26 +1,167 @@
 use yew::prelude::*;
 
+use crate::{
+    components::{TableItem, TableRow},
+    utils::compare_naturally,
+};
+
+/// A cell of the sortable `rows` of a [`Table`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableCell {
+    /// Text the rows are sorted by when the column of the cell is chosen.
+    pub sort_key: String,
+    pub content: Html,
+}
+
+impl TableCell {
+    pub fn new(sort_key: impl Into<String>, content: Html) -> Self {
+        Self {
+            sort_key: sort_key.into(),
+            content,
+        }
+    }
+}
+
+impl From<&String> for TableCell {
+    fn from(text: &String) -> Self {
+        Self::new(text.clone(), html! { text.clone() })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Properties)]
 pub struct TableProps {
     #[prop_or_default]
     pub classes: Classes,
     #[prop_or_default]
     pub children: Children,
+
+    /// Whether the header row stays at the top of the window while scrolling through the table.
+    /// Such a table doesn't scroll horizontally on its own, as the header would then only stick
+    /// within it.
+    #[prop_or(false)]
+    pub sticky_header: bool,
+
+    /// Names of the columns of `rows`, shown as a header; clicking one sorts the rows by that
+    /// column, ascending first, then descending, then in their original order again.
+    #[prop_or_default]
+    pub header: Vec<String>,
+    /// Rows shown after `children`, in the order chosen with the `header`.
+    #[prop_or_default]
+    pub rows: Vec<Vec<TableCell>>,
 }
 
+/// Column and direction (ascending if `true`) the rows of a [`Table`] are sorted by.
+type SortOrder = Option<(usize, bool)>;
+
 #[function_component(Table)]
 pub fn table(props: &TableProps) -> Html {
-    let classes = classes!(
+    let sort_order = use_state_eq(|| SortOrder::None);
+
+    let mut classes = classes!(
         props.classes.clone(),
         "max-w-max",
         "!my-0",
         "!leading-tight",
     );
+    if props.sticky_header {
+        classes.push("sticky-header");
+    }
+
+    let view_header_item = |(column, name): (usize, &String)| {
+        let (icon, next) = match *sort_order {
+            Some((sorted, true)) if sorted == column => ("fa-sort-up", Some((column, false))),
+            Some((sorted, false)) if sorted == column => ("fa-sort-down", None),
+            _ => ("fa-sort", Some((column, true))),
+        };
+        let aria_sort = match *sort_order {
+            Some((sorted, ascending)) if sorted == column => {
+                if ascending {
+                    "ascending"
+                } else {
+                    "descending"
+                }
+            }
+            _ => "none",
+        };
+        let onclick = {
+            let sort_order = sort_order.clone();
+            Callback::from(move |_| sort_order.set(next))
+        };
+
+        html! {
+            <TableItem tag="th" {aria_sort}>
+                <button
+                    class={classes!(
+                        "whitespace-nowrap",
+                        "font-bold",
+                        "focus:outline-none",
+                        "focus-visible:ring",
+                        "focus-visible:ring-brand-focus",
+                        "dark:focus-visible:ring-brand-dark-focus",
+                    )}
+                    {onclick}
+                >
+                    { name }
+                    <i class={classes!("fas", icon, "ml-1", "opacity-60")} aria-hidden="true" />
+                </button>
+            </TableItem>
+        }
+    };
+
+    let mut rows = props.rows.iter().collect::<Vec<_>>();
+    if let Some((column, ascending)) = *sort_order {
+        rows.sort_by(|a, b| {
+            let ordering = match (a.get(column), b.get(column)) {
+                (Some(a), Some(b)) => compare_naturally(&a.sort_key, &b.sort_key),
+                (a, b) => a.is_some().cmp(&b.is_some()),
+            };
+
+            if ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+    }
 
     html! {
-        <div class="overflow-x-auto">
+        <div class={classes!((!props.sticky_header).then_some("overflow-x-auto"))}>
             <table class={classes}>
+                {
+                    if props.header.is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <thead>
+                                <TableRow classes={classes!("text-left")}>
+                                    { for props.header.iter().enumerate().map(view_header_item) }
+                                </TableRow>
+                            </thead>
+                        }
+                    }
+                }
                 { props.children.clone() }
+                {
+                    if props.rows.is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <tbody>
+                                {
+                                    for rows.into_iter().map(|row| html! {
+                                        <TableRow>
+                                            {
+                                                for row.iter().map(|cell| html! {
+                                                    <TableItem>{ cell.content.clone() }</TableItem>
+                                                })
+                                            }
+                                        </TableRow>
+                                    })
+                                }
+                            </tbody>
+                        }
+                    }
+                }
             </table>
         </div>
     }
@@ -9,6 +9,11 @@ pub struct TableItemProps {
 
     #[prop_or_else(|| "td".to_owned())]
     pub tag: String,
+    #[prop_or_default]
+    pub style: Option<String>,
+    /// Order the rows are sorted by this column in, for a header cell of a sortable table.
+    #[prop_or_default]
+    pub aria_sort: Option<&'static str>,
 }
 
 #[function_component(TableItem)]
@@ -16,7 +21,7 @@ pub fn table_item(props: &TableItemProps) -> Html {
     let classes = classes!(props.classes.clone(), "first:pl-2", "last:pr-2");
 
     html! {
-        <@{props.tag.clone()} class={classes}>
+        <@{props.tag.clone()} class={classes} style={props.style.clone()} aria-sort={props.aria_sort}>
             { props.children.clone() }
         </@>
     }
@@ -1,3 +1,4 @@
+use web_sys::HtmlElement;
 use yew::prelude::*;
 
 #[derive(Debug, Clone, PartialEq, Properties)]
@@ -9,12 +10,55 @@ pub struct TableRowProps {
 
     #[prop_or_default]
     pub on_click: Callback<MouseEvent>,
+    #[prop_or_default]
+    pub on_double_click: Callback<MouseEvent>,
+
+    /// Whether the row can be focused and then clicked with Enter or Space, for rows that are
+    /// clicked as a whole rather than through a button of their own.
+    #[prop_or(false)]
+    pub focusable: bool,
+
+    #[prop_or_default]
+    pub id: Option<String>,
 }
 
 #[function_component(TableRow)]
 pub fn table_row(props: &TableRowProps) -> Html {
+    let mut classes = props.classes.clone();
+    let mut on_key_down = None;
+
+    if props.focusable {
+        classes.push(classes!(
+            "focus:outline-none",
+            "focus-visible:ring",
+            "focus-visible:ring-inset",
+            "focus-visible:ring-brand-focus",
+            "dark:focus-visible:ring-brand-dark-focus",
+        ));
+
+        on_key_down = Some(Callback::from(|event: KeyboardEvent| {
+            if event.target() != event.current_target()
+                || !matches!(event.key().as_str(), "Enter" | " ")
+            {
+                return;
+            }
+
+            event.prevent_default();
+            if let Some(row) = event.target_dyn_into::<HtmlElement>() {
+                row.click();
+            }
+        }));
+    }
+
     html! {
-        <tr class={props.classes.clone()} onclick={props.on_click.clone()}>
+        <tr
+            id={props.id.clone()}
+            class={classes}
+            onclick={props.on_click.clone()}
+            ondblclick={props.on_double_click.clone()}
+            onkeydown={on_key_down}
+            tabindex={props.focusable.then_some("0")}
+        >
             { props.children.clone() }
         </tr>
     }
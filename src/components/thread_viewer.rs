@@ -0,0 +1,93 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::{
+    components::{Badge, Button, ButtonSize, CodeBlock},
+    i18n, tr, DumpedThread, Text,
+};
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct ThreadViewerProps {
+    pub threads: Rc<Vec<DumpedThread>>,
+}
+
+#[function_component(ThreadViewer)]
+pub fn thread_viewer(props: &ThreadViewerProps) -> Html {
+    let only_blocked = use_state_eq(|| false);
+
+    let blocked_count = props
+        .threads
+        .iter()
+        .filter(|thread| thread.is_blocked())
+        .count();
+
+    let visible = props
+        .threads
+        .iter()
+        .filter(|thread| !*only_blocked || thread.is_blocked())
+        .collect::<Vec<_>>();
+
+    let view_thread = |thread: &DumpedThread| {
+        let state = match &thread.state {
+            Some(state) => {
+                let classes = if thread.is_blocked() {
+                    classes!("bg-rose-100", "dark:bg-rose-900", "ml-2")
+                } else {
+                    classes!(
+                        "bg-brand-bg-message",
+                        "dark:bg-brand-dark-bg-message",
+                        "ml-2"
+                    )
+                };
+
+                html! { <Badge {classes} text={state.clone()} /> }
+            }
+            None if thread.is_blocked() => html! {
+                <Badge classes={classes!("bg-rose-100", "dark:bg-rose-900", "ml-2")} text={tr(Text::Blocked)} />
+            },
+            None => html! {},
+        };
+
+        let summary = html! {
+            <>
+                <code>{ format!("[{}] {}", thread.id, thread.name) }</code>
+                { state }
+            </>
+        };
+
+        if thread.frames.is_empty() {
+            html! { <div class={classes!("my-2", "ml-5")}>{ summary }</div> }
+        } else {
+            html! {
+                <details class={classes!("my-2")} open={thread.is_blocked()}>
+                    <summary class={classes!("cursor-pointer")}>{ summary }</summary>
+                    <CodeBlock>
+                        { for thread.frames.iter().map(|frame| html! { <>{ frame }{ "\n" }</> }) }
+                    </CodeBlock>
+                </details>
+            }
+        }
+    };
+
+    html! {
+        <>
+            <div class={classes!("flex", "flex-wrap", "gap-2", "items-center", "mb-4")}>
+                <Button
+                    size={ButtonSize::Small}
+                    active={*only_blocked}
+                    text={tr(Text::OnlyBlocked)}
+                    disabled={blocked_count == 0}
+                    on_click={Callback::from({
+                        let only_blocked = only_blocked.clone();
+                        move |_| only_blocked.set(!*only_blocked)
+                    })}
+                />
+
+                <span>{ i18n::thread_counts(props.threads.len(), blocked_count) }</span>
+            </div>
+
+            { for visible.into_iter().map(view_thread) }
+        </>
+    }
+}
@@ -0,0 +1,129 @@
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+use crate::{components::Icon, copy_to_clipboard, tr, Text};
+
+/// How long a toast is shown before it disappears on its own.
+pub const TOAST_DURATION_MS: u32 = 4000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ToastKind {
+    Success,
+    Info,
+    Error,
+}
+
+/// Transient feedback about something that happened, shown in a corner of the page.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast {
+    pub id: usize,
+    pub kind: ToastKind,
+    pub text: String,
+}
+
+/// Shows toasts; provided as a context to all components, so that any of them can report what
+/// happened without changing the view.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Toaster(pub Callback<(ToastKind, String)>);
+
+impl Toaster {
+    pub fn show(&self, kind: ToastKind, text: impl Into<String>) {
+        self.0.emit((kind, text.into()));
+    }
+
+    /// Copies the text to the clipboard, reporting whether that worked.
+    pub fn copy(&self, text: String) {
+        let toaster = self.clone();
+
+        spawn_local(async move {
+            match copy_to_clipboard(&text).await {
+                Ok(()) => toaster.show(ToastKind::Success, tr(Text::CopiedToClipboard)),
+                Err(_) => toaster.show(ToastKind::Error, tr(Text::CopyFailed)),
+            }
+        });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct ToastsProps {
+    pub toasts: Vec<Toast>,
+    /// Called with the ID of a toast whose close button was clicked.
+    pub on_dismiss: Callback<usize>,
+}
+
+/// The toasts that are currently shown, the newest at the bottom.
+#[function_component(Toasts)]
+pub fn toasts(props: &ToastsProps) -> Html {
+    let view_toast = |toast: &Toast| {
+        let id = toast.id;
+
+        let (icon, color) = match toast.kind {
+            ToastKind::Success => ("fa-check-circle", classes!()),
+            ToastKind::Info => ("fa-info-circle", classes!()),
+            ToastKind::Error => (
+                "fa-exclamation-circle",
+                classes!("text-red-600", "dark:text-red-400"),
+            ),
+        };
+
+        html! {
+            <div class={classes!(
+                "flex",
+                "items-center",
+                "gap-2",
+                "pl-3",
+                "pr-1",
+                "py-1",
+                "rounded-2xl",
+                "shadow-lg",
+                "border",
+                "border-brand-border",
+                "dark:border-brand-dark-border",
+                "bg-brand-bg-message",
+                "dark:bg-brand-dark-bg-message",
+                "text-sm",
+            )}>
+                <Icon icon={classes!("fas", icon)} classes={color} />
+                <span class="grow">{ &toast.text }</span>
+                <button
+                    class={classes!(
+                        "w-6",
+                        "h-6",
+                        "rounded-full",
+                        "opacity-60",
+                        "hover:opacity-100",
+                        "focus:outline-none",
+                        "focus-visible:ring",
+                        "focus-visible:ring-brand-focus",
+                        "dark:focus-visible:ring-brand-dark-focus",
+                    )}
+                    title={tr(Text::Dismiss)}
+                    aria-label={tr(Text::Dismiss)}
+                    onclick={props.on_dismiss.reform(move |_| id)}
+                >
+                    <i class="fas fa-times" aria-hidden="true" />
+                </button>
+            </div>
+        }
+    };
+
+    html! {
+        <div
+            class={classes!(
+                "fixed",
+                "top-4",
+                "right-4",
+                "z-40",
+                "flex",
+                "flex-col",
+                "gap-2",
+                "max-w-sm",
+                "print:hidden",
+            )}
+            role="status"
+            aria-live="polite"
+        >
+            { for props.toasts.iter().map(view_toast) }
+        </div>
+    }
+}
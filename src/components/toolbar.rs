@@ -10,6 +10,10 @@ pub struct ToolbarProps {
     pub classes_inner: Classes,
     #[prop_or_default]
     pub children: Children,
+
+    /// What the controls of the toolbar are for, as announced by screen readers.
+    #[prop_or_default]
+    pub label: Option<String>,
 }
 
 #[function_component(Toolbar)]
@@ -38,7 +42,7 @@ pub fn toolbar(props: &ToolbarProps) -> Html {
     html! {
         <nav class={outer}>
             <div class={mid}>
-                <div class={inner}>
+                <div class={inner} role="toolbar" aria-label={props.label.clone()}>
                     { props.children.clone() }
                 </div>
             </div>
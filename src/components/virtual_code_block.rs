@@ -0,0 +1,374 @@
+use std::{cell::RefCell, ops::Range, rc::Rc};
+
+use derive_more::Display;
+use gloo::events::EventListener;
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+use crate::{
+    components::{Button, CopyButton},
+    is_shortcut, line_starts, tr, Text,
+};
+
+/// Lines rendered above and below the visible ones, so fast scrolling doesn't show gaps.
+const OVERSCAN_LINES: usize = 20;
+/// Lines assumed to be visible before the height of the view is known.
+const DEFAULT_VISIBLE_LINES: usize = 60;
+
+/// Size of monospace text, e.g. of the raw text or the logs table.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Default, EnumIter, Serialize, Deserialize)]
+pub enum TextSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+impl TextSize {
+    pub fn classes(&self) -> Classes {
+        match self {
+            TextSize::Small => classes!("text-xs", "leading-4"),
+            TextSize::Medium => classes!("text-sm", "leading-5"),
+            TextSize::Large => classes!("text-base", "leading-6"),
+        }
+    }
+
+    /// Height of a line in pixels, matching the `leading-*` class.
+    pub fn line_height(&self) -> i32 {
+        match self {
+            TextSize::Small => 16,
+            TextSize::Medium => 20,
+            TextSize::Large => 24,
+        }
+    }
+
+    /// Fixed height of a line, so that empty lines take up as much space as the others.
+    fn line_height_class(&self) -> &'static str {
+        match self {
+            TextSize::Small => "h-4",
+            TextSize::Medium => "h-5",
+            TextSize::Large => "h-6",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct VirtualCodeBlockProps {
+    #[prop_or_default]
+    pub classes: Classes,
+
+    pub text: Rc<String>,
+    /// Whether identifiers should be redacted from the copied text.
+    #[prop_or_default]
+    pub redact: bool,
+    #[prop_or_default]
+    pub text_size: TextSize,
+
+    /// 1-based number of a line to highlight and scroll to.
+    #[prop_or_default]
+    pub highlighted_line: Option<usize>,
+    /// Called with the 1-based number of a line whose number is clicked.
+    #[prop_or_default]
+    pub on_line_click: Option<Callback<usize>>,
+
+    /// Sorted byte ranges of search matches to highlight, each within one line.
+    #[prop_or_default]
+    pub matches: Rc<Vec<Range<usize>>>,
+    /// Index of the match in `matches` to emphasize and scroll to.
+    #[prop_or_default]
+    pub current_match: Option<usize>,
+}
+
+/// Scroll position of the view, in pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Viewport {
+    scroll_top: i32,
+    height: i32,
+}
+
+impl Viewport {
+    /// Range of 0-based line indices to render, out of `line_count` lines of `line_height` pixels.
+    fn lines(&self, line_count: usize, line_height: i32) -> (usize, usize) {
+        let first_visible = (self.scroll_top / line_height).max(0) as usize;
+        let visible = match self.height {
+            0 => DEFAULT_VISIBLE_LINES,
+            height => (height / line_height) as usize + 1,
+        };
+
+        let start = first_visible.saturating_sub(OVERSCAN_LINES).min(line_count);
+        let end = (first_visible + visible + OVERSCAN_LINES).min(line_count);
+
+        (start, end)
+    }
+}
+
+/// A scrollable view of a (possibly very long) text with numbered lines, of which only the
+/// visible ones are rendered.
+#[function_component(VirtualCodeBlock)]
+pub fn virtual_code_block(props: &VirtualCodeBlockProps) -> Html {
+    let container = use_node_ref();
+    let viewport = use_state_eq(|| Viewport {
+        scroll_top: 0,
+        height: 0,
+    });
+
+    // Finding the line starts of a large text is expensive, so only do it when the text changes.
+    let cache = use_mut_ref(|| (Rc::new(String::new()), vec![0]));
+    update_line_starts(&cache, &props.text);
+    let cache = cache.borrow();
+    let line_starts = &cache.1;
+    let line_count = line_starts.len();
+    let line_height = props.text_size.line_height();
+
+    let on_scroll = {
+        let container = container.clone();
+        let viewport = viewport.clone();
+
+        Callback::from(move |_: Event| {
+            if let Some(element) = container.cast::<web_sys::Element>() {
+                viewport.set(Viewport {
+                    scroll_top: element.scroll_top(),
+                    height: element.client_height(),
+                });
+            }
+        })
+    };
+
+    // Number of lines that start at or before the match is its 1-based line number.
+    let current_match_line = props
+        .current_match
+        .and_then(|index| props.matches.get(index))
+        .map(|current| line_starts.partition_point(|start| *start <= current.start));
+
+    for line in [props.highlighted_line, current_match_line] {
+        let container = container.clone();
+        let viewport = viewport.clone();
+
+        use_effect_with_deps(
+            move |line| {
+                if let Some(line) = *line {
+                    scroll_to_line(&container, &viewport, line, line_height);
+                }
+
+                || ()
+            },
+            line,
+        );
+    }
+
+    // Home and End would scroll the page instead, as the view isn't focused.
+    {
+        let container = container.clone();
+
+        use_effect_with_deps(
+            move |_| {
+                let listener =
+                    web_sys::window()
+                        .and_then(|window| window.document())
+                        .map(|document| {
+                            EventListener::new(&document, "keydown", move |event| {
+                                let event = match event.dyn_ref::<KeyboardEvent>() {
+                                    Some(event) if is_shortcut(event) => event,
+                                    _ => return,
+                                };
+
+                                let end = match event.key().as_str() {
+                                    "Home" => false,
+                                    "End" => true,
+                                    _ => return,
+                                };
+
+                                event.prevent_default();
+                                scroll_to_edge(&container, end);
+                            })
+                        });
+
+                move || drop(listener)
+            },
+            (),
+        );
+    }
+
+    let scroll_buttons = {
+        let to_start = container.clone();
+        let to_end = container.clone();
+
+        html! {
+            <div class={classes!("absolute", "bottom-4", "right-6", "z-10", "flex", "flex-col", "gap-2")}>
+                <Button
+                    classes={classes!("rounded-2xl")}
+                    on_click={Callback::from(move |_| scroll_to_edge(&to_start, false))}
+                    icon={classes!("fas", "fa-angle-double-up")}
+                    title={tr(Text::GoToFirstLine)}
+                />
+                <Button
+                    classes={classes!("rounded-2xl")}
+                    on_click={Callback::from(move |_| scroll_to_edge(&to_end, true))}
+                    icon={classes!("fas", "fa-angle-double-down")}
+                    title={tr(Text::GoToLastLine)}
+                />
+            </div>
+        }
+    };
+
+    let (start, end) = viewport.lines(line_count, line_height);
+    let text = &props.text;
+
+    let view_line = |index: usize| {
+        let number = index + 1;
+        let line_end = line_starts
+            .get(index + 1)
+            .map_or(text.len(), |next| next - 1);
+        let line_start = line_starts[index];
+        let first_match = props
+            .matches
+            .partition_point(|range| range.start < line_start);
+
+        let mut parts = vec![];
+        let mut position = line_start;
+
+        for (match_index, range) in props
+            .matches
+            .iter()
+            .enumerate()
+            .skip(first_match)
+            .take_while(|(_, range)| range.end <= line_end)
+        {
+            let mark_classes = if props.current_match == Some(match_index) {
+                classes!("bg-orange-300", "dark:bg-orange-700")
+            } else {
+                classes!("bg-yellow-200", "dark:bg-yellow-800")
+            };
+
+            parts.push(html! { &text[position..range.start] });
+            parts.push(html! {
+                <mark class={mark_classes}>{ &text[range.clone()] }</mark>
+            });
+            position = range.end;
+        }
+
+        parts.push(html! { &text[position..line_end] });
+
+        let mut classes = classes!(
+            "block",
+            props.text_size.line_height_class(),
+            "whitespace-pre"
+        );
+        if props.highlighted_line == Some(number) {
+            classes.push(classes!("bg-yellow-100", "dark:bg-yellow-900"));
+        }
+
+        let mut number_classes = classes!(
+            "inline-block",
+            "w-16",
+            "pr-4",
+            "text-right",
+            "select-none",
+            "opacity-50",
+        );
+        let number = match &props.on_line_click {
+            Some(on_line_click) => {
+                number_classes.push(classes!(
+                    "cursor-pointer",
+                    "focus:outline-none",
+                    "focus-visible:ring",
+                    "focus-visible:ring-brand-focus",
+                    "dark:focus-visible:ring-brand-dark-focus",
+                ));
+
+                html! {
+                    <button class={number_classes} onclick={on_line_click.reform(move |_| number)}>
+                        { number }
+                    </button>
+                }
+            }
+            None => html! { <span class={number_classes}>{ number }</span> },
+        };
+
+        html! {
+            <span class={classes}>
+                { number }
+                { for parts }
+            </span>
+        }
+    };
+
+    let copy_button = if props.text.is_empty() {
+        html! {}
+    } else {
+        html! {
+            <CopyButton
+                classes={classes!("absolute", "top-2", "right-6", "z-10", "rounded-2xl")}
+                text={Rc::clone(&props.text)}
+                redact={props.redact}
+            />
+        }
+    };
+
+    html! {
+        <div class="relative">
+            { copy_button }
+            { scroll_buttons }
+
+            <pre
+                ref={container}
+                class={classes!(
+                    props.classes.clone(),
+                    "h-[70vh]",
+                    "overflow-auto",
+                    "rounded-2xl",
+                    "p-4",
+                    props.text_size.classes(),
+                )}
+                onscroll={on_scroll}
+            >
+                <div
+                    class="relative"
+                    style={format!("height: {}px", line_count as i32 * line_height)}
+                >
+                    <code
+                        class="absolute left-0 right-0"
+                        style={format!("top: {}px", start as i32 * line_height)}
+                    >
+                        { for (start..end).map(view_line) }
+                    </code>
+                </div>
+            </pre>
+        </div>
+    }
+}
+
+/// Scrolls the view so that the 1-based `line` is in the middle, if possible.
+fn scroll_to_line(
+    container: &NodeRef,
+    viewport: &UseStateHandle<Viewport>,
+    line: usize,
+    line_height: i32,
+) {
+    if let Some(element) = container.cast::<web_sys::Element>() {
+        let height = element.client_height();
+        let scroll_top = (line.saturating_sub(1) as i32 * line_height - height / 2).max(0);
+
+        element.set_scroll_top(scroll_top);
+        viewport.set(Viewport { scroll_top, height });
+    }
+}
+
+/// Scrolls the view to its first line, or to its last line if `end`; the scroll event updates the
+/// rendered lines.
+fn scroll_to_edge(container: &NodeRef, end: bool) {
+    if let Some(element) = container.cast::<web_sys::Element>() {
+        element.set_scroll_top(if end { element.scroll_height() } else { 0 });
+    }
+}
+
+/// Recomputes the cached line starts if they're not for `text`.
+fn update_line_starts(cache: &RefCell<(Rc<String>, Vec<usize>)>, text: &Rc<String>) {
+    let mut cache = cache.borrow_mut();
+
+    if !Rc::ptr_eq(&cache.0, text) {
+        *cache = (Rc::clone(text), line_starts(text));
+    }
+}
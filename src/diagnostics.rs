@@ -0,0 +1,213 @@
+use yew::prelude::*;
+
+use crate::{
+    components::{Button, ButtonSize, Message},
+    parsers::{Content, LogEntry},
+    tr, DumpedThread, Tab, Text,
+};
+
+/// Element ID of the title of the thread dump view, see `Content::view_information`.
+pub const THREADS_ID: &str = "threads";
+
+/// Parts of (lowercase) log messages that mark an "Application Not Responding" error.
+const ANR_PATTERNS: [&str; 3] = [
+    "anr in ",
+    "application not responding",
+    "input dispatching timed out",
+];
+/// Parts of (lowercase) log messages that mark a main thread that was blocked for a while.
+const MAIN_THREAD_BLOCKED_PATTERNS: [&str; 3] = [
+    "main thread blocked",
+    "blocked the main thread",
+    "doing too much work on its main thread",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiagnosticKind {
+    Anr,
+    MainThreadBlocked,
+}
+
+impl DiagnosticKind {
+    /// Name of the kind in the current language.
+    pub fn label(&self) -> &'static str {
+        match self {
+            DiagnosticKind::Anr => "ANR",
+            DiagnosticKind::MainThreadBlocked => tr(Text::MainThreadBlocked),
+        }
+    }
+}
+
+/// A sign of the app being unresponsive, found in the log entries or the thread dump.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    /// First line of the log entry, or a description of the finding.
+    pub description: String,
+    /// Element ID of the log entry it was found in, if any.
+    pub entry_id: Option<String>,
+    /// Whether the thread dump is relevant, e.g. because the main thread is blocked in it.
+    pub in_thread_dump: bool,
+}
+
+impl Diagnostic {
+    /// All diagnostics of `content`, in the order of the log entries; a blocked main thread in
+    /// the thread dump comes first.
+    pub fn all(content: &Content) -> Vec<Diagnostic> {
+        let threads = DumpedThread::all(content);
+
+        let blocked_main_thread = threads
+            .iter()
+            .find(|thread| thread.name == "main" && thread.is_blocked())
+            .map(|thread| Diagnostic {
+                kind: DiagnosticKind::MainThreadBlocked,
+                description: format!(
+                    "The main thread is {} in the thread dump.",
+                    thread.state.as_deref().unwrap_or("BLOCKED")
+                ),
+                entry_id: None,
+                in_thread_dump: true,
+            });
+
+        blocked_main_thread
+            .into_iter()
+            .chain(content.log_entries().filter_map(|entry| {
+                Some(Diagnostic {
+                    kind: Self::kind(entry)?,
+                    description: entry.message.lines().next().unwrap_or_default().to_owned(),
+                    entry_id: Some(entry.element_id()),
+                    in_thread_dump: !threads.is_empty(),
+                })
+            }))
+            .collect()
+    }
+
+    fn kind(entry: &LogEntry) -> Option<DiagnosticKind> {
+        let message = entry.message.to_lowercase();
+
+        if ANR_PATTERNS.iter().any(|pattern| message.contains(pattern)) {
+            Some(DiagnosticKind::Anr)
+        } else if MAIN_THREAD_BLOCKED_PATTERNS
+            .iter()
+            .any(|pattern| message.contains(pattern))
+        {
+            Some(DiagnosticKind::MainThreadBlocked)
+        } else {
+            None
+        }
+    }
+}
+
+/// Diagnostics with buttons that call `on_show` with the tab and element ID to show.
+pub fn view_diagnostics(diagnostics: &[Diagnostic], on_show: &Callback<(Tab, String)>) -> Html {
+    if diagnostics.is_empty() {
+        return html! {};
+    }
+
+    let view_diagnostic = |diagnostic: &Diagnostic| {
+        let entry_button = match &diagnostic.entry_id {
+            Some(id) => {
+                let id = id.clone();
+
+                html! {
+                    <Button
+                        classes={classes!("rounded-2xl", "ml-2")}
+                        size={ButtonSize::Small}
+                        icon={classes!("fas", "fa-stream")}
+                        text={tr(Text::ShowEntry)}
+                        on_click={on_show.reform(move |_| (Tab::Logs, id.clone()))}
+                    />
+                }
+            }
+            None => html! {},
+        };
+
+        let thread_dump_button = if diagnostic.in_thread_dump {
+            html! {
+                <Button
+                    classes={classes!("rounded-2xl", "ml-2")}
+                    size={ButtonSize::Small}
+                    icon={classes!("fas", "fa-layer-group")}
+                    text={tr(Text::ShowThreads)}
+                    on_click={on_show.reform(|_| (Tab::Information, THREADS_ID.to_owned()))}
+                />
+            }
+        } else {
+            html! {}
+        };
+
+        html! {
+            <p>
+                <strong>{ format!("{}: ", diagnostic.kind.label()) }</strong>
+                { &diagnostic.description }
+                { entry_button }
+                { thread_dump_button }
+            </p>
+        }
+    };
+
+    html! {
+        <Message classes={classes!("mb-4")} heading={tr(Text::Diagnostics)} warning=true>
+            { for diagnostics.iter().map(view_diagnostic) }
+        </Message>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::parsers::{InfoEntry, Section};
+
+    use super::*;
+
+    #[test_case("ANR in org.thoughtcrime.securesms (org.thoughtcrime.securesms/.MainActivity)" => Some(DiagnosticKind::Anr); "anr")]
+    #[test_case("Reason: Input dispatching timed out" => Some(DiagnosticKind::Anr); "input dispatching")]
+    #[test_case("Skipped 123 frames!  The application may be doing too much work on its main thread." => Some(DiagnosticKind::MainThreadBlocked); "skipped frames")]
+    #[test_case("Main thread blocked for 5000 ms" => Some(DiagnosticKind::MainThreadBlocked); "blocked")]
+    #[test_case("Nothing to see here" => None; "other")]
+    fn kind_ok(message: &str) -> Option<DiagnosticKind> {
+        Diagnostic::kind(&LogEntry::test(message, 0))
+    }
+
+    #[test]
+    fn all_ok() {
+        let content = Content {
+            information: vec![Section {
+                name: "BLOCKED THREADS".to_owned(),
+                content: vec![],
+                subsections: vec![Section {
+                    name: "[1] main (BLOCKED)".to_owned(),
+                    content: vec![InfoEntry::Generic("abc.def.Abc.run(ABC.java:1)".to_owned())],
+                    subsections: vec![],
+                }],
+            }],
+            logs: vec![Section {
+                name: "LOGCAT".to_owned(),
+                content: vec![
+                    LogEntry::test("Fine", 0),
+                    LogEntry::test("ANR in org.thoughtcrime.securesms\nPID: 1234", 10),
+                ],
+                subsections: vec![],
+            }],
+        };
+
+        assert_eq!(
+            Diagnostic::all(&content),
+            vec![
+                Diagnostic {
+                    kind: DiagnosticKind::MainThreadBlocked,
+                    description: "The main thread is BLOCKED in the thread dump.".to_owned(),
+                    entry_id: None,
+                    in_thread_dump: true,
+                },
+                Diagnostic {
+                    kind: DiagnosticKind::Anr,
+                    description: "ANR in org.thoughtcrime.securesms".to_owned(),
+                    entry_id: Some("entry-10".to_owned()),
+                    in_thread_dump: true,
+                },
+            ]
+        );
+    }
+}
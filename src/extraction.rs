@@ -0,0 +1,290 @@
+use std::{
+    io::{self, Cursor},
+    mem,
+    ops::Range,
+    rc::Rc,
+};
+
+use chrono::{NaiveDate, NaiveDateTime};
+use gloo::timers::callback::Timeout;
+use gloo_worker::{HandlerId, Worker, WorkerScope};
+use serde::{Deserialize, Serialize};
+use zip::ZipArchive;
+
+use crate::{
+    model::truncate,
+    parsers::{self, Content, LogFilename},
+    redaction::Identifier,
+    File, Platform, RemoteObject,
+};
+
+/// Script of the worker, as built from `src/bin/readlogs-worker.rs` (see `index.html`).
+pub const WORKER_PATH: &str = "/readlogs-worker.js";
+
+/// A `zip` archive whose files the worker extracts and parses.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExtractionRequest {
+    pub bytes: Vec<u8>,
+    pub platform: Platform,
+    /// Only (about) the last bytes of every file are kept, if given.
+    pub max_file_size: Option<usize>,
+}
+
+/// What became of an entry of the archive; sent for every entry, in order.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExtractedEntry {
+    pub index: usize,
+    /// `Err` with the entry's name if it's no log file or can't be extracted.
+    pub file: Result<ExtractedFile, String>,
+}
+
+/// A file extracted from the archive, as it was parsed.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExtractedFile {
+    path: String,
+    /// When the file was last modified, in seconds since the Unix epoch.
+    modified: i64,
+    text: String,
+    /// The parsed content as JSON, which (unlike the encoding of messages) supports all of it,
+    /// e.g. JSON values; `Err` with the reason if parsing failed.
+    parsed: Result<String, String>,
+    /// Identifiers in the message of every log entry, in the order of
+    /// [`Content::log_entries`], as they aren't part of the JSON.
+    sensitive: Vec<Vec<(Range<usize>, Identifier)>>,
+    /// Time spent extracting and parsing the file, in milliseconds.
+    pub parse_ms: f64,
+}
+
+impl ExtractedFile {
+    /// The file as part of the debug log at `reference`, with its name.
+    pub fn into_file(self, reference: RemoteObject, id_prefix: String) -> (Rc<LogFilename>, File) {
+        let name = Rc::new(LogFilename::from_path(
+            &self.path,
+            NaiveDateTime::from_timestamp(self.modified, 0),
+        ));
+
+        let parsed = Self::content(self.parsed, self.sensitive);
+
+        let file = File::from_parsed(
+            reference,
+            Some(Rc::clone(&name)),
+            self.text,
+            parsed,
+            id_prefix,
+        );
+        (name, file)
+    }
+
+    /// The parsed content, with the identifiers in its log messages restored.
+    fn content(
+        parsed: Result<String, String>,
+        sensitive: Vec<Vec<(Range<usize>, Identifier)>>,
+    ) -> anyhow::Result<Content> {
+        let mut content = serde_json::from_str::<Content>(&parsed.map_err(anyhow::Error::msg)?)?;
+        for (entry, sensitive) in content.log_entries_mut().zip(sensitive) {
+            entry.sensitive = sensitive;
+        }
+        Ok(content)
+    }
+}
+
+/// Extracts and parses the files of a `zip` archive off the page's thread, one after another,
+/// sending each as soon as it's done; stops once the page disconnects.
+#[derive(Debug)]
+pub struct ArchiveExtractor {
+    job: Option<Job>,
+}
+
+/// An archive that is being extracted.
+#[derive(Debug)]
+struct Job {
+    requester: HandlerId,
+    archive: ZipArchive<Cursor<Vec<u8>>>,
+    platform: Platform,
+    max_file_size: Option<usize>,
+    /// Number of files that are done, i.e. the index of the next one.
+    extracted: usize,
+    /// Extracts the next file, unless dropped before.
+    next: Timeout,
+}
+
+impl Worker for ArchiveExtractor {
+    /// Extracts the next file of the archive.
+    type Message = ();
+    type Input = ExtractionRequest;
+    /// `Err` if the archive can't be read at all.
+    type Output = Result<ExtractedEntry, String>;
+
+    fn create(_: &WorkerScope<Self>) -> Self {
+        Self { job: None }
+    }
+
+    fn update(&mut self, scope: &WorkerScope<Self>, _: ()) {
+        let job = match &mut self.job {
+            Some(job) => job,
+            None => return,
+        };
+
+        let index = job.extracted;
+        let started = js_sys::Date::now();
+        let file = extract_file(&mut job.archive, index, job.platform, job.max_file_size).map(
+            |mut file| {
+                file.parse_ms = js_sys::Date::now() - started;
+                file
+            },
+        );
+        scope.respond(job.requester, Ok(ExtractedEntry { index, file }));
+
+        job.extracted += 1;
+        if job.extracted < job.archive.len() {
+            job.next = Self::extract_later(scope);
+        } else {
+            self.job = None;
+        }
+    }
+
+    fn received(&mut self, scope: &WorkerScope<Self>, request: ExtractionRequest, id: HandlerId) {
+        self.job = match ZipArchive::new(Cursor::new(request.bytes)) {
+            Ok(archive) => Some(Job {
+                requester: id,
+                archive,
+                platform: request.platform,
+                max_file_size: request.max_file_size,
+                extracted: 0,
+                next: Self::extract_later(scope),
+            }),
+            Err(e) => {
+                scope.respond(id, Err(e.to_string()));
+                None
+            }
+        };
+    }
+
+    fn disconnected(&mut self, _: &WorkerScope<Self>, _: HandlerId) {
+        self.job = None;
+    }
+}
+
+impl ArchiveExtractor {
+    /// Extracts the next file once the messages that arrived in the meantime (e.g. that the
+    /// page disconnected) were handled.
+    fn extract_later(scope: &WorkerScope<Self>) -> Timeout {
+        let scope = scope.clone();
+        Timeout::new(0, move || scope.send_message(()))
+    }
+}
+
+/// Extracts and parses the file at the index of the archive; `Err` with the entry's name if it's
+/// no log file or can't be extracted.
+fn extract_file(
+    archive: &mut ZipArchive<Cursor<Vec<u8>>>,
+    index: usize,
+    platform: Platform,
+    max_file_size: Option<usize>,
+) -> Result<ExtractedFile, String> {
+    let mut file = archive
+        .by_index(index)
+        .map_err(|e| format!("#{} ({})", index + 1, e))?;
+
+    if parsers::is_junk_entry(file.name(), file.size()) {
+        return Err(file.name().to_owned());
+    }
+
+    let modified = file.last_modified();
+    let modified = NaiveDate::from_ymd_opt(
+        modified.year().into(),
+        modified.month().into(),
+        modified.day().into(),
+    )
+    .and_then(|date| {
+        date.and_hms_opt(
+            modified.hour().into(),
+            modified.minute().into(),
+            modified.second().into(),
+        )
+    })
+    .map_or(0, |modified| modified.timestamp());
+    let path = file.name().to_owned();
+
+    let mut bytes: Vec<u8> = vec![];
+    io::copy(&mut file, &mut bytes).map_err(|e| format!("{} ({})", path, e))?;
+    let text = match String::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(e) => String::from_utf8_lossy(e.as_bytes()).into_owned(),
+    };
+    let text = truncate(text, max_file_size);
+
+    let (parsed, sensitive) = match Content::parse(platform, &text) {
+        Ok(mut content) => {
+            let sensitive = content
+                .log_entries_mut()
+                .map(|entry| mem::take(&mut entry.sensitive))
+                .collect();
+            (
+                serde_json::to_string(&content).map_err(|e| e.to_string()),
+                sensitive,
+            )
+        }
+        Err(e) => (Err(e.to_string()), vec![]),
+    };
+
+    Ok(ExtractedFile {
+        path,
+        modified,
+        text,
+        parsed,
+        sensitive,
+        parse_ms: 0.0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use zip::{write::FileOptions, ZipWriter};
+
+    use super::*;
+
+    const LOG_PATH: &str = "1234.01.23 12.34.56 ABCD1234-1AB2-3CDE-456F-789AB0CD1E2F/org.whispersystems.signal 1234-01-22--06-54-32-109.log";
+    const LOG_TEXT: &str =
+        "1234/01/23 12:34:56:100 💛 Sending to +14155550123\n1234/01/23 12:34:56:200 💛 Sent";
+
+    fn archive() -> ZipArchive<Cursor<Vec<u8>>> {
+        let mut writer = ZipWriter::new(Cursor::new(vec![]));
+        writer
+            .add_directory("1234.01.23 12.34.56 ABCD/", FileOptions::default())
+            .unwrap();
+        writer.start_file(LOG_PATH, FileOptions::default()).unwrap();
+        writer.write_all(LOG_TEXT.as_bytes()).unwrap();
+
+        ZipArchive::new(Cursor::new(writer.finish().unwrap().into_inner())).unwrap()
+    }
+
+    #[test]
+    fn extract_file_junk() {
+        assert_eq!(
+            extract_file(&mut archive(), 0, Platform::Ios, None).unwrap_err(),
+            "1234.01.23 12.34.56 ABCD/"
+        );
+    }
+
+    #[test]
+    fn extract_file_ok() {
+        let remote_object = RemoteObject::new_unchecked(
+            Platform::Ios,
+            None,
+            "0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123",
+        );
+
+        let extracted = extract_file(&mut archive(), 1, Platform::Ios, None).unwrap();
+        assert_eq!(
+            ExtractedFile::content(extracted.parsed.clone(), extracted.sensitive.clone()).unwrap(),
+            Content::parse(Platform::Ios, LOG_TEXT).unwrap()
+        );
+
+        let (name, file) = extracted.into_file(remote_object, String::new());
+        assert_eq!(name.path, LOG_PATH);
+        assert_eq!(file.summary().entry_count, Some(2));
+    }
+}
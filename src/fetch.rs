@@ -0,0 +1,339 @@
+use std::{
+    fmt,
+    io::{self, Write},
+};
+
+use flate2::write::GzDecoder;
+use js_sys::{Function, Promise, Reflect, Uint8Array};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use reqwasm::http::{Request, Response};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::AbortSignal;
+use yew::Callback;
+
+use crate::{parsers::GZIP_MAGIC, Text};
+
+/// Number of bytes after which the progress of a download is reported again.
+const PROGRESS_STEP_BYTES: usize = 256 * 1024;
+
+/// How often a fetch that failed temporarily is started again, before the failure is shown.
+pub const MAX_RETRIES: u32 = 3;
+/// Delay before the first retry, which doubles with every further one.
+const FIRST_RETRY_DELAY_MS: u32 = 1000;
+
+/// How long to wait before retrying a fetch that was already retried `retries` times.
+pub fn retry_delay_ms(retries: u32) -> u32 {
+    FIRST_RETRY_DELAY_MS << retries
+}
+
+/// Placeholder for the URL to fetch in the template of a proxy's URL.
+pub const PROXY_URL_PLACEHOLDER: &str = "{url}";
+
+/// The URL to fetch the URL through a proxy with: the template with the placeholder replaced by
+/// the encoded URL, or else with the URL appended as is.
+pub fn proxied_url(template: &str, url: &str) -> String {
+    if template.contains(PROXY_URL_PLACEHOLDER) {
+        template.replace(
+            PROXY_URL_PLACEHOLDER,
+            &utf8_percent_encode(url, NON_ALPHANUMERIC).to_string(),
+        )
+    } else {
+        format!("{}{}", template, url)
+    }
+}
+
+/// How much of a response was downloaded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Progress {
+    pub received: usize,
+    /// Size of the response, if the server sent it.
+    pub total: Option<usize>,
+}
+
+impl Progress {
+    /// The total size, unless it's evidently not the size of what is received, as it would be
+    /// for a compressed response.
+    pub fn total(&self) -> Option<usize> {
+        self.total.filter(|total| *total >= self.received)
+    }
+
+    pub fn percent(&self) -> Option<usize> {
+        self.total()
+            .map(|total| (self.received * 100).checked_div(total).unwrap_or(100))
+    }
+}
+
+/// Collects the chunks of a response body, decompressing them on the fly if they turn out to be
+/// `gzip` data (which isn't decoded by the browser if sent without a `Content-Encoding`), so that
+/// the compressed body is never held in full.
+#[derive(Debug)]
+enum BodyDecoder {
+    /// The first bytes, as long as there are too few of them to tell whether they're `gzip` data.
+    Undecided(Vec<u8>),
+    Plain(Vec<u8>),
+    Gzip(GzDecoder<Vec<u8>>),
+}
+
+impl BodyDecoder {
+    fn write(&mut self, chunk: &[u8]) -> io::Result<()> {
+        match self {
+            BodyDecoder::Undecided(start) => {
+                start.extend_from_slice(chunk);
+                if start.len() < GZIP_MAGIC.len() {
+                    return Ok(());
+                }
+
+                let start = std::mem::take(start);
+                *self = if start.starts_with(&GZIP_MAGIC) {
+                    BodyDecoder::Gzip(GzDecoder::new(vec![]))
+                } else {
+                    BodyDecoder::Plain(vec![])
+                };
+                self.write(&start)
+            }
+            BodyDecoder::Plain(bytes) => {
+                bytes.extend_from_slice(chunk);
+                Ok(())
+            }
+            BodyDecoder::Gzip(decoder) => decoder.write_all(chunk),
+        }
+    }
+
+    fn finish(self) -> io::Result<Vec<u8>> {
+        match self {
+            BodyDecoder::Undecided(bytes) => Ok(bytes),
+            BodyDecoder::Plain(bytes) => Ok(bytes),
+            BodyDecoder::Gzip(decoder) => decoder.finish(),
+        }
+    }
+}
+
+/// Why a debug log couldn't be fetched.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FetchError {
+    /// There was no response, e.g. without a connection, or because the request was blocked.
+    Network(String),
+    /// The response had a status code other than 200.
+    Status(u16),
+    /// The fetch was canceled before it finished.
+    Aborted,
+    /// The response looked compressed, but couldn't be decompressed.
+    Decompression(String),
+}
+
+impl FetchError {
+    /// What the failure likely means, and what can be done about it.
+    pub fn guidance(&self) -> Text {
+        match self {
+            FetchError::Network(_) => Text::FetchNetworkError,
+            FetchError::Status(404 | 410) => Text::FetchExpired,
+            FetchError::Status(403) => Text::FetchForbidden,
+            FetchError::Status(500..=599) => Text::FetchServerError,
+            FetchError::Status(_) => Text::FetchUnexpectedStatus,
+            FetchError::Aborted => Text::FetchCanceled,
+            FetchError::Decompression(_) => Text::FetchCorrupt,
+        }
+    }
+
+    /// Whether fetching again might work, as the failure is likely temporary.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, FetchError::Network(_) | FetchError::Status(500..=599))
+    }
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Network(message) => write!(f, "no response: {}", message),
+            FetchError::Status(status) => write!(f, "status code {}", status),
+            FetchError::Aborted => write!(f, "canceled"),
+            FetchError::Decompression(message) => write!(f, "couldn't decompress: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<reqwasm::Error> for FetchError {
+    fn from(e: reqwasm::Error) -> Self {
+        match e {
+            reqwasm::Error::JsError(e) if e.name == "AbortError" => FetchError::Aborted,
+            e => FetchError::Network(e.to_string()),
+        }
+    }
+}
+
+impl From<io::Error> for FetchError {
+    fn from(e: io::Error) -> Self {
+        FetchError::Decompression(e.to_string())
+    }
+}
+
+impl From<JsValue> for FetchError {
+    fn from(value: JsValue) -> Self {
+        match value.dyn_ref::<js_sys::Error>() {
+            Some(e) if e.name() == "AbortError" => FetchError::Aborted,
+            Some(e) => FetchError::Network(e.message().into()),
+            None => FetchError::Network(format!("{:?}", value)),
+        }
+    }
+}
+
+/// Starts fetching, which is aborted once the `signal` is.
+async fn get(url: &str, signal: &AbortSignal) -> Result<Response, FetchError> {
+    let response = Request::get(url).abort_signal(Some(signal)).send().await?;
+
+    match response.status() {
+        200 => Ok(response),
+        status => Err(FetchError::Status(status)),
+    }
+}
+
+/// Reads the body of the response chunk by chunk, decompressing it if needed and reporting the
+/// progress (of the body as it is sent) along the way, and once more when it's done.
+async fn read_body(
+    response: Response,
+    on_progress: &Callback<Progress>,
+) -> Result<Vec<u8>, FetchError> {
+    let total = response
+        .headers()
+        .get("content-length")
+        .ok()
+        .flatten()
+        .and_then(|length| length.parse().ok());
+
+    let body = match response.body() {
+        Some(body) => body,
+        None => {
+            let bytes = response.binary().await?;
+            on_progress.emit(Progress {
+                received: bytes.len(),
+                total,
+            });
+            return Ok(bytes);
+        }
+    };
+
+    let reader = body.get_reader();
+    let read = Reflect::get(&reader, &"read".into())?.dyn_into::<Function>()?;
+
+    let mut decoder = BodyDecoder::Undecided(vec![]);
+    let mut received = 0;
+    let mut reported = 0;
+
+    loop {
+        let chunk = JsFuture::from(read.call0(&reader)?.dyn_into::<Promise>()?).await?;
+
+        if Reflect::get(&chunk, &"done".into())?.is_truthy() {
+            break;
+        }
+
+        let value = Uint8Array::new(&Reflect::get(&chunk, &"value".into())?).to_vec();
+        decoder.write(&value)?;
+        received += value.len();
+
+        if received - reported >= PROGRESS_STEP_BYTES {
+            reported = received;
+            on_progress.emit(Progress { received, total });
+        }
+    }
+
+    on_progress.emit(Progress { received, total });
+    Ok(decoder.finish()?)
+}
+
+pub async fn fetch_text(
+    url: &str,
+    signal: &AbortSignal,
+    on_progress: &Callback<Progress>,
+) -> Result<String, FetchError> {
+    let bytes = read_body(get(url, signal).await?, on_progress).await?;
+
+    Ok(match String::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(e) => String::from_utf8_lossy(e.as_bytes()).into_owned(),
+    })
+}
+
+pub async fn fetch_binary(
+    url: &str,
+    signal: &AbortSignal,
+    on_progress: &Callback<Progress>,
+) -> Result<Vec<u8>, FetchError> {
+    read_body(get(url, signal).await?, on_progress).await
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(FetchError::Network("Failed to fetch".to_owned()) => (Text::FetchNetworkError, true); "network")]
+    #[test_case(FetchError::Status(404) => (Text::FetchExpired, false); "not found")]
+    #[test_case(FetchError::Status(410) => (Text::FetchExpired, false); "gone")]
+    #[test_case(FetchError::Status(403) => (Text::FetchForbidden, false); "forbidden")]
+    #[test_case(FetchError::Status(503) => (Text::FetchServerError, true); "server error")]
+    #[test_case(FetchError::Status(400) => (Text::FetchUnexpectedStatus, false); "other")]
+    #[test_case(FetchError::Aborted => (Text::FetchCanceled, false); "aborted")]
+    #[test_case(FetchError::Decompression("corrupt deflate stream".to_owned()) => (Text::FetchCorrupt, false); "decompression")]
+    fn guidance_ok(error: FetchError) -> (Text, bool) {
+        (error.guidance(), error.is_transient())
+    }
+
+    #[test_case(50, Some(200) => (Some(200), Some(25)); "known size")]
+    #[test_case(300, Some(200) => (None, None); "compressed size")]
+    #[test_case(0, Some(0) => (Some(0), Some(100)); "empty")]
+    #[test_case(50, None => (None, None); "unknown size")]
+    fn progress_ok(received: usize, total: Option<usize>) -> (Option<usize>, Option<usize>) {
+        let progress = Progress { received, total };
+        (progress.total(), progress.percent())
+    }
+
+    fn decode(body: &[u8], chunk_size: usize) -> Vec<u8> {
+        let mut decoder = BodyDecoder::Undecided(vec![]);
+        for chunk in body.chunks(chunk_size) {
+            decoder.write(chunk).unwrap();
+        }
+
+        decoder.finish().unwrap()
+    }
+
+    #[test_case(b"line 1\nline 2\n", 4; "chunks")]
+    #[test_case(b"line 1\nline 2\n", 1; "single bytes")]
+    #[test_case(b"\x1f", 1; "shorter than the gzip magic")]
+    fn body_decoder_ok_plain(body: &[u8], chunk_size: usize) {
+        assert_eq!(decode(body, chunk_size), body);
+    }
+
+    #[test_case(7; "chunks")]
+    #[test_case(1; "single bytes")]
+    fn body_decoder_ok_gzip(chunk_size: usize) {
+        let text = "INFO 2021-01-01 something happened\n".repeat(100);
+
+        let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+        encoder.write_all(text.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        assert_eq!(decode(&compressed, chunk_size), text.as_bytes());
+    }
+
+    #[test]
+    fn body_decoder_ok_empty() {
+        assert_eq!(decode(b"", 1), b"");
+    }
+
+    #[test_case("https://proxy.example.com/?url={url}", "https://debuglogs.org/abc.zip" => "https://proxy.example.com/?url=https%3A%2F%2Fdebuglogs%2Eorg%2Fabc%2Ezip"; "placeholder")]
+    #[test_case("https://proxy.example.com/", "https://debuglogs.org/abc.zip" => "https://proxy.example.com/https://debuglogs.org/abc.zip"; "appended")]
+    fn proxied_url_ok(template: &str, url: &str) -> String {
+        proxied_url(template, url)
+    }
+
+    #[test_case(0 => 1000)]
+    #[test_case(1 => 2000)]
+    #[test_case(2 => 4000)]
+    fn retry_delay_ms_ok(retries: u32) -> u32 {
+        retry_delay_ms(retries)
+    }
+}
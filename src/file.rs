@@ -1,20 +1,249 @@
-use std::rc::Rc;
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    ops::Range,
+    rc::Rc,
+};
 
 use anyhow::Context;
+use chrono::prelude::*;
 use yew::prelude::*;
 
 use crate::{
-    components::{ButtonSize, CodeBlock, DownloadButton, Message, Title, TitleLevel},
+    calls::{self, Call},
+    components::{
+        Button, ButtonSize, CodeBlock, CopyButton, DownloadButton, Message, MessageTimeline, Table,
+        TableItem, TableRow, TextSize, Title, TitleLevel, VirtualCodeBlock,
+    },
+    diagnostics::{self, Diagnostic},
+    export, find_ignore_ascii_case, format_duration, i18n,
+    info_diff::InfoDiff,
+    line_number, line_start,
+    minimap::{self, MinimapMarker},
+    notifications::{self, DeliveryStats, PushDelivery},
     parsers::*,
-    Platform, RemoteObject, RenderedLogSection, SearchQuery, Tab,
+    permalink, redaction, source_links,
+    summary::{self, LogSummary, LogSummaryView},
+    tr, LogLevel, LogLevelView, MessageTrace, Platform, RemoteObject, RenderedLogSection,
+    SearchQuery, Tab, Text,
 };
 
+const COMBINED_SECTION_NAME: &str = "Combined";
+
+/// Buttons to show or hide every column that the logs table of `content` has.
+fn view_column_toggles(content: &Content, log_options: &LogViewOptions) -> Html {
+    let columns = content
+        .logs
+        .iter()
+        .flat_map(|section| section.iter_sections())
+        .flat_map(|section| section.columns())
+        .collect::<BTreeSet<_>>();
+
+    if columns.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <div class={classes!("flex", "flex-wrap", "items-center", "gap-2", "my-4", "text-sm")}>
+            <span>{ tr(Text::Columns) }</span>
+            {
+                for columns.into_iter().map(|column| html! {
+                    <Button
+                        classes={classes!("rounded-2xl")}
+                        size={ButtonSize::Small}
+                        active={!log_options.hidden_columns.contains(&column)}
+                        text={column.to_string()}
+                        on_click={log_options.on_toggle_column.reform(move |_| column)}
+                    />
+                })
+            }
+        </div>
+    }
+}
+
 #[derive(Debug)]
 pub struct File {
     remote_object: RemoteObject,
     name: Option<Rc<LogFilename>>,
+    /// Prefix of the element IDs of the log entries, which tells them apart from those of other
+    /// files and debug logs; IDs passed to and from the file have it, unlike
+    /// [`LogEntry::element_id`].
+    id_prefix: String,
     text: Rc<String>,
     parsed: anyhow::Result<Content>,
+    summary: FileSummary,
+    analyses: Analyses,
+    /// Markers of the minimap for the last displayed log entries.
+    minimap: RefCell<Option<MinimapCache>>,
+    exports: RefCell<Exports>,
+    /// Generated when the Raw tab is shown, and dropped when it's left. Boxed, as most files
+    /// never need it.
+    raw_tab: RefCell<Box<RawTab>>,
+}
+
+#[derive(Debug, Default)]
+struct RawTab {
+    exports: Option<RawExports>,
+    search: Option<RawSearch>,
+}
+
+/// Analyses of the parsed file that are shown next to it, made once when it's loaded rather than
+/// on every render.
+#[derive(Debug, Default)]
+struct Analyses {
+    log_summary: LogSummary,
+    info: InfoAnalyses,
+    diagnostics: Vec<Diagnostic>,
+    delivery_stats: Vec<DeliveryStats>,
+    calls: Vec<Call>,
+}
+
+impl Analyses {
+    fn new(remote_object: &RemoteObject, parsed: &anyhow::Result<Content>) -> Self {
+        match parsed {
+            Ok(parsed) => Self {
+                log_summary: LogSummary::new(remote_object, parsed),
+                info: InfoAnalyses::new(parsed),
+                diagnostics: Diagnostic::all(parsed),
+                delivery_stats: DeliveryStats::all(&PushDelivery::all(parsed)),
+                calls: Call::all(parsed),
+            },
+            Err(_) => Self::default(),
+        }
+    }
+}
+
+/// Minimap markers, with the settings of the log entries they were made for.
+#[derive(Debug)]
+struct MinimapCache {
+    query: SearchQuery,
+    collapsed_sections: BTreeSet<String>,
+    newest_first: bool,
+    markers: Rc<Vec<MinimapMarker>>,
+}
+
+/// Downloads of the Information and Logs tabs, generated again only once they're shown with
+/// other settings.
+#[derive(Debug, Default)]
+struct Exports {
+    /// Keyed by whether it's redacted and the annotations.
+    summary: Option<CachedExport<(bool, BTreeMap<String, String>)>>,
+    /// Keyed by whether it's redacted and the query.
+    filtered_text: Option<CachedExport<(bool, SearchQuery)>>,
+    /// Keyed by whether it's redacted and the query.
+    csv: Option<CachedExport<(bool, SearchQuery)>>,
+    /// URL of a prefilled issue.
+    issue_url: Option<CachedExport<IssueKey>>,
+}
+
+/// Whether an issue is redacted, the annotations and the element IDs of the selected entries.
+type IssueKey = (bool, BTreeMap<String, String>, BTreeSet<String>);
+
+/// Generated content, with the settings it was generated for.
+#[derive(Debug)]
+struct CachedExport<K> {
+    key: K,
+    content: Rc<String>,
+}
+
+/// The content in `cache` if it was generated for `key`, or else what `generate` returns, which
+/// is cached instead.
+fn cached_export<K: PartialEq>(
+    cache: &mut Option<CachedExport<K>>,
+    key: K,
+    generate: impl FnOnce() -> String,
+) -> Rc<String> {
+    match cache {
+        Some(cache) if cache.key == key => Rc::clone(&cache.content),
+        _ => {
+            let content = Rc::new(generate());
+            *cache = Some(CachedExport {
+                key,
+                content: Rc::clone(&content),
+            });
+            content
+        }
+    }
+}
+
+/// Downloads of the Raw tab, which may be as large as the text itself.
+#[derive(Debug)]
+struct RawExports {
+    redact: bool,
+    /// Notes that are included in the JSON export.
+    annotations: BTreeMap<String, String>,
+    text: Rc<String>,
+    /// `None` if the file couldn't be parsed or serialized.
+    json: Option<Rc<String>>,
+}
+
+/// The last search of the raw text.
+#[derive(Debug)]
+struct RawSearch {
+    query: String,
+    matches: Rc<Vec<Range<usize>>>,
+}
+
+/// Settings of the raw text view.
+#[derive(Debug, Clone)]
+pub struct RawViewOptions {
+    /// 1-based number of the line that was navigated to.
+    pub line: Option<usize>,
+    /// Text to search for in the raw text (ignoring case); empty to not search.
+    pub query: String,
+    /// Index of the match to scroll to, wrapping around the number of matches.
+    pub current_match: isize,
+    /// Called with the 1-based number of a clicked line, to show its log entry.
+    pub on_show_line_in_logs: Callback<usize>,
+    pub text_size: TextSize,
+}
+
+/// Basic facts about a file, to help choose the one that covers the time of interest.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileSummary {
+    /// Size of the text in bytes.
+    pub size: usize,
+    /// `None` if the file couldn't be parsed.
+    pub entry_count: Option<usize>,
+    /// Times of the earliest and the latest log entries.
+    pub time_range: Option<(DateTime<Utc>, DateTime<Utc>)>,
+}
+
+impl FileSummary {
+    fn new(text: &str, parsed: &anyhow::Result<Content>) -> Self {
+        let parsed = parsed.as_ref().ok();
+
+        let time_range = parsed.and_then(|parsed| {
+            let times = parsed
+                .log_entries()
+                .map(|entry| entry.timestamp)
+                .collect::<Vec<_>>();
+
+            Some((*times.iter().min()?, *times.iter().max()?))
+        });
+
+        Self {
+            size: text.len(),
+            entry_count: parsed.map(|parsed| parsed.log_entries().count()),
+            time_range,
+        }
+    }
+
+    /// Entry count and covered time span, e.g. `123 entries, 2 h 5 min (… – …)`.
+    pub fn coverage(&self) -> Option<String> {
+        let entry_count = self.entry_count?;
+
+        Some(match self.time_range {
+            Some((first, last)) => format!(
+                "{}, {} ({} – {})",
+                i18n::entries_count(entry_count),
+                format_duration(last - first),
+                first,
+                last
+            ),
+            None => i18n::entries_count(entry_count),
+        })
+    }
 }
 
 impl File {
@@ -22,99 +251,1059 @@ impl File {
         remote_object: RemoteObject,
         name: Option<Rc<LogFilename>>,
         text: String,
+        id_prefix: String,
     ) -> Self {
-        let parsed = Content::parse(remote_object.platform(), &text).context(format!(
+        let parsed = Content::parse(remote_object.platform(), &text);
+        Self::from_parsed(remote_object, name, text, parsed, id_prefix)
+    }
+
+    /// A file whose text was already parsed, e.g. by the worker that extracts `zip` archives.
+    pub fn from_parsed(
+        remote_object: RemoteObject,
+        name: Option<Rc<LogFilename>>,
+        text: String,
+        parsed: anyhow::Result<Content>,
+        id_prefix: String,
+    ) -> Self {
+        let parsed = parsed.context(format!(
             "failed to parse {} debug log file",
             remote_object.platform()
         ));
 
         Self {
+            summary: FileSummary::new(&text, &parsed),
+            analyses: Analyses::new(&remote_object, &parsed),
             remote_object,
             name,
+            id_prefix,
             text: Rc::new(text),
             parsed,
+            minimap: Default::default(),
+            exports: Default::default(),
+            raw_tab: Default::default(),
         }
     }
 
-    pub fn view(&self, tab: Tab, query: &SearchQuery) -> Html {
+    /// Merges log entries of all `files` into one chronologically sorted pseudo-file, with the
+    /// origin of every entry. Files that failed to parse are left out.
+    pub fn combined<'a>(
+        remote_object: RemoteObject,
+        files: impl IntoIterator<Item = (&'a Rc<LogFilename>, &'a File)>,
+        id_prefix: String,
+    ) -> Self {
+        let mut text = String::new();
+        let mut entries = vec![];
+
+        for (name, file) in files {
+            let offset = text.len();
+            text.push_str(&file.text);
+            text.push('\n');
+
+            if let Ok(parsed) = &file.parsed {
+                entries.extend(parsed.log_entries().map(|entry| LogEntry {
+                    source: entry.source.start + offset..entry.source.end + offset,
+                    origin: Some(name.app_id.clone()),
+                    ..entry.clone()
+                }));
+            }
+        }
+
+        entries.sort_by_key(|entry| entry.timestamp);
+
+        let parsed = Ok(Content {
+            information: vec![],
+            logs: vec![Section {
+                name: COMBINED_SECTION_NAME.to_owned(),
+                content: entries,
+                subsections: vec![],
+            }],
+        });
+
+        Self {
+            summary: FileSummary::new(&text, &parsed),
+            analyses: Analyses::new(&remote_object, &parsed),
+            remote_object,
+            name: None,
+            id_prefix,
+            text: Rc::new(text),
+            parsed,
+            minimap: Default::default(),
+            exports: Default::default(),
+            raw_tab: Default::default(),
+        }
+    }
+
+    /// Element ID of the log entry of this file, with the prefix of the file.
+    fn entry_id(&self, entry: &LogEntry) -> String {
+        format!("{}{}", self.id_prefix, entry.element_id())
+    }
+
+    /// The element IDs of log entries of this file among the `ids`, without the prefix of the
+    /// file, i.e. as [`LogEntry::element_id`].
+    fn own_ids(&self, ids: &BTreeSet<String>) -> BTreeSet<String> {
+        ids.iter()
+            .filter_map(|id| id.strip_prefix(&self.id_prefix))
+            .map(str::to_owned)
+            .collect()
+    }
+
+    /// The notes of log entries of this file, by element ID without the prefix of the file.
+    fn own_annotations(&self, annotations: &BTreeMap<String, String>) -> BTreeMap<String, String> {
+        annotations
+            .iter()
+            .filter_map(|(id, note)| {
+                Some((id.strip_prefix(&self.id_prefix)?.to_owned(), note.clone()))
+            })
+            .collect()
+    }
+
+    /// Calls `on_show` with the element IDs of log entries of this file with the prefix of the
+    /// file, for views that know them only as [`LogEntry::element_id`]; the Logs tab has no other
+    /// elements to show.
+    fn on_show_own(&self, on_show: &Callback<(Tab, String)>) -> Callback<(Tab, String)> {
+        let id_prefix = self.id_prefix.clone();
+
+        on_show.reform(move |(tab, id): (Tab, String)| match tab {
+            Tab::Logs => (tab, format!("{}{}", id_prefix, id)),
+            Tab::Information | Tab::Raw => (tab, id),
+        })
+    }
+
+    /// Element ID of the log entry with the number, see [`Content::log_entry_by_number`].
+    pub fn entry_id_by_number(&self, number: usize) -> Option<String> {
+        self.parsed
+            .as_ref()
+            .ok()?
+            .log_entry_by_number(number)
+            .map(|entry| self.entry_id(entry))
+    }
+
+    /// Element ID of the log entry that was parsed from the 1-based `line`.
+    pub fn entry_id_at_line(&self, line: usize) -> Option<String> {
+        let offset = line_start(&self.text, line)?;
+
+        self.parsed
+            .as_ref()
+            .ok()?
+            .log_entry_at(offset)
+            .map(|entry| self.entry_id(entry))
+    }
+
+    /// Level of the log entry with the element ID, if it's in this file.
+    pub fn entry_level(&self, id: &str) -> Option<LogLevel> {
+        self.parsed
+            .as_ref()
+            .ok()?
+            .log_entries()
+            .find(|entry| self.entry_id(entry) == id)
+            .map(|entry| entry.level.unwrap_or_default())
+    }
+
+    /// The log entries that match the `query`, serialized as a JSON array.
+    pub fn filtered_entries_json(&self, query: &SearchQuery) -> String {
+        let entries: Vec<_> = match &self.parsed {
+            Ok(parsed) => parsed.filtered_log_entries(query).collect(),
+            Err(_) => vec![],
+        };
+
+        serde_json::to_string(&entries).unwrap_or_else(|_| "[]".to_owned())
+    }
+
+    /// Element IDs and levels of the log entries displayed for the `query`, in the order they're
+    /// displayed in.
+    pub fn displayed_entry_levels(
+        &self,
+        query: &SearchQuery,
+        collapsed_sections: &BTreeSet<String>,
+        newest_first: bool,
+    ) -> Vec<(String, LogLevel)> {
+        match &self.parsed {
+            Ok(parsed) => parsed
+                .displayed_log_entries(query, collapsed_sections, newest_first)
+                .into_iter()
+                .map(|entry| (self.entry_id(entry), entry.level.unwrap_or_default()))
+                .collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    /// Sections of `tab` that have a title to jump to.
+    pub fn outline(&self, tab: Tab) -> Vec<OutlineEntry> {
+        match (&self.parsed, tab) {
+            (Ok(parsed), Tab::Information) => parsed
+                .information
+                .iter()
+                .flat_map(|section| section.outline(0))
+                .collect(),
+            // Only Signal Android logs show the titles of their sections.
+            (Ok(parsed), Tab::Logs) if self.remote_object.platform().is_android() => parsed
+                .logs
+                .iter()
+                .flat_map(|section| section.outline(0))
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    pub fn summary(&self) -> &FileSummary {
+        &self.summary
+    }
+
+    pub fn view(
+        &self,
+        tab: Tab,
+        query: &SearchQuery,
+        info_options: &InfoViewOptions,
+        log_options: &LogViewOptions,
+        raw_options: &RawViewOptions,
+        on_show_element: &Callback<(Tab, String)>,
+    ) -> Html {
+        let redact = log_options.redact;
+        let on_show_own = self.on_show_own(on_show_element);
+
         let title = match tab {
             Tab::Information => html! {
-                <Title level={TitleLevel::H1} text={format!("{} ({})", tab, self.remote_object.platform())}/>
+                <Title level={TitleLevel::H1} text={format!("{} ({})", tab.label(), self.remote_object.platform())}/>
             },
             Tab::Logs => html! {},
             Tab::Raw => html! {
-                <Title level={TitleLevel::H1} text={tab.to_string()}/>
+                <Title level={TitleLevel::H1} text={tab.label()}/>
             },
         };
 
         let content = match tab {
             Tab::Information => match &self.parsed {
-                Ok(parsed) => parsed.view_information(self.remote_object.platform()),
+                Ok(parsed) => html! {
+                    <>
+                        <DownloadButton
+                            classes={classes!("rounded-2xl")}
+                            size={ButtonSize::Medium}
+                            icon={classes!("fab", "fa-markdown")}
+                            text={tr(Text::ExportSummary)}
+                            content={self.summary_export(parsed, redact, &log_options.annotations)}
+                            filename={self.download_filename(Some("summary"), "md")}
+                            mime_type="text/markdown"
+                        />
+
+                        {
+                            parsed.view_information(
+                                self.remote_object.platform(),
+                                &self.analyses.info,
+                                info_options,
+                            )
+                        }
+                    </>
+                },
                 Err(error) => self.view_parsing_error(error),
             },
             Tab::Logs => match &self.parsed {
-                Ok(parsed) => RenderedLogSection {
-                    title: tab.to_string(),
-                    subsections: parsed.view_logs(query),
-                    ..Default::default()
-                }
-                .view(
-                    self.remote_object.platform().is_android(),
-                    self.remote_object.platform().is_android(),
-                    true,
-                ),
+                Ok(parsed) => html! {
+                    <>
+                        <DownloadButton
+                            classes={classes!("rounded-2xl")}
+                            size={ButtonSize::Medium}
+                            icon={classes!("fas", "fa-download")}
+                            text={tr(Text::DownloadFiltered)}
+                            content={self.filtered_text_export(parsed, query, redact)}
+                            filename={self.download_filename(Some("filtered"), "txt")}
+                        />
+
+                        { self.view_selection_buttons(parsed, log_options) }
+
+                        { self.view_log_section_controls(&log_options.on_set_sections_collapsed) }
+
+                        { view_column_toggles(parsed, log_options) }
+
+                        { self.view_bookmarks(parsed, log_options, on_show_element) }
+
+                        <div class="flex gap-2">
+                            <div class="grow min-w-0">
+                                {
+                                    RenderedLogSection {
+                                        title: tab.label().to_owned(),
+                                        subsections: parsed.view_logs(query, &LogViewOptions {
+                                            text: Rc::clone(&self.text),
+                                            version: self.analyses.log_summary.version.clone(),
+                                            id_prefix: self.id_prefix.clone(),
+                                            ..log_options.clone()
+                                        }),
+                                        ..Default::default()
+                                    }
+                                    .view(
+                                        self.remote_object.platform().is_android(),
+                                        self.remote_object.platform().is_android(),
+                                        true,
+                                    )
+                                }
+                            </div>
+
+                            {
+                                minimap::view_minimap(
+                                    &self.minimap_markers(parsed, query, log_options),
+                                    &on_show_own,
+                                )
+                            }
+                        </div>
+                    </>
+                },
                 Err(error) => self.view_parsing_error(error),
             },
             Tab::Raw => html! {
                 <>
-                    <DownloadButton
-                        classes={classes!("rounded-2xl")}
-                        size={ButtonSize::Medium}
-                        icon={classes!("fas", "fa-download")}
-                        text="Download"
-                        content={Rc::clone(&self.text)}
-                        filename={format!(
-                            "{}-{}{}.txt",
-                            self.remote_object.platform(),
-                            self.remote_object.key(),
-                            self.name.as_ref()
-                                .map(|name| format!(
-                                    "-{}-{}",
-                                    name.app_id,
-                                    name.file_time.format("%F-%H-%M-%S-%3f-%Z")
-                                ))
-                                .unwrap_or_else(|| "".to_owned())
-                        ).to_lowercase()}
-                    />
+                    <div class="flex">
+                        { self.view_raw_export_buttons(redact, &log_options.annotations) }
+                    </div>
 
-                    <CodeBlock text={Rc::clone(&self.text)}/>
+                    { self.view_raw_text(raw_options, redact) }
                 </>
             },
         };
 
+        let (summary, pinned) = match &self.parsed {
+            Ok(parsed) => (
+                html! {
+                    <>
+                        { self.analyses.log_summary.view() }
+                        {
+                            match self.summary.time_range {
+                                Some((first, last)) => summary::view_coverage_warnings(first, last),
+                                None => html! {},
+                            }
+                        }
+                        { diagnostics::view_diagnostics(&self.analyses.diagnostics, &on_show_own) }
+                        {
+                            notifications::view_delivery_stats(
+                                &self.analyses.delivery_stats,
+                                &on_show_own,
+                            )
+                        }
+                        { calls::view_calls(&self.analyses.calls, &on_show_own) }
+                    </>
+                },
+                parsed.view_pinned(info_options),
+            ),
+            Err(_) => (html! {}, html! {}),
+        };
+
         html! {
             <>
+                { summary }
                 { title }
+                { pinned }
+                { content }
+            </>
+        }
+    }
+
+    /// The bookmarked log entries (regardless of the query), which can be clicked to show them,
+    /// and a button to export them.
+    fn view_bookmarks(
+        &self,
+        parsed: &Content,
+        log_options: &LogViewOptions,
+        on_show_element: &Callback<(Tab, String)>,
+    ) -> Html {
+        let bookmarks = self.own_ids(&log_options.bookmarks);
+        let entries = parsed.log_entries_with_ids(&bookmarks);
+        if entries.is_empty() {
+            return html! {};
+        }
+
+        let view_bookmark = |entry: &&LogEntry| {
+            let id = self.entry_id(entry);
+            let on_show = {
+                let id = id.clone();
+                on_show_element.reform(move |_| (Tab::Logs, id.clone()))
+            };
+
+            html! {
+                <TableRow
+                    classes={classes!(entry.level.unwrap_or_default().color(), "cursor-pointer")}
+                    on_click={on_show}
+                    focusable={true}
+                >
+                    <TableItem>
+                        <Button
+                            size={ButtonSize::Small}
+                            icon={classes!("fas", "fa-star")}
+                            title={Some(tr(Text::RemoveBookmark).to_owned())}
+                            on_click={log_options.on_toggle_bookmark.reform(move |event: MouseEvent| {
+                                // Don't show the entry that is removed
+                                event.stop_propagation();
+                                id.clone()
+                            })}
+                        />
+                    </TableItem>
+                    <TableItem classes={classes!("whitespace-nowrap")}>{ &entry.original_timestamp }</TableItem>
+                    <TableItem>{ entry.level.as_ref().map(LogLevel::view).unwrap_or_default() }</TableItem>
+                    <TableItem>{ entry.message.lines().next().unwrap_or_default() }</TableItem>
+                </TableRow>
+            }
+        };
+
+        html! {
+            <Message classes={classes!("!max-w-none", "mb-4")} heading={i18n::bookmarks_heading(entries.len())}>
+                <Table classes={classes!("font-mono", "text-sm")}>
+                    <tbody>
+                        { for entries.iter().map(view_bookmark) }
+                    </tbody>
+                </Table>
+
+                <DownloadButton
+                    classes={classes!("rounded-2xl", "mt-2")}
+                    size={ButtonSize::Small}
+                    icon={classes!("fas", "fa-file-export")}
+                    text={tr(Text::ExportBookmarks)}
+                    content={Rc::new(redaction::redact_if(
+                        log_options.redact,
+                        export::bookmarks_text(parsed, &bookmarks),
+                    ))}
+                    filename={self.download_filename(Some("bookmarks"), "txt")}
+                />
+            </Message>
+        }
+    }
+
+    /// Everything about the log entry with the element ID: its metadata, its full message and its
+    /// original lines, which can be copied, a permalink to it, and traces of the messages it
+    /// mentions, whose entries can be shown with `on_show`.
+    pub fn view_entry_details(
+        &self,
+        id: &str,
+        redact: bool,
+        on_show: &Callback<(Tab, String)>,
+    ) -> Html {
+        let (parsed, entry) = match &self.parsed {
+            Ok(parsed) => match parsed
+                .log_entries()
+                .find(|entry| self.entry_id(entry) == id)
+            {
+                Some(entry) => (parsed, entry),
+                None => return html! { <p>{ tr(Text::EntryNotInFile) }</p> },
+            },
+            Err(_) => return html! { <p>{ tr(Text::EntryNotInFile) }</p> },
+        };
+
+        let view_field = |name: &str, value: Html| {
+            html! {
+                <TableRow>
+                    <TableItem tag="th" classes={classes!("text-left")}>{ name }</TableItem>
+                    <TableItem>{ value }</TableItem>
+                </TableRow>
+            }
+        };
+
+        let permalink = match permalink::current_page() {
+            Some(page) => html! {
+                <CopyButton
+                    classes={classes!("rounded-2xl")}
+                    text={Rc::new(permalink::link(&page, &self.remote_object.debuglogs_url(), id))}
+                    label={tr(Text::CopyPermalink)}
+                />
+            },
+            None => html! {},
+        };
+
+        let raw = self.text.get(entry.source.clone()).unwrap_or_default();
+
+        html! {
+            <>
+                <Table classes={classes!("font-mono", "text-sm")}>
+                    <tbody>
+                        { view_field(tr(Text::Timestamp), html! { &entry.original_timestamp }) }
+                        { view_field(tr(Text::Level), entry.level.as_ref().map(LogLevel::view).unwrap_or_default()) }
+                        {
+                            for entry.meta.fields().into_iter().map(|(name, value)| view_field(name, html! { value }))
+                        }
+                        {
+                            match &entry.origin {
+                                Some(origin) => view_field(tr(Text::Origin), html! { origin }),
+                                None => html! {},
+                            }
+                        }
+                        { view_field(tr(Text::Line), html! { line_number(&self.text, entry.source.start) }) }
+                    </tbody>
+                </Table>
+
+                <Title level={TitleLevel::H3} text={tr(Text::Message)} />
+                <pre class={classes!("whitespace-pre-wrap", "break-words")}>{ &entry.message }</pre>
+
+                <Title level={TitleLevel::H3} text={tr(Text::OriginalLines)} />
+                <CodeBlock text={Rc::new(raw.to_owned())} {redact} />
+
+                <div class={classes!("flex", "gap-2", "mb-4")}>
+                    <CopyButton
+                        classes={classes!("rounded-2xl")}
+                        text={Rc::new(entry.message.clone())}
+                        {redact}
+                        label={tr(Text::CopyMessage)}
+                    />
+                    { permalink }
+                </div>
+
+                {
+                    match MessageTrace::for_entry(parsed, entry) {
+                        traces if traces.is_empty() => html! {},
+                        traces => html! {
+                            <>
+                                <Title level={TitleLevel::H3} text={tr(Text::MessageTimeline)} />
+                                <MessageTimeline traces={Rc::new(traces)} on_show={self.on_show_own(on_show)} />
+                            </>
+                        },
+                    }
+                }
+            </>
+        }
+    }
+
+    /// Buttons to expand or collapse all log sections, if there are multiple.
+    fn view_log_section_controls(
+        &self,
+        on_set_sections_collapsed: &Callback<(Vec<String>, bool)>,
+    ) -> Html {
+        let names = self
+            .outline(Tab::Logs)
+            .into_iter()
+            .map(|entry| entry.name)
+            .collect::<Vec<_>>();
+
+        if names.len() < 2 {
+            return html! {};
+        }
+
+        let expand = {
+            let names = names.clone();
+            on_set_sections_collapsed.reform(move |_| (names.clone(), false))
+        };
+        let collapse = on_set_sections_collapsed.reform(move |_| (names.clone(), true));
+
+        html! {
+            <div class={classes!("flex", "gap-2", "my-4")}>
+                <Button
+                    classes={classes!("rounded-2xl")}
+                    size={ButtonSize::Small}
+                    icon={classes!("fas", "fa-angle-double-down")}
+                    text={tr(Text::ExpandAll)}
+                    on_click={expand}
+                />
+                <Button
+                    classes={classes!("rounded-2xl")}
+                    size={ButtonSize::Small}
+                    icon={classes!("fas", "fa-angle-double-up")}
+                    text={tr(Text::CollapseAll)}
+                    on_click={collapse}
+                />
+            </div>
+        }
+    }
+
+    /// Differences between the information of this file and the `other` one.
+    pub fn view_information_diff(&self, other: &File) -> Html {
+        let content = match (&self.parsed, &other.parsed) {
+            (Ok(parsed), Ok(other_parsed)) => InfoDiff::new(parsed, other_parsed).view(),
+            (Err(error), _) | (_, Err(error)) => self.view_parsing_error(error),
+        };
+
+        html! {
+            <>
+                <Title level={TitleLevel::H1} text={tr(Text::InformationDifferences)}/>
                 { content }
             </>
         }
     }
 
+    /// Entries that match the `query`, as one group of the results of searching all files;
+    /// `on_select` is called with the element ID of a clicked entry.
+    pub fn view_search_results(&self, query: &SearchQuery, on_select: Callback<String>) -> Html {
+        let title = match &self.name {
+            Some(name) => format!("{} {}", name.app_id, name.title()),
+            None => tr(Text::Combined).to_owned(),
+        };
+
+        let entries = match &self.parsed {
+            Ok(parsed) => parsed.filtered_log_entries(query).collect::<Vec<_>>(),
+            Err(_) => vec![],
+        };
+
+        let table = if entries.is_empty() {
+            html! {}
+        } else {
+            html! {
+                <Table>
+                    <tbody class="font-mono">
+                        {
+                            for entries.iter().map(|entry| {
+                                let id = self.entry_id(entry);
+
+                                html! {
+                                    <TableRow
+                                        classes={classes!(entry.level.unwrap_or_default().color(), "cursor-pointer")}
+                                        on_click={on_select.reform(move |_| id.clone())}
+                                        focusable={true}
+                                    >
+                                        <TableItem>{ &entry.original_timestamp }</TableItem>
+                                        <TableItem>{ entry.level.as_ref().map(LogLevel::view).unwrap_or_default() }</TableItem>
+                                        <TableItem><pre>{ &entry.message }</pre></TableItem>
+                                    </TableRow>
+                                }
+                            })
+                        }
+                    </tbody>
+                </Table>
+            }
+        };
+
+        html! {
+            <>
+                <Title
+                    level={TitleLevel::H3}
+                    text={title}
+                    meta={format!("{} matches", entries.len())}
+                />
+                { table }
+            </>
+        }
+    }
+
+    /// A button to open a prefilled issue with the summary and the original lines of the selected
+    /// log entries, as well as buttons to copy or download these lines.
+    fn view_selection_buttons(&self, parsed: &Content, log_options: &LogViewOptions) -> Html {
+        let selected_entries = &log_options.selected_entries;
+        let redact = log_options.redact;
+
+        let excerpt = if selected_entries.is_empty() {
+            String::new()
+        } else {
+            export::raw_excerpt(parsed, &self.text, &self.own_ids(selected_entries))
+        };
+
+        let issue_button = html! {
+            <Button
+                classes={classes!("rounded-2xl", "ml-2")}
+                size={ButtonSize::Medium}
+                icon={classes!("fab", "fa-github")}
+                text={tr(Text::OpenIssue)}
+                title={Some(tr(Text::OpenIssueTitle).to_owned())}
+                href={self.issue_url(parsed, log_options, &excerpt).to_string()}
+            />
+        };
+
+        if excerpt.is_empty() {
+            return issue_button;
+        }
+
+        let count = selected_entries.len();
+
+        html! {
+            <>
+                { issue_button }
+                <CopyButton
+                    classes={classes!("rounded-2xl", "ml-2")}
+                    text={Rc::new(excerpt.clone())}
+                    {redact}
+                    label={i18n::copy_selected_label(count)}
+                />
+                <DownloadButton
+                    classes={classes!("rounded-2xl", "ml-2")}
+                    size={ButtonSize::Small}
+                    icon={classes!("fas", "fa-file-export")}
+                    text={tr(Text::ExportSelected)}
+                    content={Rc::new(redaction::redact_if(redact, excerpt))}
+                    filename={self.download_filename(Some("selected"), "txt")}
+                />
+            </>
+        }
+    }
+
+    pub fn view_csv_export_button(&self, query: &SearchQuery, redact: bool) -> Html {
+        match &self.parsed {
+            Ok(parsed) => html! {
+                <DownloadButton
+                    icon={classes!("fas", "fa-file-csv")}
+                    content={self.csv_export(parsed, query, redact)}
+                    filename={self.download_filename(Some("filtered"), "csv")}
+                    mime_type="text/csv"
+                />
+            },
+            Err(_) => html! {},
+        }
+    }
+
+    fn summary_export(
+        &self,
+        parsed: &Content,
+        redact: bool,
+        annotations: &BTreeMap<String, String>,
+    ) -> Rc<String> {
+        cached_export(
+            &mut self.exports.borrow_mut().summary,
+            (redact, annotations.clone()),
+            || {
+                redaction::redact_if(
+                    redact,
+                    export::markdown_summary(
+                        &self.remote_object,
+                        parsed,
+                        &self.own_annotations(annotations),
+                    ),
+                )
+            },
+        )
+    }
+
+    fn filtered_text_export(
+        &self,
+        parsed: &Content,
+        query: &SearchQuery,
+        redact: bool,
+    ) -> Rc<String> {
+        cached_export(
+            &mut self.exports.borrow_mut().filtered_text,
+            (redact, query.clone()),
+            || redaction::redact_if(redact, export::filtered_logs_text(parsed, query)),
+        )
+    }
+
+    fn csv_export(&self, parsed: &Content, query: &SearchQuery, redact: bool) -> Rc<String> {
+        cached_export(
+            &mut self.exports.borrow_mut().csv,
+            (redact, query.clone()),
+            || redaction::redact_if(redact, export::csv(parsed, query)),
+        )
+    }
+
+    /// URL of a new issue prefilled with the summary and the `excerpt` of the selected entries.
+    fn issue_url(
+        &self,
+        parsed: &Content,
+        log_options: &LogViewOptions,
+        excerpt: &str,
+    ) -> Rc<String> {
+        let redact = log_options.redact;
+
+        cached_export(
+            &mut self.exports.borrow_mut().issue_url,
+            (
+                redact,
+                log_options.annotations.clone(),
+                log_options.selected_entries.clone(),
+            ),
+            || {
+                source_links::new_issue(
+                    self.remote_object.platform(),
+                    &redaction::redact_if(
+                        redact,
+                        export::issue_body(
+                            &self.remote_object,
+                            parsed,
+                            &self.own_annotations(&log_options.annotations),
+                            excerpt,
+                        ),
+                    ),
+                )
+            },
+        )
+    }
+
+    fn view_raw_export_buttons(
+        &self,
+        redact: bool,
+        annotations: &BTreeMap<String, String>,
+    ) -> Html {
+        let mut raw_tab = self.raw_tab.borrow_mut();
+
+        if !matches!(&raw_tab.exports, Some(exports) if exports.redact == redact && exports.annotations == *annotations)
+        {
+            raw_tab.exports = Some(RawExports {
+                redact,
+                annotations: annotations.clone(),
+                text: if redact {
+                    Rc::new(redaction::redact(&self.text))
+                } else {
+                    Rc::clone(&self.text)
+                },
+                json: match self
+                    .parsed
+                    .as_ref()
+                    .map(|parsed| export::json(parsed, &self.own_annotations(annotations)))
+                {
+                    Ok(Ok(json)) => Some(Rc::new(redaction::redact_if(redact, json))),
+                    _ => None,
+                },
+            });
+        }
+
+        let exports = raw_tab.exports.as_ref().unwrap();
+
+        let json_export_button = match &exports.json {
+            Some(json) => html! {
+                <DownloadButton
+                    size={ButtonSize::Medium}
+                    icon={classes!("fas", "fa-file-code")}
+                    text={tr(Text::ExportJson)}
+                    content={Rc::clone(json)}
+                    filename={self.download_filename(None, "json")}
+                    mime_type="application/json"
+                />
+            },
+            None => html! {},
+        };
+
+        html! {
+            <>
+                <DownloadButton
+                    size={ButtonSize::Medium}
+                    icon={classes!("fas", "fa-download")}
+                    text={tr(Text::Download)}
+                    content={Rc::clone(&exports.text)}
+                    filename={self.download_filename(None, "txt")}
+                />
+
+                { json_export_button }
+            </>
+        }
+    }
+
+    fn view_raw_text(&self, raw_options: &RawViewOptions, redact: bool) -> Html {
+        let matches = self.raw_matches(&raw_options.query);
+
+        let current_match = match matches.len() {
+            0 => None,
+            count => Some(raw_options.current_match.rem_euclid(count as isize) as usize),
+        };
+
+        let match_count = match current_match {
+            _ if raw_options.query.is_empty() => html! {},
+            Some(current) => html! {
+                <p>{ i18n::match_position(current + 1, matches.len()) }</p>
+            },
+            None => html! {
+                <p>{ i18n::no_matches(&raw_options.query) }</p>
+            },
+        };
+
+        html! {
+            <>
+                { match_count }
+
+                <VirtualCodeBlock
+                    text={Rc::clone(&self.text)}
+                    {redact}
+                    highlighted_line={raw_options.line}
+                    on_line_click={raw_options.on_show_line_in_logs.clone()}
+                    text_size={raw_options.text_size}
+                    {matches}
+                    {current_match}
+                />
+            </>
+        }
+    }
+
+    fn raw_matches(&self, query: &str) -> Rc<Vec<Range<usize>>> {
+        let mut raw_tab = self.raw_tab.borrow_mut();
+
+        match &raw_tab.search {
+            Some(search) if search.query == query => Rc::clone(&search.matches),
+            _ => {
+                let matches = Rc::new(find_ignore_ascii_case(&self.text, query));
+                raw_tab.search = Some(RawSearch {
+                    query: query.to_owned(),
+                    matches: Rc::clone(&matches),
+                });
+                matches
+            }
+        }
+    }
+
+    fn minimap_markers(
+        &self,
+        parsed: &Content,
+        query: &SearchQuery,
+        log_options: &LogViewOptions,
+    ) -> Rc<Vec<MinimapMarker>> {
+        let mut minimap = self.minimap.borrow_mut();
+
+        match &*minimap {
+            Some(cache)
+                if cache.query == *query
+                    && cache.collapsed_sections == log_options.collapsed_sections
+                    && cache.newest_first == log_options.newest_first =>
+            {
+                Rc::clone(&cache.markers)
+            }
+            _ => {
+                let markers = Rc::new(MinimapMarker::all(
+                    parsed,
+                    query,
+                    &log_options.collapsed_sections,
+                    log_options.newest_first,
+                ));
+                *minimap = Some(MinimapCache {
+                    query: query.clone(),
+                    collapsed_sections: log_options.collapsed_sections.clone(),
+                    newest_first: log_options.newest_first,
+                    markers: Rc::clone(&markers),
+                });
+                markers
+            }
+        }
+    }
+
+    /// Drops what was generated for the Raw tab; it's generated again once the tab is shown.
+    pub fn release_raw_tab(&self) {
+        self.raw_tab.take();
+    }
+
+    fn download_filename(&self, suffix: Option<&str>, extension: &str) -> String {
+        format!(
+            "{}-{}{}{}.{}",
+            self.remote_object.platform(),
+            self.remote_object.key(),
+            self.name
+                .as_ref()
+                .map(|name| format!(
+                    "-{}-{}",
+                    name.app_id,
+                    name.file_time.format("%F-%H-%M-%S-%3f-%Z")
+                ))
+                .unwrap_or_else(|| "".to_owned()),
+            suffix
+                .map(|suffix| format!("-{}", suffix))
+                .unwrap_or_else(|| "".to_owned()),
+            extension,
+        )
+        .to_lowercase()
+    }
+
     fn view_parsing_error(&self, error: &anyhow::Error) -> Html {
-        let notice = "You can still view the raw log by switching to the corresponding tab below"
-            .to_owned()
+        let notice = tr(Text::RawLogStillShown).to_owned()
             + match self.remote_object.platform() {
-                Platform::Android | Platform::Desktop => ".",
-                Platform::Ios => {
-                    " or check other files above to see if they were successfully parsed."
-                }
-            };
+                Platform::Android | Platform::Desktop => "",
+                Platform::Ios => tr(Text::CheckOtherFiles),
+            }
+            + ".";
 
         html! {
-            <Message error={true} heading="Error parsing file">
-                <CodeBlock text={Rc::new(format!("Error: {:?}", error))}/>
+            <Message error={true} heading={tr(Text::ErrorParsingFile)}>
+                <CodeBlock text={Rc::new(format!("{}: {:?}", tr(Text::Error), error))}/>
                 <span class="text-brand-text">{notice}</span>
             </Message>
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    #[test]
+    fn combined_ok() {
+        let remote_object = RemoteObject::new_unchecked(
+            Platform::Ios,
+            None,
+            "0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123",
+        );
+
+        let file = |name: &str, text: &str| {
+            let name = Rc::new(name.parse::<LogFilename>().unwrap());
+            let file = File::from_text(
+                remote_object.clone(),
+                Some(Rc::clone(&name)),
+                text.to_owned(),
+                String::new(),
+            );
+            (name, file)
+        };
+
+        let files = BTreeMap::from([
+            file(
+                "1234.01.23 12.34.56 ABCD1234-1AB2-3CDE-456F-789AB0CD1E2F/org.whispersystems.signal 1234-01-22--06-54-32-109.log",
+                "1234/01/23 12:34:56:100 💛 First\n1234/01/23 12:34:56:300 💛 Third",
+            ),
+            file(
+                "1234.01.23 12.34.56 ABCD1234-1AB2-3CDE-456F-789AB0CD1E2F/org.whispersystems.signal.SignalNSE 1234-01-22--06-54-32-109.log",
+                "1234/01/23 12:34:56:200 💛 Second",
+            ),
+        ]);
+
+        let combined = File::combined(remote_object, &files, String::new());
+        let entries = combined
+            .parsed
+            .as_ref()
+            .unwrap()
+            .log_entries()
+            .map(|entry| {
+                (
+                    entry.origin.clone().unwrap(),
+                    entry.message.as_str(),
+                    &combined.text[entry.source.clone()],
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            entries,
+            vec![
+                (AppId::Signal, "First", "1234/01/23 12:34:56:100 💛 First"),
+                (
+                    AppId::NotificationServiceExtension,
+                    "Second",
+                    "1234/01/23 12:34:56:200 💛 Second"
+                ),
+                (AppId::Signal, "Third", "1234/01/23 12:34:56:300 💛 Third"),
+            ]
+        );
+    }
+
+    #[test]
+    fn filtered_entries_json_ok() {
+        let remote_object = RemoteObject::new_unchecked(
+            Platform::Ios,
+            None,
+            "0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123",
+        );
+        let file = File::from_text(
+            remote_object,
+            None,
+            "1234/01/23 12:34:56:100 💛 First\n1234/01/23 12:34:56:200 💛 Second".to_owned(),
+            String::new(),
+        );
+        let query = SearchQuery {
+            min_log_level: LogLevel::Info,
+            string: "second".to_owned(),
+            ..Default::default()
+        };
+
+        let entries: serde_json::Value =
+            serde_json::from_str(&file.filtered_entries_json(&query)).unwrap();
+
+        assert_eq!(entries.as_array().unwrap().len(), 1);
+        assert_eq!(entries[0]["message"], "Second");
+    }
+
+    #[test]
+    fn entry_ids_ok() {
+        let remote_object = RemoteObject::new_unchecked(
+            Platform::Ios,
+            None,
+            "0123456789abcdefabcd0123456789abcdefabcd0123456789abcdefabcd0123",
+        );
+        let file = File::from_text(
+            remote_object,
+            None,
+            "1234/01/23 12:34:56:100 💛 First\n1234/01/23 12:34:56:200 💛 Second".to_owned(),
+            "comparison-file-1-".to_owned(),
+        );
+
+        let id = file.entry_id_by_number(2).unwrap();
+        assert_eq!(id, "comparison-file-1-entry-35");
+        assert_eq!(file.entry_level(&id), Some(LogLevel::Info));
+        assert_eq!(file.entry_level("entry-35"), None);
+        assert_eq!(
+            file.own_ids(&BTreeSet::from([id, "file-1-entry-35".to_owned()])),
+            BTreeSet::from(["entry-35".to_owned()])
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use derive_more::Display;
+
+use crate::parsers::{Content, InfoEntry, Section, Value};
+
+/// Words in a section name (or the name of one of its parents) that mark its entries as flags.
+const FLAG_SECTION_MARKERS: [&str; 3] = ["flag", "remote config", "remoteconfig"];
+
+#[derive(Debug, Display, Clone, Copy, PartialEq, Default)]
+pub enum FlagFilter {
+    #[default]
+    All,
+    Enabled,
+    Disabled,
+}
+
+/// A remote config or feature flag entry, from any information section.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeatureFlag {
+    /// Names of the section and its parents, joined with ` / `.
+    pub section: String,
+    pub key: String,
+    pub enabled: Option<bool>,
+    pub value: Option<Value>,
+}
+
+impl FeatureFlag {
+    /// All flags of `content`: entries in flag sections, bucketed flags and entries with an
+    /// explicit `enabled`/`disabled` state.
+    pub fn all(content: &Content) -> Vec<FeatureFlag> {
+        fn collect(
+            section: &Section<InfoEntry>,
+            parent: Option<&str>,
+            in_flag_section: bool,
+            output: &mut Vec<FeatureFlag>,
+        ) {
+            let name = match parent {
+                Some(parent) => format!("{} / {}", parent, section.name),
+                None => section.name.clone(),
+            };
+
+            let lowercase_name = section.name.to_lowercase();
+            let in_flag_section = in_flag_section
+                || FLAG_SECTION_MARKERS
+                    .iter()
+                    .any(|marker| lowercase_name.contains(marker));
+
+            for entry in &section.content {
+                let flag = match entry {
+                    InfoEntry::KeyEnabledValue(key, enabled, value) => FeatureFlag {
+                        section: name.clone(),
+                        key: key.clone(),
+                        enabled: Some(*enabled),
+                        value: value.clone(),
+                    },
+                    InfoEntry::KeyValue(key, value)
+                        if in_flag_section || matches!(value, Value::BucketedFlag(_)) =>
+                    {
+                        FeatureFlag {
+                            section: name.clone(),
+                            key: key.clone(),
+                            enabled: match value {
+                                Value::Generic(value) => value.parse().ok(),
+                                _ => None,
+                            },
+                            value: Some(value.clone()),
+                        }
+                    }
+                    _ => continue,
+                };
+
+                output.push(flag);
+            }
+
+            for subsection in &section.subsections {
+                collect(subsection, Some(&name), in_flag_section, output);
+            }
+        }
+
+        let mut output = vec![];
+
+        for section in &content.information {
+            collect(section, None, false, &mut output);
+        }
+
+        output
+    }
+
+    /// Whether the flag is off, i.e. disabled or without a meaningful value.
+    pub fn is_default(&self) -> bool {
+        match (self.enabled, &self.value) {
+            (Some(enabled), _) => !enabled,
+            (None, Some(Value::Generic(value))) => {
+                matches!(value.as_str(), "" | "0" | "null" | "false")
+            }
+            (None, Some(_)) => false,
+            (None, None) => true,
+        }
+    }
+
+    /// Whether the flag should be shown, given a (case-insensitive) `query` for its key or value.
+    pub fn matches(&self, query: &str, filter: FlagFilter, only_non_default: bool) -> bool {
+        let query = query.trim().to_lowercase();
+
+        let matches_filter = match filter {
+            FlagFilter::All => true,
+            FlagFilter::Enabled => self.enabled == Some(true),
+            FlagFilter::Disabled => self.enabled == Some(false),
+        };
+
+        let matches_query = query.is_empty()
+            || self.key.to_lowercase().contains(&query)
+            || matches!(&self.value, Some(Value::Generic(value)) if value.to_lowercase().contains(&query));
+
+        matches_filter && matches_query && (!only_non_default || !self.is_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    fn test_content() -> Content {
+        Content {
+            information: vec![
+                Section {
+                    name: "SYSINFO".to_owned(),
+                    content: vec![InfoEntry::KeyValue(
+                        "Model".to_owned(),
+                        Value::Generic("Pixel".to_owned()),
+                    )],
+                    subsections: vec![],
+                },
+                Section {
+                    name: "FEATURE FLAGS".to_owned(),
+                    content: vec![],
+                    subsections: vec![Section {
+                        name: "Memory Values".to_owned(),
+                        content: vec![
+                            InfoEntry::KeyValue(
+                                "android.a".to_owned(),
+                                Value::Generic("true".to_owned()),
+                            ),
+                            InfoEntry::KeyValue(
+                                "android.b".to_owned(),
+                                Value::Generic("false".to_owned()),
+                            ),
+                        ],
+                        subsections: vec![],
+                    }],
+                },
+                Section {
+                    name: "Remote Config".to_owned(),
+                    content: vec![InfoEntry::KeyEnabledValue(
+                        "desktop.c".to_owned(),
+                        true,
+                        Some(Value::Generic("123".to_owned())),
+                    )],
+                    subsections: vec![],
+                },
+            ],
+            logs: vec![],
+        }
+    }
+
+    #[test]
+    fn all_ok() {
+        assert_eq!(
+            FeatureFlag::all(&test_content())
+                .iter()
+                .map(|flag| (flag.section.as_str(), flag.key.as_str(), flag.enabled))
+                .collect::<Vec<_>>(),
+            vec![
+                ("FEATURE FLAGS / Memory Values", "android.a", Some(true)),
+                ("FEATURE FLAGS / Memory Values", "android.b", Some(false)),
+                ("Remote Config", "desktop.c", Some(true)),
+            ]
+        );
+    }
+
+    #[test_case("", FlagFilter::All, false => vec!["android.a", "android.b", "desktop.c"]; "no filter")]
+    #[test_case("ANDROID", FlagFilter::All, false => vec!["android.a", "android.b"]; "query")]
+    #[test_case("123", FlagFilter::All, false => vec!["desktop.c"]; "query matches value")]
+    #[test_case("", FlagFilter::Disabled, false => vec!["android.b"]; "disabled")]
+    #[test_case("", FlagFilter::All, true => vec!["android.a", "desktop.c"]; "only non-default")]
+    fn matches_ok(query: &str, filter: FlagFilter, only_non_default: bool) -> Vec<String> {
+        FeatureFlag::all(&test_content())
+            .into_iter()
+            .filter(|flag| flag.matches(query, filter, only_non_default))
+            .map(|flag| flag.key)
+            .collect()
+    }
+}
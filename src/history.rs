@@ -0,0 +1,126 @@
+use chrono::{Local, TimeZone};
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+use crate::Platform;
+
+const HISTORY_STORAGE_KEY: &str = "history";
+/// Number of debug logs that are remembered; the ones loaded longest ago are forgotten first.
+const MAX_HISTORY_ENTRIES: usize = 30;
+
+/// A debug log that was loaded before.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub url: String,
+    pub platform: Platform,
+    /// When the debug log was loaded last, in milliseconds since the Unix epoch.
+    pub loaded: i64,
+    /// Name given to the debug log, e.g. the issue it belongs to; empty if none was given.
+    pub label: String,
+}
+
+impl HistoryEntry {
+    /// When the debug log was loaded last, in the local time zone.
+    pub fn loaded_at(&self) -> String {
+        Local
+            .timestamp_millis(self.loaded)
+            .format("%Y-%m-%d %H:%M")
+            .to_string()
+    }
+}
+
+/// Debug logs that were loaded recently, the last one first; kept in `localStorage`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct History(Vec<HistoryEntry>);
+
+impl History {
+    pub fn load() -> Self {
+        LocalStorage::get(HISTORY_STORAGE_KEY).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        let _ = LocalStorage::set(HISTORY_STORAGE_KEY, self);
+    }
+
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.0
+    }
+
+    /// Moves the debug log to the top (keeping its label if it was loaded before), forgetting
+    /// the oldest one if there are too many.
+    pub fn add(&mut self, url: String, platform: Platform, loaded: i64) {
+        let label = match self.0.iter().position(|entry| entry.url == url) {
+            Some(index) => self.0.remove(index).label,
+            None => String::new(),
+        };
+
+        self.0.insert(
+            0,
+            HistoryEntry {
+                url,
+                platform,
+                loaded,
+                label,
+            },
+        );
+        self.0.truncate(MAX_HISTORY_ENTRIES);
+    }
+
+    pub fn remove(&mut self, url: &str) {
+        self.0.retain(|entry| entry.url != url);
+    }
+
+    pub fn set_label(&mut self, url: &str, label: String) {
+        if let Some(entry) = self.0.iter_mut().find(|entry| entry.url == url) {
+            entry.label = label;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(history: &History) -> Vec<&str> {
+        history
+            .entries()
+            .iter()
+            .map(|entry| entry.url.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn add_ok_moves_to_top() {
+        let mut history = History::default();
+        history.add("a".to_owned(), Platform::Android, 1);
+        history.add("b".to_owned(), Platform::Ios, 2);
+        history.set_label("a", "Crash on start".to_owned());
+        history.add("a".to_owned(), Platform::Android, 3);
+
+        assert_eq!(urls(&history), ["a", "b"]);
+        assert_eq!(history.entries()[0].label, "Crash on start");
+        assert_eq!(history.entries()[0].loaded, 3);
+    }
+
+    #[test]
+    fn add_ok_forgets_oldest() {
+        let mut history = History::default();
+        for i in 0..=MAX_HISTORY_ENTRIES {
+            history.add(i.to_string(), Platform::Desktop, i as i64);
+        }
+
+        assert_eq!(history.entries().len(), MAX_HISTORY_ENTRIES);
+        assert_eq!(history.entries().last().unwrap().url, "1");
+    }
+
+    #[test]
+    fn remove_ok() {
+        let mut history = History::default();
+        history.add("a".to_owned(), Platform::Android, 1);
+        history.add("b".to_owned(), Platform::Ios, 2);
+        history.remove("a");
+
+        assert_eq!(urls(&history), ["b"]);
+    }
+}
@@ -0,0 +1,917 @@
+//! Translations of the UI. Parsed logs, exports and anything copied stay as they are, so only the
+//! chrome around them is translated.
+
+use std::cell::Cell;
+
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+thread_local! {
+    static LANGUAGE: Cell<Language> = Cell::new(Language::default());
+}
+
+/// Language of the UI.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Default, EnumIter, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+}
+
+impl Language {
+    /// The language that texts are currently translated to, see [`tr`].
+    pub fn current() -> Self {
+        LANGUAGE.with(Cell::get)
+    }
+
+    /// Makes this the language that texts are translated to, also for the page itself.
+    pub fn select(self) {
+        LANGUAGE.with(|language| language.set(self));
+        crate::set_page_language(self.code());
+    }
+
+    /// BCP 47 code of the language.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::German => "de",
+        }
+    }
+
+    /// Name of the language in the language itself.
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::German => "Deutsch",
+        }
+    }
+}
+
+/// A text of the UI, in the current language.
+pub fn tr(text: Text) -> &'static str {
+    text.in_language(Language::current())
+}
+
+/// Placeholder of the log search input, for entries at `level` or above.
+pub fn search_logs_placeholder(level: &str) -> String {
+    match Language::current() {
+        Language::English => format!("Search {} logs...", level.to_lowercase()),
+        Language::German => format!("{}-Logs durchsuchen...", level),
+    }
+}
+
+/// Tooltip of a country by its calling code.
+pub fn calling_code(code: &str) -> String {
+    match Language::current() {
+        Language::English => format!("Calling code +{}", code),
+        Language::German => format!("Landesvorwahl +{}", code),
+    }
+}
+
+/// Title of the button that shows `count` entries before and after a search result.
+pub fn context_entries_title(count: usize) -> String {
+    match Language::current() {
+        Language::English => format!("Show {} entries before and after", count),
+        Language::German => format!("{} Einträge davor und danach anzeigen", count),
+    }
+}
+
+/// Heading of the list of `count` bookmarks.
+pub fn bookmarks_heading(count: usize) -> String {
+    match Language::current() {
+        Language::English => format!("Bookmarks ({})", count),
+        Language::German => format!("Lesezeichen ({})", count),
+    }
+}
+
+/// Number of log entries in a file.
+pub fn entries_count(count: usize) -> String {
+    match Language::current() {
+        Language::English => format!("{} entries", count),
+        Language::German => format!("{} Einträge", count),
+    }
+}
+
+/// Label of the button that copies the `count` selected log entries.
+pub fn copy_selected_label(count: usize) -> String {
+    match Language::current() {
+        Language::English => format!("Copy {} selected", count),
+        Language::German => format!("{} ausgewählte kopieren", count),
+    }
+}
+
+/// Which of the `total` matches of a search is shown, counting from 1.
+pub fn match_position(current: usize, total: usize) -> String {
+    match Language::current() {
+        Language::English => format!("Match {} of {}", current, total),
+        Language::German => format!("Treffer {} von {}", current, total),
+    }
+}
+
+/// Notice that nothing matches `query`.
+pub fn no_matches(query: &str) -> String {
+    match Language::current() {
+        Language::English => format!("No matches for \"{}\"", query),
+        Language::German => format!("Keine Treffer für „{}“", query),
+    }
+}
+
+/// Notice that only the first `count` lines of a text are shown.
+pub fn lines_shown(count: usize) -> String {
+    match Language::current() {
+        Language::English => format!("Only the first {} lines are currently shown above. ", count),
+        Language::German => format!(
+            "Oben werden derzeit nur die ersten {} Zeilen angezeigt. ",
+            count
+        ),
+    }
+}
+
+/// How many of the `count` dumped threads are blocked.
+pub fn thread_counts(count: usize, blocked: usize) -> String {
+    match Language::current() {
+        Language::English => format!("{} threads, {} blocked", count, blocked),
+        Language::German => format!("{} Threads, {} blockiert", count, blocked),
+    }
+}
+
+/// Label of a log level badge.
+pub fn log_level_label(level: &str) -> String {
+    match Language::current() {
+        Language::English => format!("Log level: {}", level),
+        Language::German => format!("Log-Level: {}", level),
+    }
+}
+
+/// Label of a minimap marker of a search match at `level`.
+pub fn level_search_match(level: &str) -> String {
+    match Language::current() {
+        Language::English => format!("{} (search match)", level),
+        Language::German => format!("{} (Suchtreffer)", level),
+    }
+}
+
+/// Warning about a log that ends `days` ago.
+pub fn stale_log_warning(days: i64) -> String {
+    match Language::current() {
+        Language::English => format!("This log ends {} days ago.", days),
+        Language::German => format!("Dieses Log endet vor {} Tagen.", days),
+    }
+}
+
+/// Warning about a log that covers only `minutes`, or `seconds` if it's less than a minute.
+pub fn short_log_warning(minutes: i64, seconds: i64) -> String {
+    match (Language::current(), minutes) {
+        (Language::English, 0) => format!("This log only covers {} seconds.", seconds),
+        (Language::English, 1) => "This log only covers 1 minute.".to_owned(),
+        (Language::English, _) => format!("This log only covers {} minutes.", minutes),
+        (Language::German, 0) => format!("Dieses Log umfasst nur {} Sekunden.", seconds),
+        (Language::German, 1) => "Dieses Log umfasst nur 1 Minute.".to_owned(),
+        (Language::German, _) => format!("Dieses Log umfasst nur {} Minuten.", minutes),
+    }
+}
+
+/// Start of the description of a call (e.g. [`Text::IncomingCall`]) that started at `timestamp`.
+pub fn call_at(call: &str, timestamp: &str) -> String {
+    match Language::current() {
+        Language::English => format!("{} at {}: ", call, timestamp),
+        Language::German => format!("{} um {}: ", call, timestamp),
+    }
+}
+
+/// Outcome of a call that failed `elapsed` after it started.
+pub fn call_failed(elapsed: &str, failure: &str) -> String {
+    match Language::current() {
+        Language::English => format!("failed after {}: {}", elapsed, failure),
+        Language::German => format!("nach {} fehlgeschlagen: {}", elapsed, failure),
+    }
+}
+
+/// Outcome of a call that was set up in `setup` and lasted `duration`, if it ended before the
+/// log did.
+pub fn call_connected(setup: &str, duration: Option<&str>) -> String {
+    match (Language::current(), duration) {
+        (Language::English, Some(duration)) => {
+            format!("set up in {}, lasted {}", setup, duration)
+        }
+        (Language::English, None) => {
+            format!("set up in {}, still going at the end of the log", setup)
+        }
+        (Language::German, Some(duration)) => {
+            format!("in {} aufgebaut, dauerte {}", setup, duration)
+        }
+        (Language::German, None) => {
+            format!("in {} aufgebaut, lief am Ende des Logs noch", setup)
+        }
+    }
+}
+
+/// Outcome of a call that ended `elapsed` after it started, without connecting.
+pub fn call_ended_unconnected(elapsed: &str) -> String {
+    match Language::current() {
+        Language::English => format!("ended without connecting after {}", elapsed),
+        Language::German => format!("nach {} ohne Verbindung beendet", elapsed),
+    }
+}
+
+/// Heading of the breakdown of `count` calls.
+pub fn calls_heading(failed: usize, count: usize) -> String {
+    match Language::current() {
+        Language::English => format!("Calls ({} of {} failed)", failed, count),
+        Language::German => format!("Anrufe ({} von {} fehlgeschlagen)", failed, count),
+    }
+}
+
+/// How many pushes of a kind were received, and after how many a message was processed.
+pub fn push_counts(received: usize, processed: usize) -> String {
+    match Language::current() {
+        Language::English => format!("{} received, {} processed", received, processed),
+        Language::German => format!("{} empfangen, {} verarbeitet", received, processed),
+    }
+}
+
+/// Median and longest delay of the pushes of a kind.
+pub fn push_delays(median: &str, max: &str) -> String {
+    match Language::current() {
+        Language::English => format!(", median delay {}, max {}", median, max),
+        Language::German => format!(", Median der Verzögerung {}, maximal {}", median, max),
+    }
+}
+
+/// How many of the `count` remote config changes match the search.
+pub fn changes_count(visible: usize, count: usize) -> String {
+    match Language::current() {
+        Language::English => format!("{} of {} changes", visible, count),
+        Language::German => format!("{} von {} Änderungen", visible, count),
+    }
+}
+
+/// Label of the button that shows the trace of the message with `id`.
+pub fn trace_label(id: &str) -> String {
+    match Language::current() {
+        Language::English => format!("Trace {}", id),
+        Language::German => format!("{} verfolgen", id),
+    }
+}
+
+/// Texts of the UI that are translated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
+pub enum Text {
+    Information,
+    Logs,
+    Raw,
+    Read,
+    Compare,
+    StopComparing,
+    EnterDebugLog,
+    EnterAnotherDebugLog,
+    DebugLog,
+    UrlAndPress,
+    Or,
+    Progress,
+    FetchingAndParsing,
+    Error,
+    LogEntry,
+    SearchResults,
+    GoToTop,
+    GoToBottom,
+    HighlightIdentifiers,
+    Settings,
+    KeyboardShortcuts,
+    Print,
+    RememberNotes,
+    Links,
+    Wrap,
+    Redact,
+    Redacting,
+    CollapseUi,
+    ExpandUi,
+    JumpToSection,
+    Main,
+    Comparison,
+    AllFiles,
+    NewestFirst,
+    SearchRawText,
+    GoToLine,
+    GoToEntry,
+    NoSuchEntry,
+    FilterInformation,
+    On,
+    Off,
+    Language,
+    Theme,
+    ThemeSystem,
+    ThemeLight,
+    ThemeDark,
+    ColorBlindLevels,
+    CompactRows,
+    TextSize,
+    TextSizeSmall,
+    TextSizeMedium,
+    TextSizeLarge,
+    WrapLongMessages,
+    SearchResultContext,
+    RedactByDefault,
+    SettingsRemembered,
+    ContextLines,
+    Context,
+    FirstError,
+    JumpToFirstError,
+    PreviousWarning,
+    NextWarning,
+    AllSections,
+    Tag,
+    Thread,
+    AllProcesses,
+    Unofficial,
+    DisplayOptions,
+    CopiedToClipboard,
+    CopyFailed,
+    Downloaded,
+    Dismiss,
+    PreviousLogKept,
+    FetchNetworkError,
+    FetchExpired,
+    FetchForbidden,
+    FetchServerError,
+    FetchUnexpectedStatus,
+    FetchCorrupt,
+    TryAgain,
+    FetchRetried,
+    Retry,
+    Cancel,
+    FetchCanceled,
+    SizeLimit,
+    NoLimit,
+    LargeDebugLog,
+    LargeDebugLogWarning,
+    LoadEverything,
+    LoadLast,
+    ExtractingFiles,
+    SkippedArchiveEntries,
+    AllowedHosts,
+    AllowedHostsPlaceholder,
+    CorsProxy,
+    ChoosePlatform,
+    StartOnPaste,
+    RecognizedDebugLog,
+    RecognizedDirectUrl,
+    UrlNotRecognized,
+    FetchingThroughProxy,
+    RecentlyOpened,
+    Fetched,
+    FromCache,
+    OfText,
+    ParsedIn,
+    LogEntriesCount,
+    LoadedFromCache,
+    CachedDebugLogs,
+    DebugLogsCount,
+    ClearCache,
+    OpenDebugLogs,
+    NewTab,
+    CloseTab,
+    Label,
+    Open,
+    Remove,
+    Tabs,
+    ShortcutFocusSearch,
+    ShortcutSelectEntry,
+    ShortcutSelectWarning,
+    ShortcutSelectFirstError,
+    ShortcutShowTab,
+    ShortcutSwitchFile,
+    ShortcutGoToEdge,
+    ShortcutToggleList,
+    ShortcutClose,
+    Pinned,
+    FeatureFlags,
+    Threads,
+    ExpandAll,
+    CollapseAll,
+    Expand,
+    Collapse,
+    NoMatchingInformation,
+    NoContentOrSubsections,
+    NoInformationSectionsIos,
+    NoInformationSections,
+    AllOtherCountries,
+    Bookmark,
+    RemoveBookmark,
+    Note,
+    NotePlaceholder,
+    EntryDetails,
+    Columns,
+    ExportSummary,
+    DownloadFiltered,
+    ExportBookmarks,
+    EntryNotInFile,
+    CopyPermalink,
+    Timestamp,
+    Level,
+    Origin,
+    Line,
+    Message,
+    OriginalLines,
+    Copy,
+    CopyMessage,
+    OpenIssue,
+    OpenIssueTitle,
+    ExportSelected,
+    Download,
+    ExportJson,
+    ErrorParsingFile,
+    RawLogStillShown,
+    CheckOtherFiles,
+    Close,
+    ShowAll,
+    MayTakeAWhile,
+    SearchFlags,
+    OnlyNonDefault,
+    Section,
+    Key,
+    Flag,
+    State,
+    Value,
+    OnlyBlocked,
+    Blocked,
+    Combined,
+    AllFilesChronologically,
+    IncludeInSelection,
+    DragToResize,
+    GoToFirstLine,
+    GoToLastLine,
+    Diagnostics,
+    MainThreadBlocked,
+    ShowEntry,
+    ShowThreads,
+    NoDifferences,
+    InformationDifferences,
+    Version,
+    Device,
+    OperatingSystem,
+    Locale,
+    Captured,
+    SearchMatch,
+    IncomingCall,
+    OutgoingCall,
+    CallNotConnected,
+    ShowSlowest,
+    NotificationDelivery,
+    RemoteConfigChanges,
+    Store,
+    From,
+    To,
+    FinalValue,
+    NoValue,
+    DiffersFromFinalValue,
+    MessageTimeline,
+    Show,
+    Elapsed,
+    Stage,
+    StageSend,
+    StageEncrypt,
+    StageUpload,
+    StageReceipt,
+    StageReceive,
+    StageDecrypt,
+    NotParsed,
+    EmptyArchive,
+}
+
+impl Text {
+    pub fn in_language(self, language: Language) -> &'static str {
+        let (english, german) = match self {
+            Text::Information => ("Information", "Informationen"),
+            Text::Logs => ("Logs", "Logs"),
+            Text::Raw => ("Raw", "Rohdaten"),
+            Text::Read => ("Read", "Lesen"),
+            Text::Compare => ("Compare", "Vergleichen"),
+            Text::StopComparing => ("Stop comparing", "Vergleich beenden"),
+            Text::EnterDebugLog => ("Please enter a Signal ", "Bitte gib die URL eines Signal-"),
+            Text::EnterAnotherDebugLog => (
+                "Please enter another Signal ",
+                "Bitte gib die URL eines weiteren Signal-",
+            ),
+            Text::DebugLog => ("debug log", "Debug-Logs"),
+            Text::UrlAndPress => (" URL and press ", " ein und drücke "),
+            Text::Or => (" or ", " oder "),
+            Text::Progress => ("Progress", "Fortschritt"),
+            Text::FetchingAndParsing => {
+                ("Fetching and parsing...", "Wird geladen und verarbeitet...")
+            }
+            Text::Error => ("Error", "Fehler"),
+            Text::LogEntry => ("Log entry", "Logeintrag"),
+            Text::SearchResults => ("Search results", "Suchergebnisse"),
+            Text::GoToTop => ("Go to the top (Home)", "Zum Anfang (Pos1)"),
+            Text::GoToBottom => ("Go to the bottom (End)", "Zum Ende (Ende)"),
+            Text::HighlightIdentifiers => ("Highlight identifiers", "Kennungen hervorheben"),
+            Text::Settings => ("Settings", "Einstellungen"),
+            Text::KeyboardShortcuts => ("Keyboard shortcuts", "Tastenkürzel"),
+            Text::Print => ("Print", "Drucken"),
+            Text::RememberNotes => ("Remember notes", "Notizen merken"),
+            Text::Links => ("Links", "Links"),
+            Text::Wrap => ("Wrap", "Umbrechen"),
+            Text::Redact => ("Redact", "Schwärzen"),
+            Text::Redacting => ("Redacting", "Wird geschwärzt"),
+            Text::CollapseUi => ("Collapse UI", "Oberfläche verkleinern"),
+            Text::ExpandUi => ("Expand UI", "Oberfläche vergrößern"),
+            Text::JumpToSection => ("Jump to section...", "Zu Abschnitt springen..."),
+            Text::Main => ("Main", "Haupt-Log"),
+            Text::Comparison => ("Comparison", "Vergleich"),
+            Text::AllFiles => ("All files", "Alle Dateien"),
+            Text::NewestFirst => ("Newest first", "Neueste zuerst"),
+            Text::SearchRawText => ("Search raw text...", "Rohdaten durchsuchen..."),
+            Text::GoToLine => ("Go to line...", "Gehe zu Zeile..."),
+            Text::GoToEntry => ("Go to entry #...", "Gehe zu Eintrag #..."),
+            Text::NoSuchEntry => ("There is no log entry with this number", "Es gibt keinen Log-Eintrag mit dieser Nummer"),
+            Text::FilterInformation => (
+                "Filter information by key or value...",
+                "Informationen nach Schlüssel oder Wert filtern...",
+            ),
+            Text::On => ("On", "An"),
+            Text::Off => ("Off", "Aus"),
+            Text::Language => ("Language", "Sprache"),
+            Text::Theme => ("Theme", "Design"),
+            Text::ThemeSystem => ("System", "System"),
+            Text::ThemeLight => ("Light", "Hell"),
+            Text::ThemeDark => ("Dark", "Dunkel"),
+            Text::ColorBlindLevels => (
+                "Color-blind-friendly levels",
+                "Farbenblind-freundliche Level",
+            ),
+            Text::CompactRows => ("Compact rows", "Kompakte Zeilen"),
+            Text::TextSize => ("Text size", "Textgröße"),
+            Text::TextSizeSmall => ("Small", "Klein"),
+            Text::TextSizeMedium => ("Medium", "Mittel"),
+            Text::TextSizeLarge => ("Large", "Groß"),
+            Text::WrapLongMessages => ("Wrap long messages", "Lange Nachrichten umbrechen"),
+            Text::SearchResultContext => ("Search result context", "Kontext von Suchergebnissen"),
+            Text::RedactByDefault => ("Redact by default", "Standardmäßig schwärzen"),
+            Text::SettingsRemembered => (
+                "Settings are remembered in this browser. Hidden columns of the logs table are \
+                 chosen on the Logs tab.",
+                "Einstellungen werden in diesem Browser gespeichert. Ausgeblendete Spalten der \
+                 Log-Tabelle werden im Logs-Tab gewählt.",
+            ),
+            Text::ContextLines => (
+                "Entries shown before and after every search result",
+                "Einträge, die vor und nach jedem Suchergebnis angezeigt werden",
+            ),
+            Text::Context => ("Context", "Kontext"),
+            Text::FirstError => ("First error", "Erster Fehler"),
+            Text::JumpToFirstError => (
+                "Jump to the first error (F)",
+                "Zum ersten Fehler springen (F)",
+            ),
+            Text::PreviousWarning => (
+                "Previous warning or error (Shift+E)",
+                "Vorherige Warnung oder vorheriger Fehler (Umschalt+E)",
+            ),
+            Text::NextWarning => (
+                "Next warning or error (E)",
+                "Nächste Warnung oder nächster Fehler (E)",
+            ),
+            Text::AllSections => ("All sections", "Alle Abschnitte"),
+            Text::Tag => ("Tag", "Tag"),
+            Text::Thread => ("Thread", "Thread"),
+            Text::AllProcesses => ("All processes", "Alle Prozesse"),
+            Text::DisplayOptions => ("Display options", "Anzeigeoptionen"),
+            Text::CopiedToClipboard => ("Copied to the clipboard", "In die Zwischenablage kopiert"),
+            Text::CopyFailed => (
+                "Couldn't copy to the clipboard",
+                "Kopieren in die Zwischenablage fehlgeschlagen",
+            ),
+            Text::Downloaded => ("Downloaded", "Heruntergeladen:"),
+            Text::Dismiss => ("Dismiss", "Schließen"),
+            Text::FetchNetworkError => (
+                "The debug log couldn't be reached. Check your internet connection and try again; if that doesn't help, a browser extension or network filter may be blocking the request.",
+                "Das Debug-Log war nicht erreichbar. Prüfe deine Internetverbindung und versuche es erneut; falls das nicht hilft, blockiert eventuell eine Browser-Erweiterung oder ein Netzwerkfilter die Anfrage.",
+            ),
+            Text::FetchExpired => (
+                "This debug log has expired or never existed. Debug logs are only kept for a limited time, so ask for a new one to be submitted.",
+                "Dieses Debug-Log ist abgelaufen oder hat nie existiert. Debug-Logs werden nur für begrenzte Zeit aufbewahrt, bitte daher darum, ein neues einzureichen.",
+            ),
+            Text::FetchForbidden => (
+                "Access to the debug log was denied. This usually comes from a proxy, VPN or corporate network in between, or from a browser extension interfering with cross-origin (CORS) requests; try another network or disable such extensions.",
+                "Der Zugriff auf das Debug-Log wurde verweigert. Meist liegt das an einem Proxy, VPN oder Firmennetzwerk dazwischen, oder an einer Browser-Erweiterung, die Cross-Origin-Anfragen (CORS) stört; versuche ein anderes Netzwerk oder deaktiviere solche Erweiterungen.",
+            ),
+            Text::FetchServerError => (
+                "The server storing debug logs had a problem. This is usually temporary, so try again in a moment.",
+                "Der Server, der die Debug-Logs speichert, hatte ein Problem. Das ist meist vorübergehend, versuche es also gleich noch einmal.",
+            ),
+            Text::FetchUnexpectedStatus => (
+                "The server responded unexpectedly. Check that the URL is a complete debug log URL.",
+                "Der Server hat unerwartet geantwortet. Prüfe, ob die URL eine vollständige Debug-Log-URL ist.",
+            ),
+            Text::FetchCorrupt => (
+                "The debug log was received, but it is corrupt or incomplete and couldn't be decompressed. Try again; if that doesn't help, the debug log may have been damaged when it was uploaded.",
+                "Das Debug-Log wurde empfangen, ist aber beschädigt oder unvollständig und konnte nicht entpackt werden. Versuche es erneut; falls das nicht hilft, wurde das Debug-Log eventuell beim Hochladen beschädigt.",
+            ),
+            Text::TryAgain => ("Try again", "Erneut versuchen"),
+            Text::FetchRetried => (
+                "Fetching the debug log failed, trying again",
+                "Laden des Debug-Logs fehlgeschlagen, neuer Versuch",
+            ),
+            Text::Retry => ("retry", "Wiederholung"),
+            Text::Cancel => ("Cancel", "Abbrechen"),
+            Text::FetchCanceled => ("Fetching was canceled.", "Das Laden wurde abgebrochen."),
+            Text::SizeLimit => ("Ask before loading logs over", "Nachfragen bei Logs über"),
+            Text::NoLimit => ("Never", "Nie"),
+            Text::LargeDebugLog => ("Large debug log", "Großes Debug-Log"),
+            Text::LargeDebugLogWarning => (
+                "This debug log is larger than the limit in the Settings. Loading all of it may make the page unresponsive for a while; loading only the end of every file is faster, but earlier entries will be missing. Size:",
+                "Dieses Debug-Log ist größer als die Grenze in den Einstellungen. Alles zu laden kann die Seite eine Weile blockieren; nur das Ende jeder Datei zu laden geht schneller, aber frühere Einträge fehlen dann. Größe:",
+            ),
+            Text::LoadEverything => ("Load everything", "Alles laden"),
+            Text::LoadLast => ("Load the last", "Lade die letzten"),
+            Text::ExtractingFiles => ("Extracted files:", "Entpackte Dateien:"),
+            Text::AllowedHosts => ("Also load logs from", "Logs auch laden von"),
+            Text::AllowedHostsPlaceholder => (
+                "Hosts, e.g. logs.example.com, localhost:8080",
+                "Hosts, z. B. logs.example.com, localhost:8080",
+            ),
+            Text::RecognizedDebugLog => ("Debug log of Signal", "Debug-Log von Signal"),
+            Text::RecognizedDirectUrl => (
+                "Debug log from an allowed host; its platform is detected once it's fetched.",
+                "Debug-Log von einem erlaubten Host; die Plattform wird nach dem Laden erkannt.",
+            ),
+            Text::UrlNotRecognized => ("Can't read this URL:", "Diese URL kann nicht gelesen werden:"),
+            Text::RecentlyOpened => ("Recently opened", "Zuletzt geöffnet"),
+            Text::Fetched => ("Fetched", "Geladen:"),
+            Text::FromCache => ("From the cache", "Aus dem Cache"),
+            Text::OfText => ("of text", "Text"),
+            Text::ParsedIn => ("Parsed in", "Verarbeitet in"),
+            Text::LogEntriesCount => ("log entries", "Log-Einträge"),
+            Text::LoadedFromCache => (
+                "Loaded the debug log from the cache, as it was fetched before.",
+                "Debug-Log aus dem Cache geladen, da es schon einmal geladen wurde.",
+            ),
+            Text::CachedDebugLogs => ("Cached debug logs", "Zwischengespeicherte Debug-Logs"),
+            Text::DebugLogsCount => ("debug logs", "Debug-Logs"),
+            Text::ClearCache => ("Clear", "Leeren"),
+            Text::OpenDebugLogs => ("Open debug logs", "Geöffnete Debug-Logs"),
+            Text::NewTab => ("New tab", "Neuer Tab"),
+            Text::CloseTab => ("Close tab", "Tab schließen"),
+            Text::Label => ("Label", "Bezeichnung"),
+            Text::Open => ("Open", "Öffnen"),
+            Text::Remove => ("Remove", "Entfernen"),
+            Text::StartOnPaste => (
+                "Read pasted URLs right away",
+                "Eingefügte URLs sofort lesen",
+            ),
+            Text::ChoosePlatform => ("Platform…", "Plattform…"),
+            Text::CorsProxy => (
+                "CORS proxy if fetching fails",
+                "CORS-Proxy, falls das Laden fehlschlägt",
+            ),
+            Text::FetchingThroughProxy => (
+                "Fetching directly failed, fetching through the CORS proxy:",
+                "Direktes Laden fehlgeschlagen, lade über den CORS-Proxy:",
+            ),
+            Text::SkippedArchiveEntries => (
+                "Skipped what isn't a log file or couldn't be extracted:",
+                "Übersprungen, da keine Log-Datei oder nicht entpackbar:",
+            ),
+            Text::PreviousLogKept => (
+                "The debug log that was loaded before is still shown below.",
+                "Das zuvor geladene Debug-Log wird weiterhin unten angezeigt.",
+            ),
+            Text::Tabs => ("Tabs", "Tabs"),
+            Text::Unofficial => (
+                " is an unofficial project. It is not affiliated with the Signal Technology \
+                 Foundation or Signal Messenger, LLC.",
+                " ist ein inoffizielles Projekt. Es steht in keiner Verbindung zur Signal \
+                 Technology Foundation oder Signal Messenger, LLC.",
+            ),
+            Text::ShortcutFocusSearch => (
+                "Focus the search or filter input",
+                "Such- oder Filterfeld auswählen",
+            ),
+            Text::ShortcutSelectEntry => (
+                "Select the next / previous log entry",
+                "Nächsten / vorherigen Logeintrag auswählen",
+            ),
+            Text::ShortcutSelectWarning => (
+                "Select the next / previous warning or error",
+                "Nächste / vorherige Warnung oder Fehler auswählen",
+            ),
+            Text::ShortcutSelectFirstError => ("Select the first error", "Ersten Fehler auswählen"),
+            Text::ShortcutShowTab => (
+                "Show the Information / Logs / Raw tab",
+                "Tab Informationen / Logs / Rohdaten anzeigen",
+            ),
+            Text::ShortcutSwitchFile => (
+                "Show the previous / next file of Signal iOS debug logs",
+                "Vorherige / nächste Datei von Signal-iOS-Debug-Logs anzeigen",
+            ),
+            Text::ShortcutGoToEdge => (
+                "Go to the start / end of the logs or the raw text",
+                "Zum Anfang / Ende der Logs oder der Rohdaten gehen",
+            ),
+            Text::ShortcutToggleList => {
+                ("Show or hide this list", "Diese Liste ein- oder ausblenden")
+            }
+            Text::ShortcutClose => (
+                "Hide this list, the settings or the details of a log entry",
+                "Diese Liste, die Einstellungen oder die Details eines Logeintrags ausblenden",
+            ),
+            Text::Pinned => ("Pinned", "Angeheftet"),
+            Text::FeatureFlags => ("Feature flags", "Feature-Flags"),
+            Text::Threads => ("Threads", "Threads"),
+            Text::ExpandAll => ("Expand all", "Alle ausklappen"),
+            Text::CollapseAll => ("Collapse all", "Alle einklappen"),
+            Text::Expand => ("Expand", "Ausklappen"),
+            Text::Collapse => ("Collapse", "Einklappen"),
+            Text::NoMatchingInformation => (
+                "No information entries match the filter.",
+                "Keine Informationen passen zum Filter.",
+            ),
+            Text::NoContentOrSubsections => (
+                "No content or subsections",
+                "Kein Inhalt und keine Unterabschnitte",
+            ),
+            Text::NoInformationSectionsIos => (
+                "Signal iOS debug logs don't contain any dedicated information sections.",
+                "Debug-Logs von Signal iOS enthalten keine eigenen Informationsabschnitte.",
+            ),
+            Text::NoInformationSections => (
+                "This file doesn't seem to contain any dedicated information sections.",
+                "Diese Datei scheint keine eigenen Informationsabschnitte zu enthalten.",
+            ),
+            Text::AllOtherCountries => (
+                "* applies to all countries not listed separately",
+                "* gilt für alle nicht einzeln aufgeführten Länder",
+            ),
+            Text::Bookmark => ("Bookmark", "Lesezeichen setzen"),
+            Text::RemoveBookmark => ("Remove bookmark", "Lesezeichen entfernen"),
+            Text::Note => ("Note", "Notiz"),
+            Text::NotePlaceholder => (
+                "Note (Enter to finish)...",
+                "Notiz (Eingabetaste zum Beenden)...",
+            ),
+            Text::EntryDetails => (
+                "Details (or double-click the entry)",
+                "Details (oder Doppelklick auf den Eintrag)",
+            ),
+            Text::Columns => ("Columns:", "Spalten:"),
+            Text::ExportSummary => ("Export summary", "Zusammenfassung exportieren"),
+            Text::DownloadFiltered => ("Download filtered", "Gefilterte herunterladen"),
+            Text::ExportBookmarks => ("Export bookmarks", "Lesezeichen exportieren"),
+            Text::EntryNotInFile => (
+                "This log entry isn't in the shown file.",
+                "Dieser Logeintrag ist nicht in der angezeigten Datei.",
+            ),
+            Text::CopyPermalink => ("Copy permalink", "Permalink kopieren"),
+            Text::Timestamp => ("Timestamp", "Zeitstempel"),
+            Text::Level => ("Level", "Level"),
+            Text::Origin => ("Origin", "Herkunft"),
+            Text::Line => ("Line", "Zeile"),
+            Text::Message => ("Message", "Nachricht"),
+            Text::OriginalLines => ("Original lines", "Ursprüngliche Zeilen"),
+            Text::Copy => ("Copy", "Kopieren"),
+            Text::CopyMessage => ("Copy message", "Nachricht kopieren"),
+            Text::OpenIssue => ("Open issue", "Issue eröffnen"),
+            Text::OpenIssueTitle => (
+                "Open a GitHub issue prefilled with the summary and the selected log entries",
+                "Ein GitHub-Issue mit der Zusammenfassung und den ausgewählten Logeinträgen eröffnen",
+            ),
+            Text::ExportSelected => ("Export selected", "Ausgewählte exportieren"),
+            Text::Download => ("Download", "Herunterladen"),
+            Text::ExportJson => ("Export JSON", "Als JSON exportieren"),
+            Text::ErrorParsingFile => (
+                "Error parsing file",
+                "Fehler beim Verarbeiten der Datei",
+            ),
+            Text::RawLogStillShown => (
+                "You can still view the raw log by switching to the corresponding tab below",
+                "Du kannst dir trotzdem die Rohdaten ansehen, indem du unten zum entsprechenden Tab wechselst",
+            ),
+            Text::CheckOtherFiles => (
+                " or check other files above to see if they were successfully parsed",
+                " oder oben nachsehen, ob andere Dateien verarbeitet werden konnten",
+            ),
+            Text::Close => ("Close", "Schließen"),
+            Text::ShowAll => ("Show all", "Alle anzeigen"),
+            Text::MayTakeAWhile => (" (may take a little while)", " (kann etwas dauern)"),
+            Text::SearchFlags => ("Search flags...", "Flags durchsuchen..."),
+            Text::OnlyNonDefault => ("Only non-default", "Nur geänderte"),
+            Text::Section => ("Section", "Abschnitt"),
+            Text::Key => ("Key", "Schlüssel"),
+            Text::Flag => ("Flag", "Flag"),
+            Text::State => ("State", "Zustand"),
+            Text::Value => ("Value", "Wert"),
+            Text::OnlyBlocked => ("Only blocked", "Nur blockierte"),
+            Text::Blocked => ("BLOCKED", "BLOCKIERT"),
+            Text::Combined => ("Combined", "Kombiniert"),
+            Text::AllFilesChronologically => (
+                "All files, chronologically",
+                "Alle Dateien, chronologisch",
+            ),
+            Text::IncludeInSelection => ("Include in selection", "In die Auswahl aufnehmen"),
+            Text::DragToResize => (
+                "Drag to resize, double-click to reset",
+                "Ziehen zum Ändern der Größe, Doppelklick zum Zurücksetzen",
+            ),
+            Text::GoToFirstLine => ("Go to the first line (Home)", "Zur ersten Zeile (Pos1)"),
+            Text::GoToLastLine => ("Go to the last line (End)", "Zur letzten Zeile (Ende)"),
+            Text::Diagnostics => ("Diagnostics", "Diagnose"),
+            Text::MainThreadBlocked => ("Main thread blocked", "Haupt-Thread blockiert"),
+            Text::ShowEntry => ("Show entry", "Eintrag anzeigen"),
+            Text::ShowThreads => ("Show threads", "Threads anzeigen"),
+            Text::NoDifferences => ("No differences.", "Keine Unterschiede."),
+            Text::InformationDifferences => (
+                "Information (differences)",
+                "Informationen (Unterschiede)",
+            ),
+            Text::Version => ("Version", "Version"),
+            Text::Device => ("Device", "Gerät"),
+            Text::OperatingSystem => ("OS", "Betriebssystem"),
+            Text::Locale => ("Locale", "Gebietsschema"),
+            Text::Captured => ("Captured", "Erstellt"),
+            Text::SearchMatch => ("Search match", "Suchtreffer"),
+            Text::IncomingCall => ("Incoming call", "Eingehender Anruf"),
+            Text::OutgoingCall => ("Outgoing call", "Ausgehender Anruf"),
+            Text::CallNotConnected => (
+                "not connected by the end of the log",
+                "bis zum Ende des Logs nicht verbunden",
+            ),
+            Text::ShowSlowest => ("Show slowest", "Langsamste anzeigen"),
+            Text::NotificationDelivery => (
+                "Notification delivery",
+                "Zustellung von Benachrichtigungen",
+            ),
+            Text::RemoteConfigChanges => (
+                "Remote config changes",
+                "Änderungen der Remote-Konfiguration",
+            ),
+            Text::Store => ("Store", "Speicher"),
+            Text::From => ("From", "Von"),
+            Text::To => ("To", "Zu"),
+            Text::FinalValue => ("Final value", "Endgültiger Wert"),
+            Text::NoValue => ("(none)", "(keiner)"),
+            Text::DiffersFromFinalValue => (
+                "Differs from the value it changed to",
+                "Weicht von dem Wert ab, zu dem es geändert wurde",
+            ),
+            Text::MessageTimeline => ("Message timeline", "Nachrichtenverlauf"),
+            Text::Show => ("Show", "Anzeigen"),
+            Text::Elapsed => ("Elapsed", "Vergangen"),
+            Text::Stage => ("Stage", "Schritt"),
+            Text::StageSend => ("Send", "Senden"),
+            Text::StageEncrypt => ("Encrypt", "Verschlüsseln"),
+            Text::StageUpload => ("Upload", "Hochladen"),
+            Text::StageReceipt => ("Receipt", "Bestätigung"),
+            Text::StageReceive => ("Receive", "Empfangen"),
+            Text::StageDecrypt => ("Decrypt", "Entschlüsseln"),
+            Text::NotParsed => ("not parsed", "nicht verarbeitet"),
+            Text::EmptyArchive => (
+                "The debug log doesn't contain any files.",
+                "Das Debug-Log enthält keine Dateien.",
+            ),
+        };
+
+        match language {
+            Language::English => english,
+            Language::German => german,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test]
+    fn in_language_ok_complete() {
+        for language in Language::iter() {
+            for text in Text::iter() {
+                assert!(
+                    !text.in_language(language).trim().is_empty(),
+                    "{:?} is missing in {}",
+                    text,
+                    language
+                );
+            }
+        }
+    }
+
+    #[test_case(Language::English => "Search warn logs..."; "english")]
+    #[test_case(Language::German => "Warn-Logs durchsuchen..."; "german")]
+    fn search_logs_placeholder_ok(language: Language) -> String {
+        LANGUAGE.with(|current| current.set(language));
+        search_logs_placeholder("Warn")
+    }
+
+    #[test_case(Language::English, 0, 12 => "This log only covers 12 seconds."; "english seconds")]
+    #[test_case(Language::English, 1, 75 => "This log only covers 1 minute."; "english minute")]
+    #[test_case(Language::German, 3, 200 => "Dieses Log umfasst nur 3 Minuten."; "german minutes")]
+    fn short_log_warning_ok(language: Language, minutes: i64, seconds: i64) -> String {
+        LANGUAGE.with(|current| current.set(language));
+        short_log_warning(minutes, seconds)
+    }
+}
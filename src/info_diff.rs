@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+
+use yew::prelude::*;
+
+use crate::{
+    components::{Table, TableItem, TableRow},
+    parsers::{Content, InfoEntry, Section},
+    tr, Text,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    Added(String),
+    Removed(String),
+    Changed(String, String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InfoDiffRow {
+    /// Names of the section and its parents, joined with ` / `.
+    pub section: String,
+    pub key: String,
+    pub change: Change,
+}
+
+/// Key-by-key differences between the information of two debug logs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InfoDiff {
+    pub rows: Vec<InfoDiffRow>,
+}
+
+impl InfoDiff {
+    pub fn new(old: &Content, new: &Content) -> Self {
+        let old = flatten(&old.information);
+        let new = flatten(&new.information);
+
+        let new_values = new
+            .iter()
+            .map(|(section, key, value)| ((section, key), value))
+            .collect::<HashMap<_, _>>();
+        let old_values = old
+            .iter()
+            .map(|(section, key, value)| ((section, key), value))
+            .collect::<HashMap<_, _>>();
+
+        let mut rows = vec![];
+
+        for (section, key, old_value) in &old {
+            let change = match new_values.get(&(section, key)) {
+                Some(new_value) if *new_value == old_value => continue,
+                Some(new_value) => Change::Changed(old_value.clone(), (*new_value).clone()),
+                None => Change::Removed(old_value.clone()),
+            };
+
+            rows.push(InfoDiffRow {
+                section: section.clone(),
+                key: key.clone(),
+                change,
+            });
+        }
+
+        for (section, key, new_value) in &new {
+            if !old_values.contains_key(&(section, key)) {
+                rows.push(InfoDiffRow {
+                    section: section.clone(),
+                    key: key.clone(),
+                    change: Change::Added(new_value.clone()),
+                });
+            }
+        }
+
+        Self { rows }
+    }
+
+    pub fn view(&self) -> Html {
+        if self.rows.is_empty() {
+            return html! { <p>{ tr(Text::NoDifferences) }</p> };
+        }
+
+        html! {
+            <Table>
+                <thead>
+                    <TableRow classes={classes!("text-left")}>
+                        <TableItem tag="th">{ tr(Text::Section) }</TableItem>
+                        <TableItem tag="th">{ tr(Text::Key) }</TableItem>
+                        <TableItem tag="th">{ tr(Text::Main) }</TableItem>
+                        <TableItem tag="th">{ tr(Text::Comparison) }</TableItem>
+                    </TableRow>
+                </thead>
+                <tbody>
+                    { for self.rows.iter().map(InfoDiffRow::view) }
+                </tbody>
+            </Table>
+        }
+    }
+}
+
+impl InfoDiffRow {
+    pub fn view(&self) -> Html {
+        let (classes, old, new) = match &self.change {
+            Change::Added(new) => (
+                classes!("text-green-600", "dark:text-green-400"),
+                "",
+                new.as_str(),
+            ),
+            Change::Removed(old) => (
+                classes!("text-red-600", "dark:text-red-400"),
+                old.as_str(),
+                "",
+            ),
+            Change::Changed(old, new) => (
+                classes!("text-yellow-600", "dark:text-yellow-400"),
+                old.as_str(),
+                new.as_str(),
+            ),
+        };
+
+        html! {
+            <TableRow {classes}>
+                <TableItem>{ &self.section }</TableItem>
+                <TableItem>{ &self.key }</TableItem>
+                <TableItem><pre>{ old }</pre></TableItem>
+                <TableItem><pre>{ new }</pre></TableItem>
+            </TableRow>
+        }
+    }
+}
+
+/// All keyed information entries as `(section, key, value)`, in order; only the first
+/// occurrence of a key within a section is kept.
+fn flatten(sections: &[Section<InfoEntry>]) -> Vec<(String, String, String)> {
+    fn flatten_section(
+        section: &Section<InfoEntry>,
+        parent: Option<&str>,
+        output: &mut Vec<(String, String, String)>,
+    ) {
+        let name = match parent {
+            Some(parent) => format!("{} / {}", parent, section.name),
+            None => section.name.clone(),
+        };
+
+        for entry in &section.content {
+            let (key, value) = match entry {
+                InfoEntry::KeyValue(key, value) => (key.clone(), value.to_string()),
+                InfoEntry::KeyEnabledValue(key, enabled, value) => {
+                    let enabled = if *enabled { "enabled" } else { "disabled" };
+
+                    match value {
+                        Some(value) => (key.clone(), format!("{}, {}", enabled, value)),
+                        None => (key.clone(), enabled.to_owned()),
+                    }
+                }
+                InfoEntry::GenericTable(table) => {
+                    for row in &table.rows {
+                        if let Some((key, values)) = row.split_first() {
+                            push_unique(output, &name, key.clone(), values.join(" | "));
+                        }
+                    }
+
+                    continue;
+                }
+                InfoEntry::RemoteObject(_) | InfoEntry::ExplicitNone | InfoEntry::Generic(_) => {
+                    continue
+                }
+            };
+
+            push_unique(output, &name, key, value);
+        }
+
+        for subsection in &section.subsections {
+            flatten_section(subsection, Some(&name), output);
+        }
+    }
+
+    let mut output = vec![];
+
+    for section in sections {
+        flatten_section(section, None, &mut output);
+    }
+
+    output
+}
+
+fn push_unique(
+    output: &mut Vec<(String, String, String)>,
+    section: &str,
+    key: String,
+    value: String,
+) {
+    if !output.iter().any(|(s, k, _)| s == section && *k == key) {
+        output.push((section.to_owned(), key, value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::Value;
+
+    use super::*;
+
+    fn test_content(entries: Vec<InfoEntry>) -> Content {
+        Content {
+            information: vec![Section {
+                name: "SYSINFO".to_owned(),
+                content: vec![],
+                subsections: vec![Section {
+                    name: "FEATURE FLAGS".to_owned(),
+                    content: entries,
+                    subsections: vec![],
+                }],
+            }],
+            logs: vec![],
+        }
+    }
+
+    fn key_value(key: &str, value: &str) -> InfoEntry {
+        InfoEntry::KeyValue(key.to_owned(), Value::Generic(value.to_owned()))
+    }
+
+    #[test]
+    fn info_diff_ok() {
+        let old = test_content(vec![
+            key_value("Same", "1"),
+            key_value("Bumped", "1.2.3"),
+            key_value("Removed", "yes"),
+            InfoEntry::KeyEnabledValue("Flag".to_owned(), false, None),
+        ]);
+        let new = test_content(vec![
+            key_value("Same", "1"),
+            key_value("Bumped", "1.2.4"),
+            InfoEntry::KeyEnabledValue("Flag".to_owned(), true, None),
+            key_value("Added", "no"),
+        ]);
+
+        let row = |key: &str, change| InfoDiffRow {
+            section: "SYSINFO / FEATURE FLAGS".to_owned(),
+            key: key.to_owned(),
+            change,
+        };
+
+        assert_eq!(
+            InfoDiff::new(&old, &new).rows,
+            vec![
+                row(
+                    "Bumped",
+                    Change::Changed("1.2.3".to_owned(), "1.2.4".to_owned())
+                ),
+                row("Removed", Change::Removed("yes".to_owned())),
+                row(
+                    "Flag",
+                    Change::Changed("disabled".to_owned(), "enabled".to_owned())
+                ),
+                row("Added", Change::Added("no".to_owned())),
+            ]
+        );
+    }
+}
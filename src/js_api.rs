@@ -0,0 +1,56 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{parsers::Content, Platform};
+
+/// Parses the debug log, serialized as JSON (in the shape of [`Content`]).
+fn parse_to_json(platform: Platform, text: &str) -> anyhow::Result<String> {
+    Ok(serde_json::to_string(&Content::parse(platform, text)?)?)
+}
+
+/// Parses the debug log into a plain JavaScript object, throwing an `Error` if it can't be parsed.
+fn parse(platform: Platform, text: &str) -> Result<JsValue, JsValue> {
+    let json =
+        parse_to_json(platform, text).map_err(|e| js_sys::Error::new(&format!("{:#}", e)))?;
+
+    js_sys::JSON::parse(&json)
+}
+
+/// Parses a Signal Android debug log, with its information sections and log sections.
+#[wasm_bindgen(js_name = parseAndroid)]
+pub fn parse_android(text: &str) -> Result<JsValue, JsValue> {
+    parse(Platform::Android, text)
+}
+
+/// Parses a Signal iOS log file, which is one of the files of a debug log's `zip` archive.
+#[wasm_bindgen(js_name = parseIos)]
+pub fn parse_ios(text: &str) -> Result<JsValue, JsValue> {
+    parse(Platform::Ios, text)
+}
+
+/// Parses a Signal Desktop debug log (after decompressing it).
+#[wasm_bindgen(js_name = parseDesktop)]
+pub fn parse_desktop(text: &str) -> Result<JsValue, JsValue> {
+    parse(Platform::Desktop, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_to_json_ok() {
+        let json = parse_to_json(
+            Platform::Ios,
+            "1234/01/23 12:34:56:789 💚 [Item.abc:123 -[Item handleSomething]]: Test",
+        )
+        .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["logs"][0]["content"][0]["message"], "Test");
+    }
+
+    #[test]
+    fn parse_to_json_err() {
+        assert!(parse_to_json(Platform::Android, "not a debug log").is_err());
+    }
+}
@@ -0,0 +1,49 @@
+mod cache;
+mod calls;
+pub mod components;
+mod diagnostics;
+pub mod extraction;
+mod fetch;
+mod file;
+mod flags;
+mod history;
+mod i18n;
+mod info_diff;
+mod js_api;
+mod log_level;
+mod message_trace;
+mod minimap;
+mod model;
+mod notifications;
+mod page_api;
+pub mod parsers;
+mod permalink;
+mod remote_config;
+mod rendered_log_section;
+mod settings;
+mod shortcuts;
+mod source_links;
+mod summary;
+mod threads;
+mod utils;
+mod view;
+
+pub use fetch::FetchError;
+pub use file::{File, FileSummary, RawViewOptions};
+pub use flags::{FeatureFlag, FlagFilter};
+pub use history::{History, HistoryEntry};
+pub use i18n::{tr, Language, Text};
+pub use log_level::LogLevelView;
+pub use message_trace::MessageTrace;
+pub use model::*;
+pub use remote_config::RemoteConfigChange;
+pub use rendered_log_section::{title_id, RenderedLogSection};
+pub use settings::{Settings, Theme, LARGE_LOG_LIMITS_MIB};
+pub use threads::DumpedThread;
+pub use utils::*;
+
+use readlogs_core::redaction;
+pub use readlogs_core::{
+    export, needs_platform, post_processing, remote_object, LogLevel, Platform, RemoteObject,
+    SearchQuery,
+};
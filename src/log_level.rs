@@ -1,61 +1,101 @@
-use derive_more::Display;
-use strum_macros::{EnumIter, EnumString};
 use yew::prelude::*;
 
-use crate::Platform::{self, *};
+use crate::{components::Badge, i18n, LogLevel};
 use LogLevel::*;
 
-#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, PartialOrd, Hash, EnumString, EnumIter)]
-#[strum(ascii_case_insensitive)]
-pub enum LogLevel {
-    /// Desktop
-    Trace,
-    /// Android, iOS
-    // `serialize = "verbose"` is included for parsing of `<select>`'s option.
-    #[strum(serialize = "V", serialize = "💙", serialize = "verbose")]
-    Verbose,
-    /// Android, iOS, Desktop
-    #[strum(serialize = "D", serialize = "💚", serialize = "debug")]
-    Debug,
-    /// Android, iOS, Desktop
-    #[strum(serialize = "I", serialize = "💛", serialize = "info")]
-    Info,
-    /// Android, iOS, Desktop
-    #[strum(serialize = "W", serialize = "🧡", serialize = "warn")]
-    Warn,
-    /// Android, iOS, Desktop
-    #[strum(serialize = "E", serialize = "❤️", serialize = "error")]
-    Error,
-    /// Android, Desktop
-    #[strum(serialize = "F", serialize = "fatal")]
-    Fatal,
-}
+/// How log levels are shown in the app.
+pub trait LogLevelView {
+    /// Text color of entries at this level. The `colorblind:` variants avoid telling levels apart
+    /// by red and green alone.
+    fn color(&self) -> Classes;
 
-impl Default for LogLevel {
-    fn default() -> Self {
-        Info
-    }
+    fn badge_color(&self) -> Classes;
+
+    fn view(&self) -> Html;
 }
 
-impl LogLevel {
-    pub fn applicable_to_platform(&self, platform: Platform) -> bool {
-        matches!(
-            (self, platform),
-            (Trace, Desktop)
-                | (Verbose, Android | Ios)
-                | (Debug | Info | Warn | Error, Android | Ios | Desktop)
-                | (Fatal, Android | Desktop)
-        )
+impl LogLevelView for LogLevel {
+    fn color(&self) -> Classes {
+        match self {
+            Trace | Verbose => classes!(
+                "text-green-600",
+                "dark:text-green-400",
+                "colorblind:text-slate-500",
+                "dark-colorblind:text-slate-400",
+            ),
+            Debug => classes!(
+                "text-blue-600",
+                "dark:text-blue-400",
+                "colorblind:text-sky-700",
+                "dark-colorblind:text-sky-300",
+            ),
+            Info => classes!(),
+            Warn => classes!(
+                "text-yellow-600",
+                "dark:text-yellow-400",
+                "colorblind:text-amber-600",
+                "dark-colorblind:text-amber-300",
+            ),
+            Error => classes!(
+                "text-rose-600",
+                "dark:text-rose-400",
+                "colorblind:text-orange-700",
+                "dark-colorblind:text-orange-400",
+                "colorblind:font-semibold",
+            ),
+            Fatal => classes!(
+                "text-fuchsia-600",
+                "dark:text-fuchsia-400",
+                "colorblind:text-purple-800",
+                "dark-colorblind:text-purple-300",
+                "colorblind:font-bold",
+            ),
+        }
     }
 
-    pub fn color(&self) -> Classes {
+    fn badge_color(&self) -> Classes {
         match self {
-            Trace | Verbose => classes!("text-green-600", "dark:text-green-400"),
-            Debug => classes!("text-blue-600", "dark:text-blue-400"),
-            Info => classes!(),
-            Warn => classes!("text-yellow-600", "dark:text-yellow-400"),
-            Error => classes!("text-rose-600", "dark:text-rose-400"),
-            Fatal => classes!("text-fuchsia-600", "dark:text-fuchsia-400"),
+            Trace | Verbose => classes!(
+                "bg-green-100",
+                "dark:bg-green-900",
+                "colorblind:bg-slate-100",
+                "dark-colorblind:bg-slate-800",
+            ),
+            Debug => classes!(
+                "bg-blue-100",
+                "dark:bg-blue-900",
+                "colorblind:bg-sky-100",
+                "dark-colorblind:bg-sky-900",
+            ),
+            Info => classes!("bg-brand-bg-message", "dark:bg-brand-dark-bg-message"),
+            Warn => classes!(
+                "bg-yellow-100",
+                "dark:bg-yellow-900",
+                "colorblind:bg-amber-100",
+                "dark-colorblind:bg-amber-900",
+            ),
+            Error => classes!(
+                "bg-rose-100",
+                "dark:bg-rose-900",
+                "colorblind:bg-orange-200",
+                "dark-colorblind:bg-orange-900",
+            ),
+            Fatal => classes!(
+                "bg-fuchsia-100",
+                "dark:bg-fuchsia-900",
+                "colorblind:bg-purple-200",
+                "dark-colorblind:bg-purple-900",
+            ),
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <Badge
+                classes={classes!(self.badge_color(), "text-xs")}
+                text={self.to_string().to_uppercase()}
+                label={i18n::log_level_label(&self.to_string())}
+            />
         }
     }
 }
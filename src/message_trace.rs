@@ -0,0 +1,199 @@
+use crate::{
+    parsers::{Content, LogEntry},
+    tr, Text,
+};
+use chrono::{DateTime, Utc};
+
+/// Number of digits of the millisecond timestamps that Signal identifies messages by.
+const MESSAGE_ID_DIGITS: usize = 13;
+
+/// Steps of sending or receiving a message, with parts of (lowercase) log messages that mark
+/// them; the first one that matches is used.
+const STAGE_PATTERNS: [(MessageStage, &[&str]); 6] = [
+    (
+        MessageStage::Receipt,
+        &["receipt", "delivered", "read sync"],
+    ),
+    (MessageStage::Upload, &["upload", "attachment"]),
+    (MessageStage::Encrypt, &["encrypt"]),
+    (MessageStage::Decrypt, &["decrypt"]),
+    (MessageStage::Send, &["send", "sent"]),
+    (MessageStage::Receive, &["receiv", "incoming", "envelope"]),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MessageStage {
+    Send,
+    Encrypt,
+    Upload,
+    Receipt,
+    Receive,
+    Decrypt,
+}
+
+impl MessageStage {
+    /// Name of the stage in the current language.
+    pub fn label(&self) -> &'static str {
+        tr(match self {
+            MessageStage::Send => Text::StageSend,
+            MessageStage::Encrypt => Text::StageEncrypt,
+            MessageStage::Upload => Text::StageUpload,
+            MessageStage::Receipt => Text::StageReceipt,
+            MessageStage::Receive => Text::StageReceive,
+            MessageStage::Decrypt => Text::StageDecrypt,
+        })
+    }
+}
+
+/// A log entry that mentions the traced message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceStep {
+    /// Element ID of the log entry.
+    pub entry_id: String,
+    pub timestamp: DateTime<Utc>,
+    pub original_timestamp: String,
+    pub stage: Option<MessageStage>,
+    /// First line of the log message.
+    pub summary: String,
+}
+
+/// The lifecycle of a single message: the log entries of all sections that mention its ID, in
+/// the order they were logged.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageTrace {
+    pub id: String,
+    pub steps: Vec<TraceStep>,
+}
+
+impl MessageTrace {
+    pub fn new(content: &Content, id: &str) -> Self {
+        let steps = content
+            .log_entries_by_time()
+            .into_iter()
+            .filter(|entry| message_ids(&entry.message).iter().any(|i| i == id))
+            .map(|entry| TraceStep {
+                entry_id: entry.element_id(),
+                timestamp: entry.timestamp,
+                original_timestamp: entry.original_timestamp.clone(),
+                stage: stage(entry),
+                summary: entry.message.lines().next().unwrap_or_default().to_owned(),
+            })
+            .collect();
+
+        Self {
+            id: id.to_owned(),
+            steps,
+        }
+    }
+
+    /// Traces of the messages mentioned by `entry` that are mentioned by other entries too.
+    pub fn for_entry(content: &Content, entry: &LogEntry) -> Vec<MessageTrace> {
+        message_ids(&entry.message)
+            .iter()
+            .map(|id| Self::new(content, id))
+            .filter(|trace| trace.steps.len() > 1)
+            .collect()
+    }
+}
+
+/// Distinct IDs of messages in the text, i.e. millisecond timestamps that stand on their own.
+pub fn message_ids(text: &str) -> Vec<String> {
+    let mut output: Vec<String> = vec![];
+
+    for number in text.split(|c: char| !c.is_ascii_digit()) {
+        if number.len() == MESSAGE_ID_DIGITS
+            && number.starts_with('1')
+            && !output.iter().any(|id| id == number)
+        {
+            output.push(number.to_owned());
+        }
+    }
+
+    output
+}
+
+fn stage(entry: &LogEntry) -> Option<MessageStage> {
+    let message = entry.message.to_lowercase();
+
+    STAGE_PATTERNS
+        .iter()
+        .find(|(_, patterns)| patterns.iter().any(|pattern| message.contains(pattern)))
+        .map(|(stage, _)| *stage)
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::parsers::Section;
+
+    use super::*;
+
+    #[test_case("[1675000000000] Sending, then 1675000000000 again" => vec!["1675000000000"]; "repeated")]
+    #[test_case("a:1675000000001,b:1675000000002" => vec!["1675000000001", "1675000000002"]; "several")]
+    #[test_case("16750000000001 and 167500000000 and 9675000000000" => Vec::<String>::new(); "not ids")]
+    fn message_ids_ok(text: &str) -> Vec<String> {
+        message_ids(text)
+    }
+
+    #[test_case("Received delivery receipt for 1675000000000" => Some(MessageStage::Receipt); "receipt")]
+    #[test_case("Uploading attachment of 1675000000000" => Some(MessageStage::Upload); "upload")]
+    #[test_case("[sendMessage] 1675000000000" => Some(MessageStage::Send); "send")]
+    #[test_case("Decrypted envelope 1675000000000" => Some(MessageStage::Decrypt); "decrypt")]
+    #[test_case("Marking 1675000000000" => None; "other")]
+    fn stage_ok(message: &str) -> Option<MessageStage> {
+        stage(&LogEntry::test(message, 0))
+    }
+
+    #[test]
+    fn for_entry_ok() {
+        let content = Content {
+            information: vec![],
+            logs: vec![Section {
+                name: "LOGCAT".to_owned(),
+                content: vec![
+                    LogEntry::test("[sendMessage] 1675000000000 to 1675000000009", 0),
+                    LogEntry::test("Encrypting 1675000000000", 10),
+                    LogEntry::test("Unrelated", 20),
+                    LogEntry::test("Received delivery receipt for 1675000000000\nmore", 30),
+                ],
+                subsections: vec![],
+            }],
+        };
+
+        let traces = MessageTrace::for_entry(&content, &content.logs[0].content[0]);
+        assert_eq!(
+            traces
+                .iter()
+                .map(|trace| (
+                    trace.id.as_str(),
+                    trace
+                        .steps
+                        .iter()
+                        .map(|step| (step.entry_id.as_str(), step.stage, step.summary.as_str()))
+                        .collect::<Vec<_>>()
+                ))
+                .collect::<Vec<_>>(),
+            vec![(
+                "1675000000000",
+                vec![
+                    (
+                        "entry-0",
+                        Some(MessageStage::Send),
+                        "[sendMessage] 1675000000000 to 1675000000009"
+                    ),
+                    (
+                        "entry-10",
+                        Some(MessageStage::Encrypt),
+                        "Encrypting 1675000000000"
+                    ),
+                    (
+                        "entry-30",
+                        Some(MessageStage::Receipt),
+                        "Received delivery receipt for 1675000000000"
+                    ),
+                ]
+            )]
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use std::collections::BTreeSet;
+
+use yew::prelude::*;
+
+use crate::{i18n, parsers::Content, tr, LogLevel, SearchQuery, Tab, Text};
+
+/// Number of rows of the minimap; entries that fall into the same row share a marker.
+const ROWS: usize = 200;
+const ROW_PERCENT: f64 = 100.0 / ROWS as f64;
+
+/// A marker on the minimap, for one or more displayed log entries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinimapMarker {
+    /// Row of the minimap, out of `ROWS`.
+    pub row: usize,
+    /// The most important level at or above `LogLevel::Warn`, if any.
+    pub level: Option<LogLevel>,
+    /// Whether the message of any of the entries contains the search query.
+    pub is_match: bool,
+    /// Element ID of the first of the entries.
+    pub entry_id: String,
+}
+
+impl MinimapMarker {
+    /// Markers for the log entries of `content` that are displayed on the Logs tab, see
+    /// [`Content::displayed_log_entries`].
+    pub fn all(
+        content: &Content,
+        query: &SearchQuery,
+        collapsed_sections: &BTreeSet<String>,
+        newest_first: bool,
+    ) -> Vec<MinimapMarker> {
+        let entries = content.displayed_log_entries(query, collapsed_sections, newest_first);
+
+        let query_string = query.string.to_lowercase();
+        let mut markers: Vec<MinimapMarker> = vec![];
+
+        for (index, entry) in entries.iter().enumerate() {
+            let level = entry.level.filter(|level| *level >= LogLevel::Warn);
+            let is_match =
+                !query_string.is_empty() && entry.message.to_lowercase().contains(&query_string);
+
+            if level.is_none() && !is_match {
+                continue;
+            }
+
+            let row = index * ROWS / entries.len();
+
+            match markers.last_mut() {
+                Some(marker) if marker.row == row => {
+                    if level > marker.level {
+                        marker.level = level;
+                    }
+                    marker.is_match |= is_match;
+                }
+                _ => markers.push(MinimapMarker {
+                    row,
+                    level,
+                    is_match,
+                    entry_id: entry.element_id(),
+                }),
+            }
+        }
+
+        markers
+    }
+
+    /// What the marker stands for, so that it isn't told by its color alone.
+    fn label(&self) -> String {
+        match (self.level, self.is_match) {
+            (Some(level), true) => i18n::level_search_match(&level.to_string()),
+            (Some(level), false) => level.to_string(),
+            (None, _) => tr(Text::SearchMatch).to_owned(),
+        }
+    }
+
+    fn color(&self) -> Classes {
+        match self.level {
+            Some(LogLevel::Fatal) => classes!("bg-fuchsia-500", "colorblind:bg-purple-700"),
+            Some(LogLevel::Error) => classes!("bg-rose-500", "colorblind:bg-orange-600"),
+            Some(_) => classes!("bg-yellow-500", "colorblind:bg-amber-300"),
+            None => classes!("bg-brand-primary-hover", "dark:bg-brand-dark-primary-hover"),
+        }
+    }
+}
+
+/// A thin strip with the `markers` at their relative positions, which call `on_show` with the tab
+/// and element ID to show when clicked.
+pub fn view_minimap(markers: &[MinimapMarker], on_show: &Callback<(Tab, String)>) -> Html {
+    if markers.is_empty() {
+        return html! {};
+    }
+
+    let view_marker = |marker: &MinimapMarker| {
+        let id = marker.entry_id.clone();
+
+        let mut classes = classes!(
+            marker.color(),
+            "absolute",
+            "left-0",
+            "right-0",
+            "min-h-[2px]",
+            "cursor-pointer",
+            "focus:outline-none",
+            "focus-visible:ring-2",
+            "focus-visible:ring-brand-focus",
+            "dark:focus-visible:ring-brand-dark-focus",
+        );
+        if marker.is_match && marker.level.is_some() {
+            classes.push(classes!(
+                "ring-2",
+                "ring-brand-primary-hover",
+                "dark:ring-brand-dark-primary-hover",
+            ));
+        }
+
+        html! {
+            <button
+                class={classes}
+                style={format!("top: {}%; height: {}%", marker.row as f64 * ROW_PERCENT, ROW_PERCENT)}
+                title={marker.label()}
+                aria-label={marker.label()}
+                onclick={on_show.reform(move |_| (Tab::Logs, id.clone()))}
+            />
+        }
+    };
+
+    html! {
+        <div class={classes!(
+            "sticky",
+            "top-4",
+            "shrink-0",
+            "w-2",
+            "h-[90vh]",
+            "rounded",
+            "bg-brand-bg-message",
+            "dark:bg-brand-dark-bg-message",
+        )}>
+            <div class="relative h-full">
+                { for markers.iter().map(view_marker) }
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::{LogEntry, Section};
+
+    use super::*;
+
+    fn test_entry(level: LogLevel, message: &str, start: usize) -> LogEntry {
+        LogEntry {
+            level: Some(level),
+            ..LogEntry::test(message, start)
+        }
+    }
+
+    fn test_content() -> Content {
+        Content {
+            information: vec![],
+            logs: vec![
+                Section {
+                    name: "A".to_owned(),
+                    content: vec![
+                        test_entry(LogLevel::Info, "Fine", 0),
+                        test_entry(LogLevel::Warn, "Careful", 10),
+                        test_entry(LogLevel::Info, "Needle", 20),
+                    ],
+                    subsections: vec![],
+                },
+                Section {
+                    name: "B".to_owned(),
+                    content: vec![test_entry(LogLevel::Error, "Failed", 30)],
+                    subsections: vec![],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn all_ok() {
+        let query = SearchQuery {
+            min_log_level: LogLevel::Info,
+            string: "e".to_owned(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            MinimapMarker::all(&test_content(), &query, &BTreeSet::new(), false)
+                .into_iter()
+                .map(|marker| (marker.row, marker.level, marker.is_match, marker.entry_id))
+                .collect::<Vec<_>>(),
+            vec![
+                (0, None, true, "entry-0".to_owned()),
+                (50, Some(LogLevel::Warn), true, "entry-10".to_owned()),
+                (100, None, true, "entry-20".to_owned()),
+                (150, Some(LogLevel::Error), true, "entry-30".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn all_ok_newest_first() {
+        let query = SearchQuery {
+            min_log_level: LogLevel::Info,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            MinimapMarker::all(&test_content(), &query, &BTreeSet::new(), true)
+                .into_iter()
+                .map(|marker| (marker.row, marker.entry_id))
+                .collect::<Vec<_>>(),
+            vec![(50, "entry-10".to_owned()), (150, "entry-30".to_owned())]
+        );
+    }
+
+    #[test]
+    fn all_ok_collapsed() {
+        let query = SearchQuery {
+            min_log_level: LogLevel::Info,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            MinimapMarker::all(
+                &test_content(),
+                &query,
+                &BTreeSet::from(["B".to_owned()]),
+                false
+            )
+            .into_iter()
+            .map(|marker| (marker.row, marker.level))
+            .collect::<Vec<_>>(),
+            vec![(66, Some(LogLevel::Warn))]
+        );
+    }
+}
@@ -1,51 +1,306 @@
 use std::{
-    collections::BTreeMap,
-    io::{self, Cursor},
+    collections::{BTreeMap, BTreeSet},
+    io::Cursor,
+    mem,
     rc::Rc,
 };
 
-use anyhow::{anyhow, ensure, Context};
+use anyhow::{ensure, Context};
 use derive_more::{Display, IsVariant};
+use gloo::{
+    events::EventListener,
+    storage::{LocalStorage, Storage},
+    timers::callback::Timeout,
+};
+use gloo_worker::{Spawnable, WorkerBridge};
 use strum_macros::EnumIter;
-use web_sys::HtmlInputElement;
+use wasm_bindgen::JsCast;
+use web_sys::{AbortController, HtmlInputElement};
 use yew::prelude::*;
 use yewtil::NeqAssign;
 use zip::ZipArchive;
 
 use crate::{
-    parsers::{AppId, LogFilename},
+    cache,
+    components::{Toast, ToastKind, Toaster, TOAST_DURATION_MS},
+    extraction::{ArchiveExtractor, ExtractedEntry, ExtractionRequest, WORKER_PATH},
+    fetch::{self, FetchError, Progress},
+    page_api,
+    parsers::{AppId, LogColumn, LogFilename, ZIP_MAGIC},
     *,
 };
 
+const PINNED_KEYS_STORAGE_KEY: &str = "pinned_info_keys";
+/// Maximum number of entries that can be shown before and after every search result.
+pub const MAX_CONTEXT_LINES: usize = 10;
+
 #[derive(Debug)]
 pub enum Msg {
-    UpdateUrl(String),
-    Start,
-    FetchError(anyhow::Error),
-    FinishedFetchText(String),
-    FinishedFetchBinary(Vec<u8>),
-    UpdateActiveFile(Rc<LogFilename>),
+    Log(Side, LogMsg),
+    /// A [`Msg::Log`] for the session with the ID, which may not be the open one by the time it
+    /// arrives, e.g. when a fetch finishes.
+    SessionLog(usize, Side, LogMsg),
+    /// Opens a new session, with no debug log loaded yet.
+    OpenSession,
+    SwitchSession(usize),
+    CloseSession(usize),
+    UpdateComparison,
     UpdateTab(Tab),
     UpdateMinLogLevel(String),
     UpdateQuery(String),
+    UpdateTagFilter(String),
+    UpdateThreadFilter(String),
+    UpdateProcessFilter(String),
+    UpdateSectionScope(String),
     UpdateUiExpanded,
+    UpdateRedaction,
+    UpdateSensitiveHighlighting,
+    UpdateUrlLinking,
+    /// Replaces the settings, which are stored and applied right away.
+    UpdateSettings(Settings),
+    UpdateSettingsShown,
+    UpdateCacheUsage(Option<cache::Usage>),
+    /// Removes all debug logs from the cache.
+    ClearCache,
+    UpdateSortOrder,
+    UpdateContextLines(String),
+    /// Sets the hosts other than debuglogs.org that debug logs may be loaded from.
+    UpdateAllowedHosts(String),
+    UpdateCorsProxy(String),
+    /// Forgets the debug log with the URL in the history.
+    RemoveFromHistory(String),
+    /// Sets the label of the debug log with the URL in the history.
+    UpdateHistoryLabel(String, String),
+    UpdateEntryNumber(String),
+    /// Shows the log entry of the main debug log with the number entered.
+    ApplyEntryNumber,
+    UpdateRawLine(String),
+    /// Shows the 1-based line in the raw text.
+    GoToLine(usize),
+    UpdateRawQuery(String),
+    ApplyRawQuery,
+    /// Moves to the next (`1`) or previous (`-1`) match of the raw text search.
+    MoveRawMatch(isize),
+    ApplyRawLine,
+    UpdateSearchAllFiles,
+    UpdateInfoFilter(String),
+    SetInfoGroupsCollapsed(Vec<String>, bool),
+    SetLogSectionsCollapsed(Vec<String>, bool),
+    /// Shows or hides the entries around the matching log entry with the element ID.
+    ToggleEntryContext(String),
+    /// Adds the log entry with the element ID to the bookmarks, or removes it.
+    ToggleBookmark(String),
+    ToggleLogColumn(LogColumn),
+    /// Sets the width of a column of the logs table in pixels, or resets it with `None`.
+    ResizeLogColumn(LogColumn, Option<u32>),
+    UpdateMessageWrapping,
+    /// Edits the note of the log entry with the element ID, or stops editing with `None`.
+    EditAnnotation(Option<String>),
+    UpdateAnnotationRemembering,
+    TogglePinnedKey(String),
+    JumpToSection(String),
+    /// Switches to the tab and scrolls to the element with the ID.
+    ShowElement(Tab, String),
+    /// Selects (and scrolls to) a displayed log entry of the main debug log, among those at the
+    /// level or above.
+    SelectEntry(EntryJump, LogLevel),
+    /// Focuses the search (or filter) input of the tab.
+    FocusSearch,
+    UpdateShortcutsShown,
+    /// Shows the details of the log entry with the element ID in the active file of the log.
+    ShowEntryDetails(Side, String),
+    /// Hides the list of keyboard shortcuts and the details of a log entry.
+    CloseModal,
+    /// Shows a toast, which disappears after [`TOAST_DURATION_MS`].
+    ShowToast(ToastKind, String),
+    /// Hides the toast with the ID.
+    DismissToast(usize),
     ApplySearchQuery,
+    /// Prints the current tab, see [`Model::printing`].
+    Print,
+    /// Called before (`true`) and after (`false`) the page is printed.
+    UpdatePrinting(bool),
+    /// Resolves a promise of the page API with the log entries of the active file of the main
+    /// debug log that match the search query (none if it isn't loaded).
+    ReportEntries(js_sys::Function),
+}
+
+/// Which log entry to select, relative to the selected one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EntryJump {
+    First,
+    Next,
+    Previous,
+}
+
+/// Messages that concern only one of the loaded debug logs.
+#[derive(Debug)]
+pub enum LogMsg {
+    UpdateUrl(String),
+    /// Chooses the platform of a URL that is only a key.
+    UpdatePlatform(Option<Platform>),
+    Start,
+    /// Fetching the debug log at the location failed; temporary failures are retried a few times
+    /// before they are shown. Dropped unless it's the one being fetched.
+    FetchError(RemoteObject, FetchError),
+    /// Fetches the debug log that is being fetched again, after a temporary failure.
+    Retry,
+    /// Aborts fetching, or drops a debug log that is too large; the data that is shown stays.
+    Cancel,
+    UpdateProgress(Progress),
+    /// Hides the error of the last fetch; the data that is shown stays.
+    DismissError,
+    /// The debug log at the location was fetched; dropped unless it's the one being fetched.
+    FinishedFetch(RemoteObject, Body),
+    /// Loads the debug log at the location that was fetched before from the cache instead;
+    /// dropped unless it's the one being fetched.
+    LoadCached(RemoteObject, Body),
+    /// Parses the debug log that exceeded the size limit, either completely or only the end of
+    /// every file (if `true`), up to the limit.
+    LoadOversized(bool),
+    /// The worker extracted and parsed the next file of the `zip` archive that is being
+    /// extracted (or skipped it), or couldn't read the archive.
+    Extracted(Result<ExtractedEntry, String>),
+    UpdateActiveFiles(BTreeSet<Rc<LogFilename>>),
+    /// Makes the file active and scrolls to the entry with the given element ID.
+    ShowEntry(Rc<LogFilename>, String),
+    /// Shows the log entry parsed from the 1-based line of the raw text of the active file.
+    ShowLine(usize),
+    /// Makes only the next (`1`) or previous (`-1`) file active, relative to the first active one.
+    SwitchFile(isize),
+    /// Changes the selection of log entries after the entry with the element ID was clicked.
+    ClickEntry(String, SelectionMode),
+    /// Sets the note of the log entry with the element ID; an empty note removes it.
+    UpdateAnnotation(String, String),
+}
+
+/// How clicking a log entry changes the selection.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SelectionMode {
+    /// Selects only the clicked entry.
+    Single,
+    /// Selects the displayed entries from the last clicked one to the clicked one.
+    Range,
+    /// Adds the clicked entry to the selection, or removes it.
+    Toggle,
+}
+
+/// Which of the (up to two) debug logs is meant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Main,
+    Comparison,
+}
+
+impl Side {
+    /// Prefix of the element IDs of the log entries of a file of this debug log, which tells them
+    /// apart from those of the other debug log and, with the name of the `file`, from those of the
+    /// other files of a `zip` archive.
+    fn entry_id_prefix(self, file: Option<&str>) -> String {
+        let side = match self {
+            // Keeps the IDs of entries of single files as they are in permalinks
+            Side::Main => "",
+            Side::Comparison => "comparison-",
+        };
+
+        match file {
+            Some(file) => format!("{}{}-", side, file),
+            None => side.to_owned(),
+        }
+    }
 }
 
 #[derive(Debug)]
 pub enum Object {
-    Single(File),
+    Single(Box<File>),
     Multiple {
         files: BTreeMap<Rc<LogFilename>, File>,
-        active_filename: Rc<LogFilename>,
+        /// Never empty.
+        active_filenames: BTreeSet<Rc<LogFilename>>,
+        /// Entries of all active files combined, if there is more than one.
+        combined: Option<Box<File>>,
     },
 }
 
-#[derive(Debug, IsVariant)]
+impl Object {
+    /// The file that is active once a `zip` archive is loaded: the last one of the main app, or
+    /// else of the notification service or share extension, or else of any app; `None` without
+    /// any files.
+    fn default_active_filename(files: &BTreeMap<Rc<LogFilename>, File>) -> Option<Rc<LogFilename>> {
+        let last_for_app_id = |app_id| files.keys().filter(|k| k.app_id == app_id).last();
+
+        last_for_app_id(AppId::Signal)
+            .or_else(|| last_for_app_id(AppId::NotificationServiceExtension))
+            .or_else(|| last_for_app_id(AppId::ShareAppExtension))
+            .or_else(|| files.keys().last())
+            .map(Rc::clone)
+    }
+}
+
+/// Keeps only (about) the last `max_file_size` bytes of the text, if given.
+pub(crate) fn truncate(mut text: String, max_file_size: Option<usize>) -> String {
+    if let Some(max_file_size) = max_file_size {
+        keep_tail(&mut text, max_file_size);
+    }
+    text
+}
+
+/// A fetched debug log, before it's parsed.
+#[derive(Debug)]
+pub enum Body {
+    Text(String),
+    /// A `zip` archive of log files.
+    Binary(Vec<u8>),
+}
+
+impl Body {
+    /// Detects the platform of a debug log from another host than debuglogs.org by its content,
+    /// which is fetched as binary: a `zip` archive is from Signal iOS.
+    fn detect_platform(self, reference: RemoteObject) -> (RemoteObject, Self) {
+        let bytes = match self {
+            Body::Binary(bytes) if reference.is_direct() => bytes,
+            body => return (reference, body),
+        };
+
+        if bytes.starts_with(&ZIP_MAGIC) {
+            return (reference.with_platform(Platform::Ios), Body::Binary(bytes));
+        }
+
+        let text = match String::from_utf8(bytes) {
+            Ok(text) => text,
+            Err(e) => String::from_utf8_lossy(e.as_bytes()).into_owned(),
+        };
+
+        (
+            reference.with_platform(Platform::detect(&text)),
+            Body::Text(text),
+        )
+    }
+
+    /// Size of the text that would be parsed, i.e. of all files once extracted.
+    fn size(&self) -> anyhow::Result<usize> {
+        match self {
+            Body::Text(text) => Ok(text.len()),
+            Body::Binary(bytes) => {
+                let mut zip = ZipArchive::new(Cursor::new(bytes.as_slice()))
+                    .context("couldn't read the debug log file as a `zip`")?;
+
+                let mut size = 0;
+                for i in 0..zip.len() {
+                    size += zip.by_index(i)?.size() as usize;
+                }
+
+                Ok(size)
+            }
+        }
+    }
+}
+
+/// The data of a debug log, which is kept while another one is fetched, and if that fails.
+#[derive(Debug, Default, IsVariant)]
 pub enum State {
+    #[default]
     NoData,
-    Error(anyhow::Error),
-    Fetching,
     Ready(Object),
 }
 
@@ -53,49 +308,250 @@ impl PartialEq for State {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (State::NoData, State::NoData) => true,
-            (State::Error(_), State::Error(_)) => false,
-            (State::Fetching, State::Fetching) => true,
             (State::Ready(_), State::Ready(_)) => false,
             _ => false,
         }
     }
 }
 
-impl Default for State {
-    fn default() -> Self {
-        Self::NoData
+/// How the last fetch of a debug log went, independent of the data that is shown.
+#[derive(Debug, Default, IsVariant)]
+pub enum Status {
+    #[default]
+    Idle,
+    /// The debug log is being fetched from the location, which becomes the `remote_object` once
+    /// it's parsed; `retries` counts the fetches that failed temporarily and were started again.
+    Fetching {
+        reference: RemoteObject,
+        retries: u32,
+        /// Aborts the request that is in flight.
+        controller: AbortController,
+        /// Starts the next retry, unless dropped before.
+        retry: Option<Timeout>,
+        /// How much of the current attempt was downloaded, once that is reported.
+        progress: Option<Progress>,
+        /// The template of the CORS proxy's URL, once fetching directly failed and the debug log
+        /// is fetched through it instead.
+        proxy: Option<String>,
+    },
+    /// The debug log was fetched, but is larger than the limit of the settings; it's parsed once
+    /// it's decided whether to load all of it.
+    Oversized {
+        reference: RemoteObject,
+        body: Body,
+        /// Size of the text that would be parsed.
+        size: usize,
+    },
+    /// The files of the fetched `zip` archive are extracted and parsed one after another by a
+    /// worker, off the page's thread; the files that are done are already shown.
+    Extracting {
+        reference: RemoteObject,
+        /// Stops extracting once dropped.
+        extractor: WorkerBridge<ArchiveExtractor>,
+        /// Number of entries in the archive.
+        count: usize,
+        /// Number of files that are done, i.e. the index of the next one.
+        extracted: usize,
+        /// Names of the entries that were skipped, as they're no log files or are broken.
+        skipped: Vec<String>,
+    },
+    /// Fetching or parsing failed; shown until dismissed or the next fetch starts.
+    Failed(anyhow::Error),
+}
+
+impl Status {
+    /// Aborts the fetch (or the upcoming retry) if one is in progress, drops the debug log that
+    /// is too large, or stops extracting files (keeping the ones that are done).
+    fn cancel(&mut self) -> bool {
+        match self {
+            Status::Fetching { controller, .. } => controller.abort(),
+            Status::Oversized { .. } | Status::Extracting { .. } => {}
+            _ => return false,
+        }
+
+        *self = Status::Idle;
+        true
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SearchQuery {
-    pub min_log_level: LogLevel,
-    pub string: String,
+/// What loading a debug log took, shown below it.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LoadMetrics {
+    /// Bytes received when fetching it, which may be compressed; `None` if it was cached.
+    pub received: Option<usize>,
+    /// Size of the text of all files.
+    pub size: usize,
+    /// Time spent extracting and parsing the files, in milliseconds.
+    pub parse_ms: f64,
 }
 
-impl Default for SearchQuery {
-    fn default() -> Self {
-        Self {
-            min_log_level: LogLevel::Error,
-            string: Default::default(),
+/// A debug log, from entering its URL to it being fetched and parsed.
+#[derive(Debug, Default)]
+pub struct DebugLog {
+    pub state: State,
+    pub status: Status,
+    pub input: NodeRef,
+    pub url: String,
+    /// Platform chosen for a URL that is only a key, which doesn't tell it.
+    pub platform: Option<Platform>,
+    pub remote_object: Option<RemoteObject>,
+    /// Notes attached to log entries, by element ID.
+    pub annotations: BTreeMap<String, String>,
+    /// What loading the debug log that is shown took, once it's loaded completely.
+    pub metrics: Option<LoadMetrics>,
+    /// What loading the debug log that was fetched last took so far.
+    loading: LoadMetrics,
+}
+
+impl DebugLog {
+    /// Key of the notes of this log in `localStorage`.
+    fn annotations_storage_key(&self) -> String {
+        format!("annotations:{}", self.url)
+    }
+
+    /// Loads the notes of this log, shown on the `side`; they're stored by element IDs without the
+    /// prefix of the side, so that they're kept when the log is opened on the other one.
+    fn load_annotations(&mut self, side: Side) {
+        let prefix = side.entry_id_prefix(None);
+
+        self.annotations =
+            LocalStorage::get::<BTreeMap<String, String>>(self.annotations_storage_key())
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(id, note)| (format!("{}{}", prefix, id), note))
+                .collect();
+    }
+
+    /// Stores the notes of this log, shown on the `side`, in `localStorage`, or removes them if
+    /// there are none.
+    fn save_annotations(&self, side: Side) {
+        if self.annotations.is_empty() {
+            LocalStorage::delete(self.annotations_storage_key());
+        } else {
+            let prefix = side.entry_id_prefix(None);
+            let annotations = self
+                .annotations
+                .iter()
+                .filter_map(|(id, note)| Some((id.strip_prefix(&prefix)?, note)))
+                .collect::<BTreeMap<_, _>>();
+
+            let _ = LocalStorage::set(self.annotations_storage_key(), &annotations);
+        }
+    }
+
+    /// Parses the entered URL, with the chosen platform if it's only a key.
+    pub fn parse_url(&self, allowed_hosts: &[&str]) -> anyhow::Result<RemoteObject> {
+        RemoteObject::from_url(&self.url, allowed_hosts, self.platform)
+    }
+
+    /// Whether the debug log at the location is being fetched.
+    fn is_fetching(&self, fetched: &RemoteObject) -> bool {
+        matches!(&self.status, Status::Fetching { reference, .. } if reference == fetched)
+    }
+
+    /// Takes the location that is being fetched once the response for `fetched` arrived; `None`
+    /// if no fetch of it was awaited, e.g. as it was cancelled and another one started since.
+    fn finish_fetch(&mut self, fetched: &RemoteObject) -> Option<RemoteObject> {
+        match std::mem::take(&mut self.status) {
+            Status::Fetching { reference, .. } if reference == *fetched => Some(reference),
+            status => {
+                self.status = status;
+                None
+            }
+        }
+    }
+
+    /// Adds a file extracted from the `zip` archive that is shown, which becomes the active file
+    /// if it's a better default, unless other files were selected in the meantime.
+    fn add_file(&mut self, name: Rc<LogFilename>, file: File) {
+        if let State::Ready(Object::Multiple {
+            files,
+            active_filenames,
+            ..
+        }) = &mut self.state
+        {
+            let default_selected = Object::default_active_filename(files)
+                .is_some_and(|default| *active_filenames == BTreeSet::from([default]));
+
+            files.insert(name, file);
+
+            if default_selected {
+                if let Some(default) = Object::default_active_filename(files) {
+                    *active_filenames = BTreeSet::from([default]);
+                }
+            }
+        }
+    }
+
+    /// Replaces the data with the parsed debug log from the location, shown on the `side`.
+    fn show(&mut self, side: Side, reference: RemoteObject, object: Object) {
+        self.url = reference.debuglogs_url();
+        self.remote_object = Some(reference);
+        self.load_annotations(side);
+        self.state = State::Ready(object);
+    }
+
+    /// Number of log entries of all files.
+    pub fn entry_count(&self) -> usize {
+        let count = |file: &File| file.summary().entry_count.unwrap_or_default();
+
+        match &self.state {
+            State::Ready(Object::Single(file)) => count(file),
+            State::Ready(Object::Multiple { files, .. }) => files.values().map(count).sum(),
+            State::NoData => 0,
+        }
+    }
+
+    pub fn active_file(&self) -> &File {
+        match &self.state {
+            State::Ready(Object::Single(file)) => file,
+            State::Ready(Object::Multiple {
+                files,
+                active_filenames,
+                combined,
+            }) => match combined {
+                Some(combined) => combined,
+                None => files.get(active_filenames.iter().next().unwrap()).unwrap(),
+            },
+            _ => panic!("State is not `Ready`"),
+        }
+    }
+
+    /// Drops what was generated for the Raw tab of all files.
+    pub fn release_raw_tab(&self) {
+        match &self.state {
+            State::Ready(Object::Single(file)) => file.release_raw_tab(),
+            State::Ready(Object::Multiple {
+                files, combined, ..
+            }) => {
+                files
+                    .values()
+                    .chain(combined.as_deref())
+                    .for_each(File::release_raw_tab);
+            }
+            _ => {}
         }
     }
 }
 
-#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash, EnumIter, IsVariant)]
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash, Default, EnumIter, IsVariant)]
 pub enum Tab {
+    #[default]
     Information,
     Logs,
     Raw,
 }
 
-impl Default for Tab {
-    fn default() -> Self {
-        Tab::Information
+impl Tab {
+    /// Name of the tab in the current language.
+    pub fn label(&self) -> &'static str {
+        tr(match self {
+            Tab::Information => Text::Information,
+            Tab::Logs => Text::Logs,
+            Tab::Raw => Text::Raw,
+        })
     }
-}
 
-impl Tab {
     pub fn icon(&self) -> Classes {
         match self {
             Tab::Information => classes!("fas", "fa-info"),
@@ -105,191 +561,1303 @@ impl Tab {
     }
 }
 
+/// Debug logs opened in a tab of the tab strip, with what is shown of them; kept for the
+/// sessions that aren't open, while the open one's are the fields of the [`Model`].
+#[derive(Debug, Default)]
+pub struct Session {
+    pub main: DebugLog,
+    pub comparison: Option<DebugLog>,
+    pub tab: Tab,
+    pub pending_query: SearchQuery,
+    pub active_query: SearchQuery,
+    pub bookmarks: BTreeSet<String>,
+}
+
+impl Session {
+    /// Stops fetching or extracting the debug logs.
+    fn cancel(&mut self) {
+        self.main.status.cancel();
+        if let Some(comparison) = &mut self.comparison {
+            comparison.status.cancel();
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Model {
-    pub state: State,
-    pub debug_log_input: NodeRef,
-    pub debug_log_url: String,
-    pub remote_object: Option<RemoteObject>,
+    pub main: DebugLog,
+    /// Another debug log shown side by side with the main one, if comparing.
+    pub comparison: Option<DebugLog>,
     pub tab: Tab,
     pub pending_query: SearchQuery,
     pub active_query: SearchQuery,
+    /// Narrows down the entries shown on the Information tab; applied as it is typed.
+    pub info_filter: String,
+    /// IDs of information key prefix groups that are collapsed, kept for the whole session.
+    pub collapsed_info_groups: BTreeSet<String>,
+    /// Names of log sections that are collapsed, kept for the whole session.
+    pub collapsed_log_sections: BTreeSet<String>,
+    /// Keys of information entries shown at the top of every log; stored in `localStorage`.
+    pub pinned_keys: BTreeSet<String>,
     pub ui_expanded: bool,
+    /// Whether the page is embedded in another, showing only the tabs and their content.
+    pub embedded: bool,
+    /// Sessions other than the open one, by ID; their tabs are in the order of the IDs.
+    pub sessions: BTreeMap<usize, Session>,
+    /// ID of the open session.
+    pub session_id: usize,
+    /// ID of the next session that is opened.
+    next_session_id: usize,
+    /// Preferences that are stored in `localStorage`.
+    pub settings: Settings,
+    /// Size of the debug logs in the cache, once it's known.
+    pub cache_usage: Option<cache::Usage>,
+    /// Debug logs that were loaded recently; stored in `localStorage`.
+    pub history: History,
+    /// Whether identifiers are redacted; starts as [`Settings::redact_by_default`].
+    pub redact: bool,
+    pub highlight_sensitive: bool,
+    /// Whether URLs in log messages are links.
+    pub linkify_urls: bool,
+    /// Whether log entries are shown the latest first.
+    pub newest_first: bool,
+    /// Contents of the "go to entry" input of the logs table.
+    pub pending_entry_number: String,
+    /// Contents of the "go to line" input of the raw text.
+    pub pending_raw_line: String,
+    /// 1-based number of the line of the raw text that was navigated to.
+    pub raw_line: Option<usize>,
+    /// Contents of the search input of the raw text, independent of the log search query.
+    pub pending_raw_query: String,
+    pub active_raw_query: String,
+    /// Index of the current match of `active_raw_query`, wrapping around the number of matches.
+    pub raw_match: isize,
+    /// Whether the query is applied to all files of multi-file debug logs at once.
+    pub search_all_files: bool,
+    /// Element ID of a log entry or section title to scroll to after rendering.
+    pub scroll_to_entry: Option<String>,
+    /// Element ID of the log entry that was selected last, from which [`Msg::SelectEntry`] moves
+    /// on and range selections start.
+    pub selected_entry: Option<String>,
+    /// Element IDs of the selected log entries, which can be copied and exported.
+    pub selected_entries: BTreeSet<String>,
+    /// Element IDs of matching log entries that are shown with the entries around them.
+    pub context_entries: BTreeSet<String>,
+    /// Element IDs of bookmarked log entries, kept for the whole session.
+    pub bookmarks: BTreeSet<String>,
+    /// Widths of the columns of the logs table that were resized, in pixels.
+    pub log_column_widths: BTreeMap<LogColumn, u32>,
+    /// Element ID of the log entry whose note is being edited.
+    pub editing_annotation: Option<String>,
+    /// The search (or filter) input of the current tab.
+    pub search_input: NodeRef,
+    /// Whether the list of keyboard shortcuts is shown.
+    pub shortcuts_shown: bool,
+    pub settings_shown: bool,
+    /// The log and element ID of the log entry whose details are shown.
+    pub entry_details: Option<(Side, String)>,
+    /// ID of the session and element ID of the log entry to show once its main log is loaded and
+    /// it's open, if the page was opened via a permalink.
+    pub permalink_entry: Option<(usize, String)>,
+    /// Toasts that are currently shown, the oldest first.
+    pub toasts: Vec<Toast>,
+    /// ID of the next toast that is shown.
+    next_toast_id: usize,
+    /// Shows toasts; provided as a context to all components.
+    pub toaster: Toaster,
+    /// Whether the page is being printed, which expands collapsed sections, wraps log messages
+    /// and leaves out the entries shown around matching ones.
+    pub printing: bool,
+    /// Whether the print dialog is opened once the page is rendered for printing.
+    print_requested: bool,
+    _keydown_listener: Option<EventListener>,
+    _print_listeners: Vec<EventListener>,
 }
 
 impl Component for Model {
     type Message = Msg;
     type Properties = ();
 
-    fn create(_ctx: &yew::prelude::Context<Self>) -> Self {
+    fn create(ctx: &yew::prelude::Context<Self>) -> Self {
+        let link = ctx.link().clone();
+        let keydown_listener =
+            web_sys::window()
+                .and_then(|window| window.document())
+                .map(|document| {
+                    EventListener::new(&document, "keydown", move |event| {
+                        if let Some(msg) = event.dyn_ref::<KeyboardEvent>().and_then(shortcuts::msg)
+                        {
+                            event.prevent_default();
+                            link.send_message(msg);
+                        }
+                    })
+                });
+
+        let print_listeners = web_sys::window()
+            .map(|window| {
+                [("beforeprint", true), ("afterprint", false)]
+                    .into_iter()
+                    .map(|(event, printing)| {
+                        let link = ctx.link().clone();
+                        EventListener::new(&window, event, move |_| {
+                            link.send_message(Msg::UpdatePrinting(printing));
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let (url, permalink_entry) = match permalink::current() {
+            Some((url, entry_id)) => {
+                ctx.link().send_message(Msg::Log(Side::Main, LogMsg::Start));
+                (url, entry_id.map(|id| (0, id)))
+            }
+            None => (String::new(), None),
+        };
+
+        let settings = Settings::load();
+        settings.apply();
+
+        let _ = page_api::register(ctx.link());
+
         Self {
-            state: Default::default(),
-            debug_log_input: NodeRef::default(),
-            debug_log_url: Default::default(),
-            remote_object: None,
+            main: DebugLog {
+                url,
+                ..Default::default()
+            },
+            comparison: None,
             tab: Default::default(),
             pending_query: Default::default(),
             active_query: Default::default(),
+            info_filter: String::new(),
+            collapsed_info_groups: BTreeSet::new(),
+            collapsed_log_sections: BTreeSet::new(),
+            pinned_keys: LocalStorage::get(PINNED_KEYS_STORAGE_KEY).unwrap_or_default(),
             ui_expanded: false,
+            embedded: permalink::current_embedded(),
+            sessions: BTreeMap::new(),
+            session_id: 0,
+            next_session_id: 1,
+            redact: settings.redact_by_default,
+            highlight_sensitive: false,
+            linkify_urls: true,
+            newest_first: false,
+            pending_entry_number: String::new(),
+            pending_raw_line: String::new(),
+            raw_line: None,
+            pending_raw_query: String::new(),
+            active_raw_query: String::new(),
+            raw_match: 0,
+            search_all_files: false,
+            scroll_to_entry: None,
+            selected_entry: None,
+            selected_entries: BTreeSet::new(),
+            context_entries: BTreeSet::new(),
+            bookmarks: BTreeSet::new(),
+            log_column_widths: BTreeMap::new(),
+            editing_annotation: None,
+            search_input: NodeRef::default(),
+            shortcuts_shown: false,
+            settings_shown: false,
+            settings,
+            cache_usage: None,
+            history: History::load(),
+            entry_details: None,
+            permalink_entry,
+            toasts: vec![],
+            next_toast_id: 0,
+            toaster: Toaster(
+                ctx.link()
+                    .callback(|(kind, text)| Msg::ShowToast(kind, text)),
+            ),
+            printing: false,
+            print_requested: false,
+            _keydown_listener: keydown_listener,
+            _print_listeners: print_listeners,
         }
     }
 
     fn update(&mut self, ctx: &yew::prelude::Context<Self>, msg: Self::Message) -> bool {
-        match self.update_inner(ctx, msg) {
-            Ok(should_render) => should_render,
-            Err(e) => self.state.neq_assign(State::Error(e)),
+        match msg {
+            Msg::Log(side, msg) => self.apply_log(ctx, side, msg) | self.show_permalink_entry(),
+            Msg::ShowToast(kind, text) => {
+                let id = self.next_toast_id;
+                self.next_toast_id += 1;
+                self.toasts.push(Toast { id, kind, text });
+
+                let link = ctx.link().clone();
+                Timeout::new(TOAST_DURATION_MS, move || {
+                    link.send_message(Msg::DismissToast(id))
+                })
+                .forget();
+                true
+            }
+            Msg::UpdateSettingsShown if !self.settings_shown => {
+                ctx.link()
+                    .send_future(async { Msg::UpdateCacheUsage(cache::usage().await.ok()) });
+                self.update_inner(msg)
+            }
+            Msg::ClearCache => {
+                ctx.link().send_future(async {
+                    let _ = cache::clear().await;
+                    Msg::UpdateCacheUsage(cache::usage().await.ok())
+                });
+                false
+            }
+            Msg::SessionLog(session, side, msg) if session == self.session_id => {
+                self.update(ctx, Msg::Log(side, msg))
+            }
+            Msg::SessionLog(session, side, msg) => match self.sessions.remove(&session) {
+                Some(mut other) => {
+                    let open = self.session_id;
+
+                    // Only the log is updated, as the selection and scrolling are the open session's.
+                    self.swap_session(&mut other);
+                    self.session_id = session;
+                    self.apply_log(ctx, side, msg);
+                    self.swap_session(&mut other);
+                    self.session_id = open;
+
+                    self.sessions.insert(session, other);
+                    true
+                }
+                None => false,
+            },
+            msg => self.update_inner(msg),
         }
     }
 
     fn view(&self, ctx: &yew::prelude::Context<Self>) -> Html {
         self.view_inner(ctx)
     }
+
+    fn rendered(&mut self, _ctx: &yew::prelude::Context<Self>, _first_render: bool) {
+        if !self.tab.is_raw() {
+            self.main.release_raw_tab();
+
+            if let Some(comparison) = &self.comparison {
+                comparison.release_raw_tab();
+            }
+        }
+
+        if let Some(id) = self.scroll_to_entry.take() {
+            if let Some(element) = web_sys::window()
+                .and_then(|window| window.document())
+                .and_then(|document| document.get_element_by_id(&id))
+            {
+                element.scroll_into_view();
+            }
+        }
+
+        if mem::take(&mut self.print_requested) {
+            if let Some(window) = web_sys::window() {
+                let _ = window.print();
+            }
+        }
+    }
 }
 
 impl Model {
-    fn fetch(&self, ctx: &yew::prelude::Context<Self>, url: &str, binary: bool) {
-        let request = reqwasm::http::Request::get(url);
+    /// Changes the settings, then stores and applies them.
+    fn update_settings(&mut self, change: impl FnOnce(&mut Settings)) -> bool {
+        let mut settings = self.settings.clone();
+        change(&mut settings);
+
+        settings.save();
+        settings.apply();
+        self.settings.neq_assign(settings)
+    }
+
+    /// Switches to the rendering for printing (in light colors), or back.
+    fn update_printing(&mut self, printing: bool) -> bool {
+        if printing {
+            set_page_class("dark", false);
+        } else {
+            self.settings.apply();
+        }
+
+        self.printing.neq_assign(printing)
+    }
+
+    /// Shows the log entry of the permalink that the page was opened with, once the main log of
+    /// its session is loaded and open; its level becomes the minimum one if needed, so that it's
+    /// displayed.
+    fn show_permalink_entry(&mut self) -> bool {
+        if !self.main.state.is_ready() {
+            return false;
+        }
+
+        match self.permalink_entry.take() {
+            Some((session, id)) if session == self.session_id => self.show_entry(id),
+            pending => {
+                self.permalink_entry = pending;
+                false
+            }
+        }
+    }
+
+    /// Selects (and scrolls to) the log entry of the main log with the element ID on the Logs
+    /// tab; its level becomes the minimum one if needed, so that it's displayed.
+    fn show_entry(&mut self, id: String) -> bool {
+        let level = match self.main.active_file().entry_level(&id) {
+            Some(level) => level,
+            None => return false,
+        };
+
+        if level < self.active_query.min_log_level {
+            self.pending_query.min_log_level = level;
+            self.active_query.min_log_level = level;
+        }
+
+        self.tab = Tab::Logs;
+        self.selected_entry = Some(id.clone());
+        self.selected_entries = BTreeSet::from([id.clone()]);
+        self.scroll_to_entry = Some(id);
+        true
+    }
+
+    /// Updates the debug log on the `side`, which fails if the update does; returns whether to
+    /// render.
+    fn apply_log(&mut self, ctx: &yew::prelude::Context<Self>, side: Side, msg: LogMsg) -> bool {
+        match self.update_log(ctx, side, msg) {
+            Ok(should_render) => should_render,
+            Err(e) => match self.log_mut(side) {
+                Some(log) => {
+                    log.status = Status::Failed(e);
+                    true
+                }
+                None => false,
+            },
+        }
+    }
+
+    /// Exchanges what is shown of the open session with the other session.
+    fn swap_session(&mut self, session: &mut Session) {
+        mem::swap(&mut self.main, &mut session.main);
+        mem::swap(&mut self.comparison, &mut session.comparison);
+        mem::swap(&mut self.tab, &mut session.tab);
+        mem::swap(&mut self.pending_query, &mut session.pending_query);
+        mem::swap(&mut self.active_query, &mut session.active_query);
+        mem::swap(&mut self.bookmarks, &mut session.bookmarks);
+    }
+
+    /// Opens the session with the ID, or a new one if there's none, keeping the open one for later;
+    /// log entries selected in it are forgotten, as they don't exist in the other one.
+    fn open_session(&mut self, id: usize) {
+        let mut session = self.sessions.remove(&id).unwrap_or_default();
+        self.swap_session(&mut session);
+        self.sessions.insert(self.session_id, session);
+        self.session_id = id;
+
+        self.selected_entry = None;
+        self.selected_entries.clear();
+        self.context_entries.clear();
+        self.entry_details = None;
+        self.editing_annotation = None;
+        self.scroll_to_entry = None;
+        self.raw_line = None;
+    }
+
+    fn new_session_id(&mut self) -> usize {
+        let id = self.next_session_id;
+        self.next_session_id += 1;
+        id
+    }
+
+    /// IDs of all sessions, in the order of their tabs.
+    pub fn session_ids(&self) -> Vec<usize> {
+        let mut ids: Vec<_> = self.sessions.keys().copied().collect();
+        let index = ids.partition_point(|id| *id < self.session_id);
+        ids.insert(index, self.session_id);
+        ids
+    }
+
+    /// Shows a newly loaded main log from the start, with the default query.
+    fn reset_view(&mut self, side: Side) {
+        if side == Side::Main {
+            self.tab = Default::default();
+            self.pending_query = Default::default();
+            self.active_query = Default::default();
+        }
+    }
+
+    /// Shows a newly loaded log from the start, and remembers it in the history.
+    fn finish_loading(&mut self, side: Side) {
+        self.reset_view(side);
+
+        if let Some(reference) = self.log(side).and_then(|log| log.remote_object.as_ref()) {
+            self.history.add(
+                reference.debuglogs_url(),
+                reference.platform(),
+                js_sys::Date::now() as i64,
+            );
+            self.history.save();
+        }
+    }
+
+    /// Fetches the debug log at the location, which is a `zip` archive for Signal iOS (and may be
+    /// one from other hosts), until aborted with the returned controller; through the proxy with
+    /// the URL template, if given. It's taken from the cache if it was fetched before, and cached
+    /// otherwise.
+    fn fetch(
+        ctx: &yew::prelude::Context<Self>,
+        session: usize,
+        side: Side,
+        reference: &RemoteObject,
+        proxy: Option<&str>,
+    ) -> AbortController {
+        let url = match proxy {
+            Some(template) => fetch::proxied_url(template, &reference.debuglogs_url()),
+            None => reference.fetchable_url(),
+        };
+        let reference = reference.clone();
+        let cached_url = reference.debuglogs_url();
+        let binary = reference.is_direct() || matches!(reference.platform(), Platform::Ios);
+        let controller = AbortController::new().unwrap();
+        let signal = controller.signal();
+        let on_progress = ctx.link().callback(move |progress| {
+            Msg::SessionLog(session, side, LogMsg::UpdateProgress(progress))
+        });
 
         ctx.link().send_future(async move {
-            match request.send().await {
-                Ok(response) => match response.status() {
-                    200 => {
-                        if binary {
-                            let bytes = response.binary().await.unwrap();
-                            Msg::FinishedFetchBinary(bytes)
-                        } else {
-                            let text = response.text().await.unwrap();
-                            Msg::FinishedFetchText(text)
-                        }
-                    }
-                    _ => Msg::FetchError(
-                        anyhow!("status code {}", response.status())
-                            .context("fetching debug log finished unsuccessfully"),
-                    ),
-                },
-                Err(e) => Msg::FetchError(
-                    anyhow::Error::from(e).context("couldn't start fetching debug log"),
-                ),
+            if let Some(body) = cache::get(&cached_url).await {
+                let msg = if signal.aborted() {
+                    LogMsg::FetchError(reference, FetchError::Aborted)
+                } else {
+                    LogMsg::LoadCached(reference, body)
+                };
+                return Msg::SessionLog(session, side, msg);
             }
-        })
+
+            let body = if binary {
+                fetch::fetch_binary(&url, &signal, &on_progress)
+                    .await
+                    .map(Body::Binary)
+            } else {
+                fetch::fetch_text(&url, &signal, &on_progress)
+                    .await
+                    .map(Body::Text)
+            };
+
+            let msg = match body {
+                Ok(body) => {
+                    let _ = cache::put(&cached_url, &body).await;
+                    LogMsg::FinishedFetch(reference, body)
+                }
+                Err(e) => LogMsg::FetchError(reference, e),
+            };
+
+            Msg::SessionLog(session, side, msg)
+        });
+
+        controller
     }
 
-    pub(super) fn active_file(&self) -> &File {
-        match &self.state {
-            State::Ready(Object::Single(file)) => file,
-            State::Ready(Object::Multiple {
-                files,
-                active_filename,
-            }) => files.get(active_filename).unwrap(),
-            _ => panic!("State is not `Ready`"),
+    /// Shows the fetched debug log, or starts extracting the files of a `zip` archive one after
+    /// another in a worker; returns whether it's shown already.
+    fn load(
+        ctx: &yew::prelude::Context<Self>,
+        session: usize,
+        side: Side,
+        log: &mut DebugLog,
+        reference: RemoteObject,
+        body: Body,
+        max_file_size: Option<usize>,
+    ) -> anyhow::Result<bool> {
+        match body {
+            Body::Text(text) => {
+                let started = js_sys::Date::now();
+                let file = File::from_text(
+                    reference.clone(),
+                    None,
+                    truncate(text, max_file_size),
+                    side.entry_id_prefix(None),
+                );
+                log.loading.parse_ms = js_sys::Date::now() - started;
+                log.loading.size = file.summary().size;
+
+                log.show(side, reference, Object::Single(Box::new(file)));
+                log.metrics = Some(log.loading);
+                Ok(true)
+            }
+            Body::Binary(bytes) => {
+                let archive = ZipArchive::new(Cursor::new(bytes))
+                    .context("couldn't read the debug log file as a `zip`")?;
+
+                ensure!(!archive.is_empty(), tr(Text::EmptyArchive));
+
+                let link = ctx.link().clone();
+                let extractor = ArchiveExtractor::spawner()
+                    .callback(move |output| {
+                        link.send_message(Msg::SessionLog(session, side, LogMsg::Extracted(output)))
+                    })
+                    .spawn(WORKER_PATH);
+
+                let count = archive.len();
+                extractor.send(ExtractionRequest {
+                    bytes: archive.into_inner().into_inner(),
+                    platform: reference.platform(),
+                    max_file_size,
+                });
+
+                log.status = Status::Extracting {
+                    reference,
+                    extractor,
+                    count,
+                    extracted: 0,
+                    skipped: vec![],
+                };
+                Ok(false)
+            }
+        }
+    }
+
+    pub(super) fn log(&self, side: Side) -> Option<&DebugLog> {
+        match side {
+            Side::Main => Some(&self.main),
+            Side::Comparison => self.comparison.as_ref(),
         }
     }
 
-    fn update_inner(
+    fn log_mut(&mut self, side: Side) -> Option<&mut DebugLog> {
+        match side {
+            Side::Main => Some(&mut self.main),
+            Side::Comparison => self.comparison.as_mut(),
+        }
+    }
+
+    /// Loaded debug logs, in the order they are displayed.
+    pub(super) fn logs(&self) -> impl Iterator<Item = (Side, &DebugLog)> {
+        [Side::Main, Side::Comparison]
+            .into_iter()
+            .filter_map(move |side| self.log(side).map(|log| (side, log)))
+    }
+
+    fn update_log(
         &mut self,
         ctx: &yew::prelude::Context<Self>,
-        msg: <Self as Component>::Message,
+        side: Side,
+        msg: LogMsg,
     ) -> anyhow::Result<bool> {
+        let session = self.session_id;
+        let log = match side {
+            Side::Main => &mut self.main,
+            Side::Comparison => match &mut self.comparison {
+                Some(log) => log,
+                None => return Ok(false),
+            },
+        };
+
         match msg {
-            Msg::UpdateUrl(value) => Ok(self.debug_log_url.neq_assign(value)),
-            Msg::Start => match &self.state {
-                State::NoData | State::Error(_) | State::Ready(_) => {
-                    if let Some(input) = self.debug_log_input.cast::<HtmlInputElement>() {
-                        let _ = input.blur();
-                    }
+            LogMsg::UpdateUrl(value) => Ok(log.url.neq_assign(value)),
+            LogMsg::UpdatePlatform(platform) => Ok(log.platform.neq_assign(platform)),
+            LogMsg::Start => {
+                if let Some(input) = log.input.cast::<HtmlInputElement>() {
+                    let _ = input.blur();
+                }
+
+                let reference = log
+                    .parse_url(&self.settings.allowed_hosts())
+                    .context("failed to parse the debug log URL")?;
 
-                    self.tab = Default::default();
-                    self.pending_query = Default::default();
-                    self.active_query = Default::default();
-
-                    let reference = self
-                        .debug_log_url
-                        .trim()
-                        .to_lowercase()
-                        .parse::<RemoteObject>()
-                        .context("failed to parse the debug log URL")?;
-
-                    self.fetch(
-                        ctx,
-                        &reference.fetchable_url(),
-                        matches!(reference.platform(), Platform::Ios),
+                match &log.status {
+                    Status::Fetching {
+                        reference: fetching,
+                        ..
+                    } if *fetching == reference => return Ok(false),
+                    _ => log.status.cancel(),
+                };
+
+                log.status = Status::Fetching {
+                    controller: Self::fetch(ctx, session, side, &reference, None),
+                    reference,
+                    retries: 0,
+                    retry: None,
+                    progress: None,
+                    proxy: None,
+                };
+
+                Ok(true)
+            }
+            LogMsg::Cancel => Ok(log.status.cancel()),
+            LogMsg::UpdateProgress(update) => match &mut log.status {
+                Status::Fetching { progress, .. } => Ok(progress.neq_assign(Some(update))),
+                _ => Ok(false),
+            },
+            LogMsg::FetchError(_, FetchError::Aborted) => Ok(false),
+            LogMsg::FetchError(fetched, _) if !log.is_fetching(&fetched) => Ok(false),
+            LogMsg::FetchError(_, e) => match &mut log.status {
+                // Failures because of CORS can't be told apart from other network errors.
+                Status::Fetching {
+                    reference,
+                    controller,
+                    progress,
+                    proxy: proxy @ None,
+                    ..
+                } if matches!(e, FetchError::Network(_))
+                    && self.settings.cors_proxy().is_some() =>
+                {
+                    let template = self.settings.cors_proxy().unwrap();
+
+                    self.toaster.show(
+                        ToastKind::Info,
+                        format!("{} {}", tr(Text::FetchingThroughProxy), template),
                     );
 
-                    self.debug_log_url = reference.debuglogs_url();
-                    self.remote_object = Some(reference);
+                    *controller = Self::fetch(ctx, session, side, reference, Some(template));
+                    *progress = None;
+                    *proxy = Some(template.to_owned());
+                    Ok(true)
+                }
+                Status::Fetching { retries, retry, .. }
+                    if e.is_transient() && *retries < fetch::MAX_RETRIES =>
+                {
+                    let delay = fetch::retry_delay_ms(*retries);
+                    *retries += 1;
+
+                    self.toaster.show(
+                        ToastKind::Info,
+                        format!(
+                            "{} ({}/{})",
+                            tr(Text::FetchRetried),
+                            retries,
+                            fetch::MAX_RETRIES
+                        ),
+                    );
 
-                    Ok(self.state.neq_assign(State::Fetching))
+                    let link = ctx.link().clone();
+                    *retry = Some(Timeout::new(delay, move || {
+                        link.send_message(Msg::SessionLog(session, side, LogMsg::Retry))
+                    }));
+                    Ok(true)
+                }
+                _ => Err(anyhow::Error::from(e).context("couldn't fetch the debug log")),
+            },
+            LogMsg::Retry => match &mut log.status {
+                Status::Fetching {
+                    reference,
+                    controller,
+                    retry,
+                    progress,
+                    proxy,
+                    ..
+                } => {
+                    *controller = Self::fetch(ctx, session, side, reference, proxy.as_deref());
+                    *retry = None;
+                    *progress = None;
+                    Ok(true)
                 }
                 _ => Ok(false),
             },
-            Msg::FetchError(e) => Err(e),
-            Msg::FinishedFetchText(text) => {
-                let file = File::from_text(self.remote_object.clone().unwrap(), None, text);
+            LogMsg::DismissError => match log.status {
+                Status::Failed(_) => {
+                    log.status = Status::Idle;
+                    Ok(true)
+                }
+                _ => Ok(false),
+            },
+            LogMsg::LoadCached(fetched, body) => {
+                if !log.is_fetching(&fetched) {
+                    return Ok(false);
+                }
 
-                Ok(self.state.neq_assign(State::Ready(Object::Single(file))))
+                self.toaster
+                    .show(ToastKind::Info, tr(Text::LoadedFromCache));
+                self.update_log(ctx, side, LogMsg::FinishedFetch(fetched, body))
             }
-            Msg::FinishedFetchBinary(bytes) => {
-                let mut zip = ZipArchive::new(Cursor::new(bytes.as_slice()))
-                    .context("couldn't read the debug log file as a `zip`")?;
+            LogMsg::FinishedFetch(fetched, body) => {
+                let received = match &log.status {
+                    Status::Fetching { progress, .. } => progress.map(|progress| progress.received),
+                    _ => None,
+                };
+                let reference = match log.finish_fetch(&fetched) {
+                    Some(reference) => reference,
+                    None => return Ok(false),
+                };
 
-                let mut files = BTreeMap::new();
+                let (reference, body) = body.detect_platform(reference);
+                log.loading = LoadMetrics {
+                    received,
+                    ..Default::default()
+                };
 
-                for i in 0..zip.len() {
-                    let mut file = zip.by_index(i)?;
+                let size = body.size()?;
+                if let Some(limit) = self.settings.large_log_limit_bytes() {
+                    if size > limit {
+                        log.status = Status::Oversized {
+                            reference,
+                            body,
+                            size,
+                        };
+                        return Ok(true);
+                    }
+                }
 
-                    let name = Rc::new(
-                        file.name()
-                            .parse::<LogFilename>()
-                            .context("couldn't parse a file's name")?,
+                if Self::load(ctx, session, side, log, reference, body, None)? {
+                    self.finish_loading(side);
+                }
+                Ok(true)
+            }
+            LogMsg::LoadOversized(only_tail) => {
+                let (reference, body) = match std::mem::take(&mut log.status) {
+                    Status::Oversized {
+                        reference, body, ..
+                    } => (reference, body),
+                    status => {
+                        log.status = status;
+                        return Ok(false);
+                    }
+                };
+
+                let max_file_size = if only_tail {
+                    self.settings.large_log_limit_bytes()
+                } else {
+                    None
+                };
+
+                if Self::load(ctx, session, side, log, reference, body, max_file_size)? {
+                    self.finish_loading(side);
+                }
+                Ok(true)
+            }
+            LogMsg::Extracted(output) => {
+                let (reference, extractor, count, extracted, mut skipped) =
+                    match std::mem::take(&mut log.status) {
+                        Status::Extracting {
+                            reference,
+                            extractor,
+                            count,
+                            extracted,
+                            skipped,
+                        } => (reference, extractor, count, extracted, skipped),
+                        status => {
+                            log.status = status;
+                            return Ok(false);
+                        }
+                    };
+
+                let entry = output
+                    .map_err(anyhow::Error::msg)
+                    .context("couldn't read the debug log file as a `zip`")?;
+
+                // Until a file is shown, the previous debug log (if any) stays.
+                let nothing_shown = skipped.len() == extracted;
+
+                let extracted_file = entry.file.map(|file| {
+                    let started = js_sys::Date::now();
+                    let parse_ms = file.parse_ms;
+                    let (name, file) = file.into_file(
+                        reference.clone(),
+                        side.entry_id_prefix(Some(&format!("file-{}", entry.index))),
                     );
+                    log.loading.parse_ms += parse_ms + (js_sys::Date::now() - started);
+                    log.loading.size += file.summary().size;
+                    (name, file)
+                });
 
-                    let mut bytes: Vec<u8> = vec![];
-                    io::copy(&mut file, &mut bytes)
-                        .context("couldn't copy a log file into a `Vec<u8>`")?;
-                    let text = String::from_utf8(bytes)
-                        .context("couldn't turn a `Vec<u8>` into a `String`")?;
+                let shown_first = match extracted_file {
+                    Ok((name, file)) if nothing_shown => {
+                        log.show(
+                            side,
+                            reference.clone(),
+                            Object::Multiple {
+                                files: BTreeMap::from([(Rc::clone(&name), file)]),
+                                active_filenames: BTreeSet::from([name]),
+                                combined: None,
+                            },
+                        );
+                        true
+                    }
+                    Ok((name, file)) => {
+                        log.add_file(name, file);
+                        false
+                    }
+                    Err(name) => {
+                        skipped.push(name);
+                        false
+                    }
+                };
 
-                    files.insert(
-                        Rc::clone(&name),
-                        File::from_text(self.remote_object.clone().unwrap(), Some(name), text),
+                if extracted + 1 < count {
+                    log.status = Status::Extracting {
+                        reference,
+                        extractor,
+                        count,
+                        extracted: extracted + 1,
+                        skipped,
+                    };
+                } else {
+                    ensure!(
+                        skipped.len() < count,
+                        "no log files in zip, only: {}",
+                        skipped.join(", ")
                     );
-                }
 
-                ensure!(!files.is_empty(), "no files in zip"); // TODO: maybe should just be a notice instead of an error
+                    if !skipped.is_empty() {
+                        self.toaster.show(
+                            ToastKind::Info,
+                            format!("{} {}", tr(Text::SkippedArchiveEntries), skipped.join(", ")),
+                        );
+                    }
 
-                let last_for_app_id = |app_id| files.keys().filter(|k| k.app_id == app_id).last();
-                let active_filename =
-                    Rc::clone(last_for_app_id(AppId::Signal).unwrap_or_else(|| {
-                        last_for_app_id(AppId::NotificationServiceExtension)
-                            .unwrap_or_else(|| last_for_app_id(AppId::ShareAppExtension).unwrap())
-                    }));
+                    log.metrics = Some(log.loading);
+                }
 
-                Ok(self.state.neq_assign(State::Ready(Object::Multiple {
-                    files,
-                    active_filename,
-                })))
+                if shown_first {
+                    self.finish_loading(side);
+                }
+                Ok(true)
             }
-            Msg::UpdateActiveFile(filename) => Ok(
-                if let State::Ready(Object::Multiple {
-                    active_filename, ..
-                }) = &mut self.state
-                {
-                    active_filename.neq_assign(filename)
+            LogMsg::UpdateActiveFiles(filenames) => Ok(match &mut log.state {
+                State::Ready(Object::Multiple {
+                    files,
+                    active_filenames,
+                    combined,
+                }) if !filenames.is_empty() && filenames != *active_filenames => {
+                    *combined = if filenames.len() > 1 {
+                        Some(Box::new(File::combined(
+                            log.remote_object.clone().unwrap(),
+                            files.iter().filter(|(name, _)| filenames.contains(*name)),
+                            side.entry_id_prefix(Some("combined")),
+                        )))
+                    } else {
+                        None
+                    };
+                    *active_filenames = filenames;
+
+                    true
+                }
+                _ => false,
+            }),
+            LogMsg::UpdateAnnotation(id, note) => {
+                if note.trim().is_empty() {
+                    log.annotations.remove(&id);
                 } else {
-                    false
-                },
-            ),
-            Msg::UpdateTab(tab) => Ok(self.tab.neq_assign(tab)),
-            Msg::UpdateMinLogLevel(value) => Ok(self
-                .pending_query
-                .min_log_level
-                .neq_assign(value.parse().unwrap())),
-            Msg::UpdateQuery(value) => Ok(self.pending_query.string.neq_assign(value)),
+                    log.annotations.insert(id, note);
+                }
+
+                if self.settings.remember_annotations {
+                    log.save_annotations(side);
+                }
+
+                Ok(true)
+            }
+            LogMsg::ShowEntry(filename, id) => {
+                self.update_log(
+                    ctx,
+                    side,
+                    LogMsg::UpdateActiveFiles(BTreeSet::from([filename])),
+                )?;
+
+                self.search_all_files = false;
+                self.scroll_to_entry = Some(id);
+
+                Ok(true)
+            }
+            LogMsg::SwitchFile(step) => {
+                let filename = match &log.state {
+                    State::Ready(Object::Multiple {
+                        files,
+                        active_filenames,
+                        ..
+                    }) => {
+                        let filenames = files.keys().collect::<Vec<_>>();
+                        let active = filenames
+                            .iter()
+                            .position(|filename| active_filenames.contains(*filename))
+                            .unwrap_or_default();
+
+                        Rc::clone(
+                            filenames[(active as isize + step).rem_euclid(filenames.len() as isize)
+                                as usize],
+                        )
+                    }
+                    _ => return Ok(false),
+                };
+
+                self.update_log(
+                    ctx,
+                    side,
+                    LogMsg::UpdateActiveFiles(BTreeSet::from([filename])),
+                )
+            }
+            LogMsg::ClickEntry(id, mode) => {
+                match mode {
+                    SelectionMode::Single => {
+                        self.selected_entries = BTreeSet::from([id.clone()]);
+                    }
+                    SelectionMode::Range => {
+                        let ids = match log.state {
+                            State::Ready(_) => log
+                                .active_file()
+                                .displayed_entry_levels(
+                                    &self.active_query,
+                                    &self.collapsed_log_sections,
+                                    self.newest_first,
+                                )
+                                .into_iter()
+                                .map(|(id, _)| id)
+                                .collect::<Vec<_>>(),
+                            _ => return Ok(false),
+                        };
+
+                        let clicked = ids.iter().position(|other| *other == id);
+                        let anchor = self
+                            .selected_entry
+                            .as_ref()
+                            .and_then(|selected| ids.iter().position(|other| other == selected))
+                            .or(clicked);
+
+                        if let (Some(anchor), Some(clicked)) = (anchor, clicked) {
+                            self.selected_entries = ids[anchor.min(clicked)..=anchor.max(clicked)]
+                                .iter()
+                                .cloned()
+                                .collect();
+                        }
+
+                        return Ok(true);
+                    }
+                    SelectionMode::Toggle => {
+                        if !self.selected_entries.remove(&id) {
+                            self.selected_entries.insert(id.clone());
+                        }
+                    }
+                }
+
+                self.selected_entry = Some(id);
+                Ok(true)
+            }
+            LogMsg::ShowLine(line) => {
+                if !log.state.is_ready() {
+                    return Ok(false);
+                }
+
+                match log.active_file().entry_id_at_line(line) {
+                    Some(id) => {
+                        self.tab = Tab::Logs;
+                        self.scroll_to_entry = Some(id);
+                        Ok(true)
+                    }
+                    None => Ok(false),
+                }
+            }
+        }
+    }
+
+    fn update_inner(&mut self, msg: <Self as Component>::Message) -> bool {
+        match msg {
+            Msg::Log(..) | Msg::SessionLog(..) | Msg::ShowToast(..) | Msg::ClearCache => {
+                unreachable!("handled in `update`")
+            }
+            Msg::DismissToast(id) => {
+                let count = self.toasts.len();
+                self.toasts.retain(|toast| toast.id != id);
+                self.toasts.len() != count
+            }
+            Msg::OpenSession => {
+                let id = self.new_session_id();
+                self.open_session(id);
+                true
+            }
+            Msg::SwitchSession(id) => {
+                if id == self.session_id || !self.sessions.contains_key(&id) {
+                    return false;
+                }
+
+                self.open_session(id);
+                self.show_permalink_entry();
+                true
+            }
+            Msg::CloseSession(id) => {
+                if id == self.session_id {
+                    let next = self
+                        .sessions
+                        .range(id..)
+                        .next()
+                        .or_else(|| self.sessions.range(..id).next_back())
+                        .map(|(next, _)| *next)
+                        .unwrap_or_else(|| self.new_session_id());
+                    self.open_session(next);
+                }
+
+                if let Some(mut session) = self.sessions.remove(&id) {
+                    session.cancel();
+                }
+                true
+            }
+            Msg::UpdateComparison => {
+                self.comparison = match self.comparison.take() {
+                    Some(mut comparison) => {
+                        comparison.status.cancel();
+                        None
+                    }
+                    None => Some(Default::default()),
+                };
+                true
+            }
+            Msg::UpdateTab(tab) => self.tab.neq_assign(tab),
+            Msg::UpdateMinLogLevel(value) => match value.parse() {
+                Ok(level) => self.pending_query.min_log_level.neq_assign(level),
+                Err(_) => false,
+            },
+            Msg::UpdateQuery(value) => self.pending_query.string.neq_assign(value),
+            Msg::UpdateTagFilter(value) => self.pending_query.tag.neq_assign(value),
+            Msg::UpdateThreadFilter(value) => self.pending_query.thread.neq_assign(value),
+            Msg::UpdateProcessFilter(value) => self.pending_query.process.neq_assign(value),
+            Msg::UpdateSectionScope(value) => self.pending_query.section.neq_assign(value),
             Msg::UpdateUiExpanded => {
                 self.ui_expanded = !self.ui_expanded;
-                Ok(true)
+                true
+            }
+            Msg::UpdateRedaction => {
+                self.redact = !self.redact;
+                true
+            }
+            Msg::UpdateSensitiveHighlighting => {
+                self.highlight_sensitive = !self.highlight_sensitive;
+                true
+            }
+            Msg::UpdateSettings(settings) => self.update_settings(|current| *current = settings),
+            Msg::UpdateSettingsShown => {
+                self.settings_shown = !self.settings_shown;
+                true
+            }
+            Msg::UpdateCacheUsage(usage) => self.cache_usage.neq_assign(usage),
+            Msg::UpdateUrlLinking => {
+                self.linkify_urls = !self.linkify_urls;
+                true
+            }
+            Msg::UpdateSortOrder => {
+                self.newest_first = !self.newest_first;
+                true
+            }
+            Msg::UpdateContextLines(value) => match value.parse::<usize>() {
+                Ok(lines) => self.update_settings(|settings| {
+                    settings.context_lines = lines.min(MAX_CONTEXT_LINES);
+                }),
+                Err(_) => false,
+            },
+            Msg::UpdateAllowedHosts(value) => {
+                self.update_settings(|settings| settings.allowed_hosts = value)
+            }
+            Msg::RemoveFromHistory(url) => {
+                self.history.remove(&url);
+                self.history.save();
+                true
+            }
+            Msg::UpdateHistoryLabel(url, label) => {
+                self.history.set_label(&url, label);
+                self.history.save();
+                true
+            }
+            Msg::UpdateCorsProxy(value) => {
+                self.update_settings(|settings| settings.cors_proxy = value)
+            }
+            Msg::UpdateEntryNumber(value) => self.pending_entry_number.neq_assign(value),
+            Msg::ApplyEntryNumber => {
+                let id = match (&self.main.state, self.pending_entry_number.trim().parse()) {
+                    (State::Ready(_), Ok(number)) => {
+                        self.main.active_file().entry_id_by_number(number)
+                    }
+                    _ => None,
+                };
+
+                match id {
+                    Some(id) => self.show_entry(id),
+                    None => {
+                        self.toaster
+                            .show(ToastKind::Error, tr(Text::NoSuchEntry).to_owned());
+                        false
+                    }
+                }
+            }
+            Msg::UpdateRawLine(value) => self.pending_raw_line.neq_assign(value),
+            Msg::GoToLine(line) => {
+                self.tab = Tab::Raw;
+                self.raw_line = Some(line);
+                true
+            }
+            Msg::ApplyRawLine => match self.pending_raw_line.trim().parse() {
+                Ok(line) => self.update_inner(Msg::GoToLine(line)),
+                Err(_) => false,
+            },
+            Msg::UpdateRawQuery(value) => self.pending_raw_query.neq_assign(value),
+            Msg::ApplyRawQuery => {
+                if self.pending_raw_query == self.active_raw_query {
+                    self.update_inner(Msg::MoveRawMatch(1))
+                } else {
+                    self.active_raw_query = self.pending_raw_query.clone();
+                    self.raw_match = 0;
+                    true
+                }
+            }
+            Msg::MoveRawMatch(step) => {
+                self.raw_match += step;
+                true
+            }
+            Msg::UpdateSearchAllFiles => {
+                self.search_all_files = !self.search_all_files;
+                true
+            }
+            Msg::UpdateInfoFilter(value) => self.info_filter.neq_assign(value),
+            Msg::JumpToSection(id) => {
+                self.scroll_to_entry = Some(id);
+                true
+            }
+            Msg::ShowElement(tab, id) => {
+                self.tab = tab;
+                self.scroll_to_entry = Some(id);
+                true
+            }
+            Msg::SelectEntry(jump, min_level) => {
+                if !self.main.state.is_ready() {
+                    return false;
+                }
+
+                let entries = self.main.active_file().displayed_entry_levels(
+                    &self.active_query,
+                    &self.collapsed_log_sections,
+                    self.newest_first,
+                );
+                let selected = self
+                    .selected_entry
+                    .as_ref()
+                    .and_then(|selected| entries.iter().position(|(id, _)| id == selected));
+
+                let mut candidates = entries
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (_, level))| *level >= min_level);
+
+                let id = match (jump, selected) {
+                    (EntryJump::First, _) | (EntryJump::Next, None) => candidates.next(),
+                    (EntryJump::Next, Some(selected)) => {
+                        candidates.find(|(index, _)| *index > selected)
+                    }
+                    (EntryJump::Previous, Some(selected)) => {
+                        candidates.rev().find(|(index, _)| *index < selected)
+                    }
+                    (EntryJump::Previous, None) => candidates.next_back(),
+                }
+                .map(|(_, (id, _))| id.clone());
+
+                match id {
+                    Some(id) => {
+                        self.tab = Tab::Logs;
+                        self.selected_entry = Some(id.clone());
+                        self.selected_entries = BTreeSet::from([id.clone()]);
+                        self.scroll_to_entry = Some(id);
+                        true
+                    }
+                    None => false,
+                }
+            }
+            Msg::FocusSearch => {
+                if let Some(input) = self.search_input.cast::<HtmlInputElement>() {
+                    let _ = input.focus();
+                }
+
+                false
+            }
+            Msg::UpdateShortcutsShown => {
+                self.shortcuts_shown = !self.shortcuts_shown;
+                true
+            }
+            Msg::ShowEntryDetails(side, id) => self.entry_details.neq_assign(Some((side, id))),
+            Msg::CloseModal => {
+                self.shortcuts_shown.neq_assign(false)
+                    | self.settings_shown.neq_assign(false)
+                    | self.entry_details.neq_assign(None)
+            }
+            Msg::SetInfoGroupsCollapsed(ids, collapsed) => {
+                for id in ids {
+                    if collapsed {
+                        self.collapsed_info_groups.insert(id);
+                    } else {
+                        self.collapsed_info_groups.remove(&id);
+                    }
+                }
+
+                true
+            }
+            Msg::SetLogSectionsCollapsed(names, collapsed) => {
+                for name in names {
+                    if collapsed {
+                        self.collapsed_log_sections.insert(name);
+                    } else {
+                        self.collapsed_log_sections.remove(&name);
+                    }
+                }
+
+                true
+            }
+            Msg::ToggleEntryContext(id) => {
+                if !self.context_entries.remove(&id) {
+                    self.context_entries.insert(id);
+                }
+
+                true
+            }
+            Msg::ToggleBookmark(id) => {
+                if !self.bookmarks.remove(&id) {
+                    self.bookmarks.insert(id);
+                }
+
+                true
+            }
+            Msg::ToggleLogColumn(column) => {
+                self.update_settings(|settings| settings.toggle_log_column(column))
+            }
+            Msg::ResizeLogColumn(column, width) => match width {
+                Some(width) => self.log_column_widths.insert(column, width) != Some(width),
+                None => self.log_column_widths.remove(&column).is_some(),
+            },
+            Msg::UpdateMessageWrapping => self.update_settings(|settings| {
+                settings.wrap_log_messages = !settings.wrap_log_messages;
+            }),
+            Msg::EditAnnotation(id) => self.editing_annotation.neq_assign(id),
+            Msg::UpdateAnnotationRemembering => {
+                self.update_settings(|settings| {
+                    settings.remember_annotations = !settings.remember_annotations;
+                });
+
+                for (side, log) in self.logs() {
+                    if self.settings.remember_annotations {
+                        log.save_annotations(side);
+                    } else {
+                        LocalStorage::delete(log.annotations_storage_key());
+                    }
+                }
+
+                true
+            }
+            Msg::TogglePinnedKey(key) => {
+                if !self.pinned_keys.remove(&key) {
+                    self.pinned_keys.insert(key);
+                }
+
+                let _ = LocalStorage::set(PINNED_KEYS_STORAGE_KEY, &self.pinned_keys);
+
+                true
+            }
+            Msg::ApplySearchQuery => self.active_query.neq_assign(self.pending_query.clone()),
+            Msg::Print => {
+                self.print_requested = true;
+                self.update_printing(true);
+                true
+            }
+            Msg::UpdatePrinting(printing) => self.update_printing(printing),
+            Msg::ReportEntries(resolve) => {
+                let json = match &self.main.state {
+                    State::Ready(_) => self
+                        .main
+                        .active_file()
+                        .filtered_entries_json(&self.active_query),
+                    State::NoData => "[]".to_owned(),
+                };
+                page_api::resolve_entries(&resolve, &json);
+                false
             }
-            Msg::ApplySearchQuery => Ok(self.active_query.neq_assign(self.pending_query.clone())),
         }
     }
 }
@@ -0,0 +1,250 @@
+use chrono::{DateTime, Duration, Utc};
+use derive_more::Display;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+use yew::prelude::*;
+
+use crate::{
+    components::{Button, ButtonSize, Message},
+    format_delta, i18n,
+    parsers::{Content, LogEntry},
+    tr, Tab, Text,
+};
+
+/// Parts of (lowercase) log messages that mark a push received through FCM.
+const FCM_PATTERNS: [&str; 2] = ["onmessagereceived", "fcm message"];
+/// Parts of (lowercase) log messages that mark a push received through APNs.
+const APNS_PATTERNS: [&str; 2] = ["didreceiveremotenotification", "apns push"];
+/// Parts of (lowercase) log messages that mark a message received through the websocket.
+const WEBSOCKET_PATTERNS: [&str; 2] = ["websocket received", "received websocket message"];
+/// Parts of (lowercase) log messages that mark a received message as processed.
+const PROCESSED_PATTERNS: [&str; 4] = [
+    "processed message",
+    "message processed",
+    "processed envelope",
+    "finished processing",
+];
+
+#[derive(Debug, Display, Clone, Copy, PartialEq, EnumIter)]
+pub enum PushKind {
+    #[display(fmt = "FCM")]
+    Fcm,
+    #[display(fmt = "APNs")]
+    Apns,
+    #[display(fmt = "WebSocket")]
+    WebSocket,
+}
+
+/// A push that was received, and how long it took until a message was processed after it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PushDelivery {
+    pub kind: PushKind,
+    /// Element ID of the log entry of the push.
+    pub entry_id: String,
+    pub received: DateTime<Utc>,
+    /// `None` if no message was processed before the next push.
+    pub delay: Option<Duration>,
+}
+
+impl PushDelivery {
+    /// All pushes in the log entries of `content`, in the order they were received (regardless
+    /// of the sections they are in).
+    pub fn all(content: &Content) -> Vec<PushDelivery> {
+        let mut output: Vec<PushDelivery> = vec![];
+
+        for entry in content.log_entries_by_time() {
+            if let Some(kind) = Self::kind(entry) {
+                output.push(PushDelivery {
+                    kind,
+                    entry_id: entry.element_id(),
+                    received: entry.timestamp,
+                    delay: None,
+                });
+            } else if Self::is_processed(entry) {
+                if let Some(last) = output.last_mut().filter(|last| last.delay.is_none()) {
+                    last.delay = Some(entry.timestamp - last.received);
+                }
+            }
+        }
+
+        output
+    }
+
+    fn kind(entry: &LogEntry) -> Option<PushKind> {
+        let message = entry.message.to_lowercase();
+        let matches = |patterns: &[&str]| patterns.iter().any(|pattern| message.contains(pattern));
+
+        if matches(&FCM_PATTERNS) {
+            Some(PushKind::Fcm)
+        } else if matches(&APNS_PATTERNS) {
+            Some(PushKind::Apns)
+        } else if matches(&WEBSOCKET_PATTERNS) {
+            Some(PushKind::WebSocket)
+        } else {
+            None
+        }
+    }
+
+    fn is_processed(entry: &LogEntry) -> bool {
+        let message = entry.message.to_lowercase();
+        PROCESSED_PATTERNS
+            .iter()
+            .any(|pattern| message.contains(pattern))
+    }
+}
+
+/// Delays of the pushes of one kind.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeliveryStats {
+    pub kind: PushKind,
+    pub count: usize,
+    /// Number of pushes after which a message was processed.
+    pub processed: usize,
+    pub median_delay: Option<Duration>,
+    /// Longest delay, with the element ID of the log entry of its push.
+    pub max_delay: Option<(Duration, String)>,
+}
+
+impl DeliveryStats {
+    /// Statistics of every kind of push that was received.
+    pub fn all(deliveries: &[PushDelivery]) -> Vec<DeliveryStats> {
+        PushKind::iter()
+            .filter_map(|kind| {
+                let deliveries = deliveries
+                    .iter()
+                    .filter(|delivery| delivery.kind == kind)
+                    .collect::<Vec<_>>();
+                if deliveries.is_empty() {
+                    return None;
+                }
+
+                let mut delays = deliveries
+                    .iter()
+                    .filter_map(|delivery| Some((delivery.delay?, &delivery.entry_id)))
+                    .collect::<Vec<_>>();
+                delays.sort_by_key(|(delay, _)| *delay);
+
+                Some(DeliveryStats {
+                    kind,
+                    count: deliveries.len(),
+                    processed: delays.len(),
+                    median_delay: delays.get(delays.len() / 2).map(|(delay, _)| *delay),
+                    max_delay: delays.last().map(|(delay, id)| (*delay, (*id).clone())),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Formats a delay like `2.4s`.
+fn format_delay(delay: Duration) -> String {
+    format_delta(delay).trim_start_matches('+').to_owned()
+}
+
+/// Delivery statistics, with buttons that call `on_show` with the tab and element ID of the push
+/// with the longest delay.
+pub fn view_delivery_stats(stats: &[DeliveryStats], on_show: &Callback<(Tab, String)>) -> Html {
+    if stats.is_empty() {
+        return html! {};
+    }
+
+    let view_stats = |stats: &DeliveryStats| {
+        let delays = match (&stats.median_delay, &stats.max_delay) {
+            (Some(median), Some((max, id))) => {
+                let id = id.clone();
+
+                html! {
+                    <>
+                        { i18n::push_delays(&format_delay(*median), &format_delay(*max)) }
+                        <Button
+                            classes={classes!("rounded-2xl", "ml-2")}
+                            size={ButtonSize::Small}
+                            icon={classes!("fas", "fa-stream")}
+                            text={tr(Text::ShowSlowest)}
+                            on_click={on_show.reform(move |_| (Tab::Logs, id.clone()))}
+                        />
+                    </>
+                }
+            }
+            _ => html! {},
+        };
+
+        html! {
+            <p>
+                <strong>{ format!("{}: ", stats.kind) }</strong>
+                { i18n::push_counts(stats.count, stats.processed) }
+                { delays }
+            </p>
+        }
+    };
+
+    html! {
+        <Message classes={classes!("mb-4")} heading={tr(Text::NotificationDelivery)}>
+            { for stats.iter().map(view_stats) }
+        </Message>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use test_case::test_case;
+
+    use crate::parsers::Section;
+
+    use super::*;
+
+    #[test_case("onMessageReceived() ID: 123, Delay: 10" => Some(PushKind::Fcm); "fcm")]
+    #[test_case("application:didReceiveRemoteNotification:" => Some(PushKind::Apns); "apns")]
+    #[test_case("WebSocket received request" => Some(PushKind::WebSocket); "websocket")]
+    #[test_case("Processed message 123" => None; "processed")]
+    fn kind_ok(message: &str) -> Option<PushKind> {
+        PushDelivery::kind(&LogEntry::test(message, 0))
+    }
+
+    #[test]
+    fn all_ok() {
+        let entry = |start: usize, millis: i64, message: &str| LogEntry {
+            timestamp: Utc.timestamp_millis(millis),
+            ..LogEntry::test(message, start)
+        };
+
+        let content = Content {
+            information: vec![],
+            logs: vec![Section {
+                name: "LOGCAT".to_owned(),
+                content: vec![
+                    entry(0, 1000, "onMessageReceived()"),
+                    entry(10, 1500, "Processed message 1"),
+                    entry(20, 1700, "Processed message 2"),
+                    entry(30, 2000, "onMessageReceived()"),
+                    entry(40, 3000, "onMessageReceived()"),
+                    entry(50, 7000, "Processed message 3"),
+                    entry(60, 8000, "onMessageReceived()"),
+                    entry(70, 8200, "Finished processing"),
+                ],
+                subsections: vec![],
+            }],
+        };
+
+        let deliveries = PushDelivery::all(&content);
+        assert_eq!(
+            deliveries
+                .iter()
+                .map(|delivery| delivery.delay.map(|delay| delay.num_milliseconds()))
+                .collect::<Vec<_>>(),
+            vec![Some(500), None, Some(4000), Some(200)]
+        );
+
+        assert_eq!(
+            DeliveryStats::all(&deliveries),
+            vec![DeliveryStats {
+                kind: PushKind::Fcm,
+                count: 4,
+                processed: 3,
+                median_delay: Some(Duration::milliseconds(500)),
+                max_delay: Some((Duration::milliseconds(4000), "entry-40".to_owned())),
+            }]
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use js_sys::{Function, Object, Promise, Reflect};
+use wasm_bindgen::{closure::Closure, JsValue};
+use yew::html::Scope;
+
+use crate::{LogMsg, Model, Msg, Side};
+
+/// Sets `window.readlogs`, through which the page can be driven from bookmarklets, or from other
+/// tools that embed it in an `iframe`:
+/// - `load(url)` loads the debug log from the URL,
+/// - `setQuery(query)` searches the log entries for the text,
+/// - `getEntries()` returns a promise of the log entries of the active file that match the search.
+pub fn register(link: &Scope<Model>) -> Result<(), JsValue> {
+    let window = web_sys::window().ok_or("no window")?;
+    let api = Object::new();
+
+    let load_link = link.clone();
+    let load = Closure::wrap(Box::new(move |url: String| {
+        load_link.send_message_batch(vec![
+            Msg::Log(Side::Main, LogMsg::UpdateUrl(url)),
+            Msg::Log(Side::Main, LogMsg::Start),
+        ]);
+    }) as Box<dyn Fn(String)>);
+
+    let query_link = link.clone();
+    let set_query = Closure::wrap(Box::new(move |query: String| {
+        query_link.send_message_batch(vec![Msg::UpdateQuery(query), Msg::ApplySearchQuery]);
+    }) as Box<dyn Fn(String)>);
+
+    let entries_link = link.clone();
+    let get_entries = Closure::wrap(Box::new(move || {
+        Promise::new(&mut |resolve: Function, _| {
+            entries_link.send_message(Msg::ReportEntries(resolve));
+        })
+    }) as Box<dyn Fn() -> Promise>);
+
+    // The closures live as long as the page does.
+    Reflect::set(&api, &"load".into(), &load.into_js_value())?;
+    Reflect::set(&api, &"setQuery".into(), &set_query.into_js_value())?;
+    Reflect::set(&api, &"getEntries".into(), &get_entries.into_js_value())?;
+    Reflect::set(&window, &"readlogs".into(), &api)?;
+
+    Ok(())
+}
+
+/// Resolves the promise of `getEntries()` with the log entries, serialized as JSON.
+pub fn resolve_entries(resolve: &Function, json: &str) {
+    let entries = js_sys::JSON::parse(json).unwrap_or_else(|_| js_sys::Array::new().into());
+    let _ = resolve.call1(&JsValue::NULL, &entries);
+}
@@ -0,0 +1,1427 @@
+//! The parsers of `readlogs_core`, with how the app shows what they parse.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Range,
+    rc::Rc,
+};
+
+use yew::prelude::*;
+
+use crate::{
+    components::*, diagnostics, find_urls, format_delta, i18n, line_number,
+    log_level::LogLevelView, redaction, source_links, title_id, tr, DumpedThread, FeatureFlag,
+    LogLevel, Platform, RemoteConfigChange, RenderedLogSection, SearchQuery, SelectionMode, Text,
+    MAX_CONTEXT_LINES,
+};
+
+pub use readlogs_core::parsers::*;
+
+/// Stack traces with fewer frames are shown in full.
+const MIN_STACK_TRACE_FRAMES: usize = 3;
+
+/// A section in the table of contents of a tab.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutlineEntry {
+    /// Number of parent sections.
+    pub depth: usize,
+    pub name: String,
+    /// Element ID of the title of the section.
+    pub id: String,
+    /// Number of entries in the section, including its subsections.
+    pub count: usize,
+}
+
+/// What is derived from all information sections together, made once per file rather than on
+/// every render.
+#[derive(Debug, Default)]
+pub struct InfoAnalyses {
+    pub flags: Rc<Vec<FeatureFlag>>,
+    pub config_changes: Rc<Vec<RemoteConfigChange>>,
+    pub threads: Rc<Vec<DumpedThread>>,
+}
+
+impl InfoAnalyses {
+    pub fn new(content: &Content) -> Self {
+        Self {
+            flags: Rc::new(FeatureFlag::all(content)),
+            config_changes: Rc::new(RemoteConfigChange::all(content)),
+            threads: Rc::new(DumpedThread::all(content)),
+        }
+    }
+}
+
+/// Settings that affect how information entries are rendered.
+#[derive(Debug, Clone)]
+pub struct InfoViewOptions {
+    /// Only entries whose key or value contains this are shown.
+    pub filter: String,
+    /// IDs (see `Section::group_id`) of key prefix groups that are collapsed.
+    pub collapsed_groups: BTreeSet<String>,
+    /// Called with IDs of groups and whether they should be collapsed.
+    pub on_set_groups_collapsed: Callback<(Vec<String>, bool)>,
+    /// Keys of entries that are shown in a summary at the top of every log.
+    pub pinned_keys: BTreeSet<String>,
+    pub on_toggle_pinned_key: Callback<String>,
+}
+
+/// Settings that affect how log entries are rendered.
+#[derive(Debug, Clone)]
+pub struct LogViewOptions {
+    /// Full text of the file, from which entries are copied as they were originally written;
+    /// filled in by the file that is viewed.
+    pub text: Rc<String>,
+    /// Whether identifiers should be redacted from copied entries.
+    pub redact: bool,
+    /// Whether identifiers in messages should be highlighted.
+    pub highlight_sensitive: bool,
+    /// Whether URLs in messages should be links.
+    pub linkify_urls: bool,
+    /// Called with the 1-based number of the first line of an entry, to show it in the raw text.
+    pub on_show_raw_line: Callback<usize>,
+    /// App version, to link to the matching sources; filled in by the file that is viewed.
+    pub version: Option<String>,
+    /// Prefix of the element IDs of the entries, which tells them apart from those of other files
+    /// and debug logs; filled in by the file that is viewed.
+    pub id_prefix: String,
+    /// Names of sections whose entries are hidden.
+    pub collapsed_sections: BTreeSet<String>,
+    /// Called with names of sections and whether they should be collapsed.
+    pub on_set_sections_collapsed: Callback<(Vec<String>, bool)>,
+    /// Whether the entries of every section are shown in reverse, i.e. the latest first.
+    pub newest_first: bool,
+    /// Element IDs of the selected entries, which are highlighted.
+    pub selected_entries: BTreeSet<String>,
+    /// Called with the element ID of a clicked entry, and how it changes the selection.
+    pub on_click_entry: Callback<(String, SelectionMode)>,
+    /// Element IDs of matching entries that are shown with the (unfiltered) entries around them.
+    pub context_entries: BTreeSet<String>,
+    /// Called with the element ID of a matching entry to show or hide the entries around it.
+    pub on_toggle_context: Callback<String>,
+    /// Number of entries shown before and after every matching entry.
+    pub context_lines: usize,
+    /// Element IDs of the bookmarked entries, which are kept regardless of the query.
+    pub bookmarks: BTreeSet<String>,
+    /// Called with the element ID of an entry to add it to the bookmarks, or remove it.
+    pub on_toggle_bookmark: Callback<String>,
+    /// Notes attached to entries, by element ID.
+    pub annotations: BTreeMap<String, String>,
+    /// Element ID of the entry whose note is being edited, if any.
+    pub editing_annotation: Option<String>,
+    /// Called with the element ID of an entry to edit its note, or `None` to stop editing.
+    pub on_edit_annotation: Callback<Option<String>>,
+    /// Called with the element ID of an entry and its new note; empty notes are removed.
+    pub on_update_annotation: Callback<(String, String)>,
+    /// Called with the element ID of an entry to show all of its details.
+    pub on_show_details: Callback<String>,
+    /// Columns of the table that aren't shown.
+    pub hidden_columns: BTreeSet<LogColumn>,
+    /// Called with a column of the table to hide it, or show it again.
+    pub on_toggle_column: Callback<LogColumn>,
+    /// Whether long messages wrap, instead of being scrolled horizontally.
+    pub wrap_messages: bool,
+    /// Widths of the columns of the table that were resized, in pixels.
+    pub column_widths: BTreeMap<LogColumn, u32>,
+    /// Called with a column of the table and its new width, or `None` to reset it.
+    pub on_resize_column: Callback<(LogColumn, Option<u32>)>,
+    /// Whether rows are shown without vertical padding, to fit more of them on the screen.
+    pub compact: bool,
+    pub text_size: TextSize,
+    /// Confirms that entries were copied.
+    pub toaster: Toaster,
+}
+
+impl LogViewOptions {
+    /// Element ID of the `entry` in the logs table, which the sets of element IDs refer to.
+    pub fn entry_id(&self, entry: &LogEntry) -> String {
+        format!("{}{}", self.id_prefix, entry.element_id())
+    }
+
+    /// Number of entries shown before and after a matching entry whose context was toggled on:
+    /// the most that `context_lines` can be set to, so never fewer than for every match.
+    pub fn toggled_context_lines(&self) -> usize {
+        MAX_CONTEXT_LINES.max(self.context_lines)
+    }
+}
+
+/// Why a log entry is displayed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EntryRole {
+    /// All entries of the section match the query.
+    Unfiltered,
+    /// The entry matches the query, while others don't.
+    Match,
+    /// The entry doesn't match the query, but is near a matching entry whose context is shown.
+    Context,
+}
+
+pub trait ContentView {
+    /// Summary of the pinned information entries that this log has.
+    fn view_pinned(&self, options: &InfoViewOptions) -> Html;
+
+    /// Renders the information sections, keeping only entries that match the filter, with the
+    /// `analyses` of this content.
+    fn view_information(
+        &self,
+        platform: Platform,
+        analyses: &InfoAnalyses,
+        options: &InfoViewOptions,
+    ) -> Html;
+
+    fn view_logs(&self, query: &SearchQuery, options: &LogViewOptions) -> Vec<RenderedLogSection>;
+}
+
+impl ContentView for Content {
+    fn view_pinned(&self, options: &InfoViewOptions) -> Html {
+        let entries = options
+            .pinned_keys
+            .iter()
+            .filter_map(|key| self.find_info_entry(key))
+            .collect::<Vec<_>>();
+
+        if entries.is_empty() {
+            return html! {};
+        }
+
+        // The filter only applies to the Information tab.
+        let options = InfoViewOptions {
+            filter: String::new(),
+            ..options.clone()
+        };
+
+        html! {
+            <Message classes={classes!("!max-w-none", "mb-4")} heading={tr(Text::Pinned)}>
+                <Table classes={classes!("font-mono", "text-sm")}>
+                    <tbody>
+                        { for entries.iter().map(|entry| entry.view(&options)) }
+                    </tbody>
+                </Table>
+            </Message>
+        }
+    }
+
+    fn view_information(
+        &self,
+        platform: Platform,
+        analyses: &InfoAnalyses,
+        options: &InfoViewOptions,
+    ) -> Html {
+        let filter = options.filter.as_str();
+
+        if self.information.is_empty() {
+            let text = tr(match platform {
+                Platform::Ios => Text::NoInformationSectionsIos,
+                Platform::Android | Platform::Desktop => Text::NoInformationSections,
+            });
+
+            html! {
+                <Message {text} />
+            }
+        } else {
+            let flag_explorer = if analyses.flags.is_empty() {
+                html! {}
+            } else {
+                html! {
+                    <>
+                        <Title level={TitleLevel::H2} text={tr(Text::FeatureFlags)} />
+                        <FlagExplorer flags={Rc::clone(&analyses.flags)} />
+                    </>
+                }
+            };
+
+            let change_log = if analyses.config_changes.is_empty() {
+                html! {}
+            } else {
+                html! {
+                    <>
+                        <Title level={TitleLevel::H2} text={tr(Text::RemoteConfigChanges)} />
+                        <ConfigChangeLog changes={Rc::clone(&analyses.config_changes)} />
+                    </>
+                }
+            };
+
+            let thread_viewer = if analyses.threads.is_empty() {
+                html! {}
+            } else {
+                html! {
+                    <>
+                        <Title level={TitleLevel::H2} text={tr(Text::Threads)} id={diagnostics::THREADS_ID} />
+                        <ThreadViewer threads={Rc::clone(&analyses.threads)} />
+                    </>
+                }
+            };
+
+            let group_ids = self.info_group_ids();
+
+            let group_controls = if group_ids.is_empty() {
+                html! {}
+            } else {
+                let collapse = {
+                    let group_ids = group_ids.clone();
+                    options
+                        .on_set_groups_collapsed
+                        .reform(move |_| (group_ids.clone(), true))
+                };
+                let expand = options
+                    .on_set_groups_collapsed
+                    .reform(move |_| (group_ids.clone(), false));
+
+                html! {
+                    <div class={classes!("flex", "gap-2", "my-4")}>
+                        <Button
+                            classes={classes!("rounded-2xl")}
+                            size={ButtonSize::Small}
+                            icon={classes!("fas", "fa-angle-double-down")}
+                            text={tr(Text::ExpandAll)}
+                            on_click={expand}
+                        />
+                        <Button
+                            classes={classes!("rounded-2xl")}
+                            size={ButtonSize::Small}
+                            icon={classes!("fas", "fa-angle-double-up")}
+                            text={tr(Text::CollapseAll)}
+                            on_click={collapse}
+                        />
+                    </div>
+                }
+            };
+
+            html! {
+                <>
+                    { flag_explorer }
+                    { change_log }
+                    { thread_viewer }
+                    { group_controls }
+                    {
+                        if self.information.iter().any(|section| section.matches(filter)) {
+                            html! {
+                                for self.information
+                                    .iter()
+                                    .filter(|section| section.matches(filter))
+                                    .map(|section| section.view(TitleLevel::H2, options))
+                            }
+                        } else {
+                            html! { <Message text={tr(Text::NoMatchingInformation)} /> }
+                        }
+                    }
+                </>
+            }
+        }
+    }
+
+    fn view_logs(&self, query: &SearchQuery, options: &LogViewOptions) -> Vec<RenderedLogSection> {
+        let mut first_number = 1;
+
+        self.logs
+            .iter()
+            .map(|section| {
+                let rendered = section.view(query, TitleLevel::H2, options, first_number);
+                first_number += section.iter_all().count();
+                rendered
+            })
+            .collect()
+    }
+}
+
+pub trait SectionOutline {
+    /// This section and all of its subsections as a table of contents, depth-first.
+    fn outline(&self, depth: usize) -> Vec<OutlineEntry>;
+}
+
+impl<C> SectionOutline for Section<C> {
+    fn outline(&self, depth: usize) -> Vec<OutlineEntry> {
+        std::iter::once(OutlineEntry {
+            depth,
+            name: self.name.clone(),
+            id: title_id(&self.name),
+            count: self.iter_all().count(),
+        })
+        .chain(
+            self.subsections
+                .iter()
+                .flat_map(|subsection| subsection.outline(depth + 1)),
+        )
+        .collect()
+    }
+}
+
+pub trait InfoSectionView {
+    /// Renders the section, keeping only entries and subsections that match the filter; groups
+    /// of entries are always expanded while filtering, so that no match is hidden.
+    fn view(&self, level: TitleLevel, options: &InfoViewOptions) -> Html;
+}
+
+impl InfoSectionView for Section<InfoEntry> {
+    fn view(&self, level: TitleLevel, options: &InfoViewOptions) -> Html {
+        let filter = options.filter.as_str();
+
+        let view_entries = |entries: &[&InfoEntry]| {
+            html! {
+                for entries.iter().filter(|entry| entry.matches(filter)).map(|entry| entry.view(options))
+            }
+        };
+
+        let view_group = |prefix: Option<&str>, entries: &[&InfoEntry]| {
+            let visible_count = entries.iter().filter(|entry| entry.matches(filter)).count();
+
+            let table = |entries| {
+                html! {
+                    <Table classes={classes!("font-mono", "text-sm")}>
+                        <tbody>
+                            { view_entries(entries) }
+                        </tbody>
+                    </Table>
+                }
+            };
+
+            match prefix {
+                _ if visible_count == 0 => html! {},
+                None => table(entries),
+                Some(prefix) => {
+                    let id = self.group_id(prefix);
+                    let collapsed =
+                        filter.trim().is_empty() && options.collapsed_groups.contains(&id);
+
+                    html! {
+                        <>
+                            <Button
+                                classes={classes!("rounded-2xl", "my-2")}
+                                size={ButtonSize::Small}
+                                icon={classes!("fas", if collapsed { "fa-chevron-right" } else { "fa-chevron-down" })}
+                                text={format!("{}.* ({})", prefix, visible_count)}
+                                on_click={options.on_set_groups_collapsed.reform(move |_| (vec![id.clone()], !collapsed))}
+                            />
+                            { if collapsed { html! {} } else { table(entries) } }
+                        </>
+                    }
+                }
+            }
+        };
+
+        // TODO: Assumes that all entries in the section are the same variant.
+        let wrapper = match self.content.first() {
+            _ if !self.content.iter().any(|entry| entry.matches(filter)) => html! {},
+            Some(InfoEntry::KeyValue(_, _)) | Some(InfoEntry::KeyEnabledValue(_, _, _)) => html! {
+                for self.groups().iter().map(|(prefix, entries)| view_group(*prefix, entries))
+            },
+            Some(InfoEntry::Generic(_)) => html! {
+                <CodeBlock>
+                    { view_entries(&self.content.iter().collect::<Vec<_>>()) }
+                </CodeBlock>
+            },
+            _ => view_entries(&self.content.iter().collect::<Vec<_>>()),
+        };
+
+        let full_content = if self.content.is_empty() && self.subsections.is_empty() {
+            html! {
+                <p><i>{ tr(Text::NoContentOrSubsections) }</i></p>
+            }
+        } else {
+            html! {
+                <>
+                    { wrapper }
+                    {
+                        for self.subsections
+                            .iter()
+                            .filter(|section| section.matches(filter))
+                            .map(|section| section.view(level.incremented().unwrap(), options))
+                    }
+                </>
+            }
+        };
+
+        let raw = level > TitleLevel::H2;
+
+        html! {
+            <>
+                <Title {level} text={self.name.clone()} id={title_id(&self.name)} {raw} capitalize={!raw} />
+                { full_content }
+            </>
+        }
+    }
+}
+
+pub trait InfoEntryView {
+    /// Renders the entry, marking occurrences of the filter in its key and plain value.
+    fn view(&self, options: &InfoViewOptions) -> Html;
+}
+
+impl InfoEntryView for InfoEntry {
+    fn view(&self, options: &InfoViewOptions) -> Html {
+        let filter = options.filter.as_str();
+
+        let pin_button = |key: &str| {
+            let key = key.to_owned();
+
+            html! {
+                <TableItem>
+                    <Button
+                        size={ButtonSize::Small}
+                        icon={classes!("fas", "fa-thumbtack")}
+                        active={options.pinned_keys.contains(&key)}
+                        on_click={options.on_toggle_pinned_key.reform(move |_| key.clone())}
+                    />
+                </TableItem>
+            }
+        };
+
+        let view_value = |value: &Value| match value {
+            Value::Generic(text) => view_highlighted(text, filter),
+            _ => value.view(),
+        };
+
+        match self {
+            InfoEntry::KeyValue(key, value) => html! {
+                <TableRow>
+                    { pin_button(key) }
+                    <TableItem>{ view_highlighted(key, filter) }</TableItem>
+                    <TableItem>{ view_value(value) }</TableItem>
+                </TableRow>
+            },
+            InfoEntry::KeyEnabledValue(key, enabled, value) => html! {
+                <TableRow>
+                    { pin_button(key) }
+                    <TableItem>{ view_highlighted(key, filter) }</TableItem>
+                    {
+                        // TODO: Assumes that all KV pairs in section have `enabled`
+                        //       (otherwise the table will be misaligned).
+                        html! {
+                            <TableItem>
+                                {
+                                    if *enabled {
+                                        "enabled"
+                                    } else {
+                                        "disabled"
+                                    }
+                                }
+                            </TableItem>
+                        }
+                    }
+                    <TableItem>{ view_value(&value.clone().unwrap_or_default()) }</TableItem>
+                </TableRow>
+            },
+            InfoEntry::RemoteObject(ro) => html! {
+                <Button
+                    size={ButtonSize::Medium}
+                    icon={classes!("fas", "fa-download")}
+                    text="debuglogs.org"
+                    href={ro.debuglogs_url()}
+                />
+            },
+            InfoEntry::ExplicitNone => html! { <p>{ "None" }</p> },
+            InfoEntry::GenericTable(table) => html! {
+                <Table
+                    header={table.header.clone()}
+                    rows={
+                        table
+                            .rows
+                            .iter()
+                            .map(|row| row.iter().map(TableCell::from).collect())
+                            .collect::<Vec<_>>()
+                    }
+                />
+            },
+            InfoEntry::Generic(text) => html! {
+                <>{ view_highlighted(text, filter) }{ "\n" }</>
+            },
+        }
+    }
+}
+
+/// `text` with all (ASCII case-insensitive) occurrences of `query` marked.
+fn view_highlighted(text: &str, query: &str) -> Html {
+    let query = query.trim().to_ascii_lowercase();
+
+    if query.is_empty() {
+        return html! { text.to_owned() };
+    }
+
+    let lowercase = text.to_ascii_lowercase();
+    let mut parts = vec![];
+    let mut shown_until = 0;
+
+    for (start, _) in lowercase.match_indices(&query) {
+        let end = start + query.len();
+
+        parts.push(html! { &text[shown_until..start] });
+        parts.push(html! {
+            <mark class={classes!("rounded", "bg-yellow-200", "dark:bg-yellow-700", "dark:text-brand-dark-text")}>
+                { &text[start..end] }
+            </mark>
+        });
+        shown_until = end;
+    }
+
+    parts.push(html! { &text[shown_until..] });
+
+    html! { for parts }
+}
+
+pub trait ValueView {
+    fn view(&self) -> Html;
+}
+
+impl ValueView for Value {
+    fn view(&self) -> Html {
+        match self {
+            Value::Generic(s) => html! { s },
+            Value::BucketedFlag(buckets) => html! {
+                <div class={classes!("flex", "flex-wrap", "gap-1")}>
+                    { for buckets.iter().map(Bucket::view) }
+                </div>
+            },
+            Value::List(items) => html! {
+                <div class={classes!("flex", "flex-wrap", "gap-1")}>
+                    {
+                        for items.iter().map(|item| html! {
+                            <Badge
+                                classes={classes!("bg-brand-bg-message", "dark:bg-brand-dark-bg-message", "!px-2")}
+                                text={item.clone()}
+                            />
+                        })
+                    }
+                </div>
+            },
+            Value::Json(value) => html! {
+                <div class={classes!("font-mono")}>{ view_json(None, value) }</div>
+            },
+        }
+    }
+}
+
+/// A collapsible tree of a JSON value; objects and arrays start expanded at the top level only.
+fn view_json(key: Option<&str>, value: &serde_json::Value) -> Html {
+    let top_level = key.is_none();
+    let key = match key {
+        Some(key) => html! { <span class={classes!("font-bold")}>{ format!("{}: ", key) }</span> },
+        None => html! {},
+    };
+
+    let children = match value {
+        serde_json::Value::Object(map) => map
+            .iter()
+            .map(|(key, value)| view_json(Some(key), value))
+            .collect::<Vec<_>>(),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(i, value)| view_json(Some(&i.to_string()), value))
+            .collect(),
+        _ => return html! { <div>{ key }{ value.to_string() }</div> },
+    };
+
+    let summary = match value {
+        serde_json::Value::Object(map) => format!("{{…}} ({} keys)", map.len()),
+        _ => format!("[…] ({} items)", children.len()),
+    };
+
+    html! {
+        <details open={top_level}>
+            <summary class={classes!("cursor-pointer")}>{ key }{ summary }</summary>
+            <div class={classes!("pl-4")}>{ for children }</div>
+        </details>
+    }
+}
+
+pub trait BucketView {
+    fn view(&self) -> Html;
+}
+
+impl BucketView for Bucket {
+    fn view(&self) -> Html {
+        let tooltip = if self.country_code == "*" {
+            tr(Text::AllOtherCountries).to_owned()
+        } else {
+            i18n::calling_code(&self.country_code)
+        };
+
+        let (width, text) = match self.percentage() {
+            Some(percentage) => (
+                percentage.min(100.0),
+                format!("{}%", (percentage * 100.0).round() / 100.0),
+            ),
+            None => (0.0, self.value.clone()),
+        };
+
+        html! {
+            <span
+                class={classes!("inline-flex", "items-center", "gap-2", "rounded-2xl", "px-3", "py-1", "bg-brand-bg-message", "dark:bg-brand-dark-bg-message")}
+                title={tooltip}
+            >
+                <span class={classes!("font-bold")}>{ &self.country_code }</span>
+                <span class={classes!("w-12", "h-2", "rounded", "bg-brand-bg", "dark:bg-brand-dark-bg")}>
+                    <span
+                        class={classes!("block", "h-2", "rounded", "bg-brand-primary-hover", "dark:bg-brand-dark-primary-hover")}
+                        style={format!("width: {}%", width)}
+                    />
+                </span>
+                <span>{ text }</span>
+            </span>
+        }
+    }
+}
+
+pub trait LogSectionView {
+    /// The section's entries and subsections; its first entry has the `first_number` (see
+    /// [`Content::log_entry_by_number`]).
+    fn view(
+        &self,
+        query: &SearchQuery,
+        level: TitleLevel,
+        options: &LogViewOptions,
+        first_number: usize,
+    ) -> RenderedLogSection;
+}
+
+impl LogSectionView for Section<LogEntry> {
+    fn view(
+        &self,
+        query: &SearchQuery,
+        level: TitleLevel,
+        options: &LogViewOptions,
+        first_number: usize,
+    ) -> RenderedLogSection {
+        view_scoped(
+            self,
+            query,
+            level,
+            options,
+            query.section.is_empty(),
+            first_number,
+        )
+    }
+}
+
+/// Like [`LogSectionView::view`]; entries are only displayed if the `section` is `in_scope` of
+/// the query because of one of its parents, or if it's named like the scope itself.
+fn view_scoped(
+    section: &Section<LogEntry>,
+    query: &SearchQuery,
+    level: TitleLevel,
+    options: &LogViewOptions,
+    in_scope: bool,
+    first_number: usize,
+) -> RenderedLogSection {
+    let in_scope = in_scope || section.name == query.section;
+    let matches = if in_scope {
+        section.filtered_indices(query).collect::<Vec<_>>()
+    } else {
+        vec![]
+    };
+    let collapsed = options.collapsed_sections.contains(&section.name);
+
+    let displayed_count = matches.len();
+    let show_process = section.shows_process();
+    let total_count = section.content.len();
+
+    // Indices of the displayed entries in `content`, with why they're displayed
+    let mut groups = if displayed_count == total_count {
+        vec![(0..total_count)
+            .map(|index| (index, EntryRole::Unfiltered))
+            .collect::<Vec<_>>()]
+    } else {
+        context_groups(&matches, total_count, |index| {
+            if options
+                .context_entries
+                .contains(&options.entry_id(&section.content[index]))
+            {
+                options.toggled_context_lines()
+            } else {
+                options.context_lines
+            }
+        })
+        .into_iter()
+        .map(|group| {
+            group
+                .into_iter()
+                .map(|(index, is_match)| {
+                    let role = if is_match {
+                        EntryRole::Match
+                    } else {
+                        EntryRole::Context
+                    };
+
+                    (index, role)
+                })
+                .collect()
+        })
+        .collect()
+    };
+    if options.newest_first {
+        groups.reverse();
+        groups.iter_mut().for_each(|group| group.reverse());
+    }
+
+    let has_context =
+        |group: &Vec<(usize, EntryRole)>| group.iter().any(|(_, role)| *role == EntryRole::Context);
+    let rows = groups.iter().enumerate().map(|(i, group)| {
+        // Like `grep -C`, groups with context are separated from the others
+        let separator = if i > 0 && (has_context(group) || has_context(&groups[i - 1])) {
+            html! {
+                <tr>
+                    <td colspan="100" class={classes!("text-center", "font-sans", "opacity-60")}>{ "⋯" }</td>
+                </tr>
+            }
+        } else {
+            html! {}
+        };
+
+        html! {
+            <>
+                { separator }
+                {
+                    for group.iter().map(|(index, role)| {
+                        let entry = &section.content[*index];
+                        let delta = index
+                            .checked_sub(1)
+                            .map(|previous| entry.timestamp - section.content[previous].timestamp);
+
+                        entry.view(options, show_process, *role, first_number + index, delta)
+                    })
+                }
+            </>
+        }
+    });
+
+    let table = if displayed_count != 0 && !collapsed {
+        html! {
+            <Table sticky_header={true} classes={classes!(options.compact.then_some("compact"))}>
+                <thead>
+                    <TableRow classes={classes!("text-left")}>
+                        <TableItem tag="th"/>
+                        {
+                            for section
+                                .columns()
+                                .into_iter()
+                                .filter(|column| !options.hidden_columns.contains(column))
+                                .map(|column| {
+                                    let classes = match column {
+                                        LogColumn::Timestamp => classes!("min-w-[235px]"),
+                                        _ => classes!(),
+                                    };
+                                    let style = options
+                                        .column_widths
+                                        .get(&column)
+                                        .map(|width| format!("width: {0}px; min-width: {0}px", width));
+
+                                    html! {
+                                        <TableItem tag="th" {classes} {style}>
+                                            { column }
+                                            <ResizeHandle on_resize={options.on_resize_column.reform(move |width| (column, width))} />
+                                        </TableItem>
+                                    }
+                                })
+                        }
+                    </TableRow>
+                </thead>
+                <tbody class={classes!("font-mono", options.text_size.classes())}>
+                    { for rows }
+                </tbody>
+            </Table>
+        }
+    } else {
+        html! {}
+    };
+
+    let mut next_number = first_number + total_count;
+    let subsections = section
+        .subsections
+        .iter()
+        .map(|subsection| {
+            let rendered = view_scoped(
+                subsection,
+                query,
+                level.incremented().unwrap(),
+                options,
+                in_scope,
+                next_number,
+            );
+            next_number += subsection.iter_all().count();
+            rendered
+        })
+        .collect();
+
+    let name = section.name.clone();
+
+    RenderedLogSection {
+        level,
+        title: section.name.clone(),
+        displayed_count,
+        total_count,
+        html: table,
+        subsections,
+        collapsed,
+        on_toggle_collapsed: Some(
+            options
+                .on_set_sections_collapsed
+                .reform(move |_| (vec![name.clone()], !collapsed)),
+        ),
+    }
+}
+
+pub trait LogEntryView {
+    /// The row of the entry in the logs table, with its `number` (see
+    /// [`Content::log_entry_by_number`]) and the time elapsed since the previous entry (if any)
+    /// as the `delta`.
+    fn view(
+        &self,
+        options: &LogViewOptions,
+        show_process: bool,
+        role: EntryRole,
+        number: usize,
+        delta: Option<chrono::Duration>,
+    ) -> Html;
+}
+
+impl LogEntryView for LogEntry {
+    fn view(
+        &self,
+        options: &LogViewOptions,
+        show_process: bool,
+        role: EntryRole,
+        number: usize,
+        delta: Option<chrono::Duration>,
+    ) -> Html {
+        let id = options.entry_id(self);
+
+        let mut classes = self.level.unwrap_or_default().color();
+        if role == EntryRole::Context {
+            classes.push("opacity-60");
+        }
+        if options.selected_entries.contains(&id) {
+            classes.push(classes!(
+                "outline",
+                "outline-2",
+                "-outline-offset-2",
+                "outline-brand-focus",
+                "dark:outline-brand-dark-focus",
+            ));
+        }
+
+        let on_click_entry = {
+            let id = id.clone();
+
+            options.on_click_entry.reform(move |event: MouseEvent| {
+                let mode = if event.shift_key() {
+                    SelectionMode::Range
+                } else if event.ctrl_key() || event.meta_key() {
+                    SelectionMode::Toggle
+                } else {
+                    SelectionMode::Single
+                };
+
+                (id.clone(), mode)
+            })
+        };
+
+        let shows = |column| !options.hidden_columns.contains(&column);
+
+        let on_show_details = {
+            let id = id.clone();
+            options.on_show_details.reform(move |_| id.clone())
+        };
+
+        let context_button = if role == EntryRole::Match {
+            let id = id.clone();
+
+            html! {
+                <Button
+                    size={ButtonSize::Small}
+                    icon={classes!("fas", "fa-arrows-alt-v")}
+                    active={options.context_entries.contains(&id)}
+                    title={Some(i18n::context_entries_title(options.toggled_context_lines()))}
+                    on_click={options.on_toggle_context.reform(move |_| id.clone())}
+                />
+            }
+        } else {
+            html! {}
+        };
+
+        let bookmark_button = {
+            let bookmarked = options.bookmarks.contains(&id);
+            let id = id.clone();
+
+            html! {
+                <Button
+                    size={ButtonSize::Small}
+                    icon={classes!(if bookmarked { "fas" } else { "far" }, "fa-star")}
+                    active={bookmarked}
+                    title={Some(tr(if bookmarked { Text::RemoveBookmark } else { Text::Bookmark }).to_owned())}
+                    on_click={options.on_toggle_bookmark.reform(move |_| id.clone())}
+                />
+            }
+        };
+
+        let note = options.annotations.get(&id);
+        let annotation_button = {
+            let id = id.clone();
+
+            html! {
+                <Button
+                    size={ButtonSize::Small}
+                    icon={classes!(if note.is_some() { "fas" } else { "far" }, "fa-sticky-note")}
+                    title={Some(tr(Text::Note).to_owned())}
+                    on_click={options.on_edit_annotation.reform(move |_| Some(id.clone()))}
+                />
+            }
+        };
+
+        let note = if options.editing_annotation.as_ref() == Some(&id) {
+            let on_change = {
+                let id = id.clone();
+                options
+                    .on_update_annotation
+                    .reform(move |note| (id.clone(), note))
+            };
+            let on_submit_maybe = {
+                let id = id.clone();
+                options
+                    .on_edit_annotation
+                    .reform(move |submit: bool| (!submit).then(|| id.clone()))
+            };
+
+            html! {
+                <div class="flex mb-1 font-sans">
+                    <Input
+                        classes={classes!("rounded-2xl", "py-1")}
+                        value={note.cloned().unwrap_or_default()}
+                        {on_change}
+                        {on_submit_maybe}
+                        placeholder={tr(Text::NotePlaceholder)}
+                        autofocus={true}
+                    />
+                </div>
+            }
+        } else {
+            match note {
+                Some(note) => html! {
+                    <p class={classes!("mb-1", "font-sans", "italic")}>
+                        <Icon icon={classes!("fas", "fa-sticky-note")} classes={classes!("mr-2")} />
+                        { note }
+                    </p>
+                },
+                None => html! {},
+            }
+        };
+
+        let text = Rc::clone(&options.text);
+        let source = self.source.clone();
+        let redact = options.redact;
+
+        let on_show_raw = {
+            let text = Rc::clone(&text);
+            let start = source.start;
+
+            options
+                .on_show_raw_line
+                .reform(move |_| line_number(&text, start))
+        };
+
+        let toaster = options.toaster.clone();
+        let on_copy = Callback::from(move |_| {
+            let raw = text.get(source.clone()).unwrap_or_default().to_owned();
+            toaster.copy(redaction::redact_if(redact, raw));
+        });
+
+        html! {
+            <TableRow {id} {classes} on_click={on_click_entry} on_double_click={on_show_details.clone()}>
+                <TableItem>
+                    <Button
+                        size={ButtonSize::Small}
+                        icon={classes!("fas", "fa-expand-alt")}
+                        title={Some(tr(Text::EntryDetails).to_owned())}
+                        on_click={on_show_details}
+                    />
+                    <Button
+                        size={ButtonSize::Small}
+                        icon={classes!("far", "fa-copy")}
+                        on_click={on_copy}
+                    />
+                    <Button
+                        size={ButtonSize::Small}
+                        icon={classes!("far", "fa-file-alt")}
+                        on_click={on_show_raw}
+                    />
+                    { bookmark_button }
+                    { annotation_button }
+                    { context_button }
+                </TableItem>
+                {
+                    if shows(LogColumn::Number) {
+                        html! { <TableItem classes={classes!("text-right", "opacity-60")}>{ number }</TableItem> }
+                    } else {
+                        html! {}
+                    }
+                }
+                {
+                    match &self.origin {
+                        Some(origin) if shows(LogColumn::Origin) => html! { <TableItem>{ origin }</TableItem> },
+                        _ => html! {},
+                    }
+                }
+                {
+                    if shows(LogColumn::Timestamp) {
+                        html! { <TableItem>{ &self.original_timestamp }</TableItem> }
+                    } else {
+                        html! {}
+                    }
+                }
+                {
+                    if shows(LogColumn::Delta) {
+                        // Gaps of a second or more stand out, as they may be stalls or retries.
+                        let short = !matches!(delta, Some(delta) if delta >= chrono::Duration::seconds(1));
+
+                        html! {
+                            <TableItem classes={classes!("whitespace-nowrap", short.then_some("opacity-60"))}>
+                                { delta.map(format_delta).unwrap_or_default() }
+                            </TableItem>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+                {
+                    if shows(LogColumn::Level) {
+                        html! { <TableItem>{ self.level.as_ref().map(LogLevel::view).unwrap_or_default() }</TableItem> }
+                    } else {
+                        html! {}
+                    }
+                }
+                {
+                    for self
+                        .meta
+                        .clone()
+                        .view(show_process, options.version.as_deref())
+                        .into_iter()
+                        .filter(|(column, _)| shows(*column))
+                        .map(|(_, cell)| cell)
+                }
+                {
+                    if shows(LogColumn::Message) {
+                        html! {
+                            <TableItem>
+                                { note }
+                                { view_message_block(self, options) }
+                            </TableItem>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+            </TableRow>
+        }
+    }
+}
+
+/// The message of the `entry` as a block, which either wraps long lines or scrolls horizontally within the
+/// width of the message column (or a default one).
+fn view_message_block(entry: &LogEntry, options: &LogViewOptions) -> Html {
+    if options.wrap_messages {
+        return html! {
+            <pre class={classes!("whitespace-pre-wrap", "[overflow-wrap:anywhere]")}>
+                { view_message(entry, options) }
+            </pre>
+        };
+    }
+
+    let style = options
+        .column_widths
+        .get(&LogColumn::Message)
+        .map(|width| format!("max-width: {}px", width));
+
+    html! {
+        <pre class={classes!("!overflow-x-auto", "max-w-[60vw]")} {style}>
+            { view_message(entry, options) }
+        </pre>
+    }
+}
+
+/// The message of the `entry`, with a stack trace in it (if any) collapsed below the first line(s).
+fn view_message(entry: &LogEntry, options: &LogViewOptions) -> Html {
+    let (start, frame_count) = match stack_trace(&entry.message) {
+        Some(stack_trace) => stack_trace,
+        None => return view_message_part(entry, 0..entry.message.len(), options),
+    };
+
+    let head_end = if entry.message[..start].ends_with('\n') {
+        start - 1
+    } else {
+        start
+    };
+
+    html! {
+        <>
+            { view_message_part(entry, 0..head_end, options) }
+            <details>
+                <summary class={classes!("cursor-pointer", "font-sans")}>
+                    { format!("{} frame stack trace", frame_count) }
+                </summary>
+                { view_message_part(entry, start..entry.message.len(), options) }
+            </details>
+        </>
+    }
+}
+
+fn view_message_part(entry: &LogEntry, part: Range<usize>, options: &LogViewOptions) -> Html {
+    let text = |range: Range<usize>| view_text(&entry.message[range], options.linkify_urls);
+
+    if !options.highlight_sensitive || entry.sensitive.is_empty() {
+        return text(part);
+    }
+
+    let mut parts = vec![];
+    let mut shown_until = part.start;
+
+    for (range, identifier) in &entry.sensitive {
+        let range = range.start.max(part.start)..range.end.min(part.end);
+
+        if range.is_empty() {
+            continue;
+        }
+
+        parts.push(text(shown_until..range.start));
+        parts.push(html! {
+            <mark
+                class={classes!("rounded", "bg-yellow-200", "dark:bg-yellow-700", "dark:text-brand-dark-text")}
+                title={identifier.to_string()}
+            >
+                { &entry.message[range.clone()] }
+            </mark>
+        });
+        shown_until = range.end;
+    }
+
+    parts.push(text(shown_until..part.end));
+
+    html! { for parts }
+}
+
+/// Plain `text`, with its URLs as links if `linkify_urls`.
+fn view_text(text: &str, linkify_urls: bool) -> Html {
+    if !linkify_urls {
+        return html! { text };
+    }
+
+    let mut parts = vec![];
+    let mut shown_until = 0;
+
+    for url in find_urls(text) {
+        parts.push(html! { &text[shown_until..url.start] });
+        parts.push(html! { <Link href={text[url.clone()].to_owned()} text={text[url.clone()].to_owned()} /> });
+        shown_until = url.end;
+    }
+
+    parts.push(html! { &text[shown_until..] });
+
+    html! { for parts }
+}
+
+/// Whether `line` is a frame of a Java/JavaScript (`at …`) or an iOS (`3   Signal   0x…`) stack
+/// trace.
+fn is_stack_frame(line: &str) -> bool {
+    let line = line.trim();
+    let mut words = line.split_whitespace();
+
+    line.starts_with("at ")
+        || (line.starts_with("... ") && line.ends_with(" more"))
+        || matches!(
+            (words.next(), words.nth(1)),
+            (Some(index), Some(address))
+                if index.chars().all(|c| c.is_ascii_digit()) && address.starts_with("0x")
+        )
+}
+
+/// Byte offset of the first line of a stack trace in `message` and the number of its frames, if
+/// it has enough to be worth collapsing.
+fn stack_trace(message: &str) -> Option<(usize, usize)> {
+    let mut start = None;
+    let mut frame_count = 0;
+    let mut offset = 0;
+
+    for line in message.split_inclusive('\n') {
+        if is_stack_frame(line) {
+            start.get_or_insert(offset);
+            frame_count += 1;
+        }
+
+        offset += line.len();
+    }
+
+    start
+        .filter(|_| frame_count >= MIN_STACK_TRACE_FRAMES)
+        .map(|start| (start, frame_count))
+}
+
+pub trait PlatformMetadataView {
+    /// Table cells for the metadata, with their columns; `show_process` must match whether the
+    /// table has a Process column (only applicable to Signal Desktop). Source code links are for
+    /// the app `version`, if known.
+    fn view(self, show_process: bool, version: Option<&str>) -> Vec<(LogColumn, Html)>;
+}
+
+impl PlatformMetadataView for PlatformMetadata {
+    fn view(self, show_process: bool, version: Option<&str>) -> Vec<(LogColumn, Html)> {
+        let columns = self.columns(show_process);
+
+        let cells = match self {
+            PlatformMetadata::AndroidLogcat {
+                process_id,
+                thread_id,
+                tag,
+            } => vec![
+                html! { <TableItem>{ process_id }</TableItem> },
+                html! { <TableItem>{ thread_id }</TableItem> },
+                html! { <TableItem>{ tag }</TableItem> },
+            ],
+            PlatformMetadata::AndroidLogger {
+                version,
+                thread_id,
+                tag,
+            } => {
+                let tag = match source_links::android_tag(&tag) {
+                    Some(href) => html! { <Link {href} text={tag} /> },
+                    None => html! { tag },
+                };
+
+                vec![
+                    html! {
+                        <TableItem>
+                            <Link href={source_links::android_version(&version)} text={version} />
+                        </TableItem>
+                    },
+                    html! { <TableItem>{ thread_id }</TableItem> },
+                    html! { <TableItem>{ tag }</TableItem> },
+                ]
+            }
+            PlatformMetadata::Ios(Some(meta)) => {
+                let symbol = match source_links::ios_symbol(&meta.symbol) {
+                    Some(href) => html! { <Link {href} text={meta.symbol.clone()} /> },
+                    None => html! { meta.symbol.clone() },
+                };
+
+                vec![
+                    html! {
+                        <TableItem>
+                            <Link href={source_links::ios_file(version, &meta.file, &meta.line)} text={meta.file.clone()} />
+                        </TableItem>
+                    },
+                    html! { <TableItem>{ meta.line }</TableItem> },
+                    html! { <TableItem classes={classes!("text-right")}><pre>{ symbol }</pre></TableItem> },
+                ]
+            }
+            PlatformMetadata::Ios(None) => vec![
+                html! { <TableItem/> },
+                html! { <TableItem/> },
+                html! { <TableItem/> },
+            ],
+            PlatformMetadata::Desktop(Some(meta)) if show_process => {
+                vec![html! { <TableItem>{ meta.process }</TableItem> }]
+            }
+            PlatformMetadata::Desktop(None) if show_process => vec![html! { <TableItem/> }],
+            PlatformMetadata::Desktop(_) => vec![],
+        };
+
+        columns.into_iter().zip(cells).collect()
+    }
+}
+
+/// Groups of consecutive indices of `count` entries to display: the ascending `matches`, and
+/// `context(index)` entries before and after every matching `index`. Every index comes with whether
+/// it's a match; overlapping or adjacent groups are merged.
+fn context_groups(
+    matches: &[usize],
+    count: usize,
+    context: impl Fn(usize) -> usize,
+) -> Vec<Vec<(usize, bool)>> {
+    let mut ranges = matches
+        .iter()
+        .map(|&index| {
+            let context = context(index);
+            index.saturating_sub(context)..(index + context + 1).min(count)
+        })
+        .collect::<Vec<_>>();
+    ranges.sort_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<usize>> = vec![];
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+
+    merged
+        .into_iter()
+        .map(|range| {
+            range
+                .map(|index| (index, matches.binary_search(&index).is_ok()))
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test]
+    fn section_outline_ok() {
+        let section = Section {
+            name: "Logger".to_owned(),
+            content: vec![1],
+            subsections: vec![Section {
+                name: "Previous Run".to_owned(),
+                content: vec![2, 3],
+                subsections: vec![],
+            }],
+        };
+
+        assert_eq!(
+            section.outline(0),
+            vec![
+                OutlineEntry {
+                    depth: 0,
+                    name: "Logger".to_owned(),
+                    id: "logger".to_owned(),
+                    count: 3,
+                },
+                OutlineEntry {
+                    depth: 1,
+                    name: "Previous Run".to_owned(),
+                    id: "previous-run".to_owned(),
+                    count: 2,
+                },
+            ]
+        );
+    }
+
+    #[test_case("java.lang.IllegalStateException: Oops\n\tat a.B.c(B.java:1)\n\tat a.B.d(B.java:2)\nCaused by: x\n\tat a.C.e(C.java:3)\n\t... 12 more" => Some((38, 4)); "java")]
+    #[test_case("Error: Oops\n    at f (app.js:1:2)\n    at g (app.js:3:4)\n    at h (app.js:5:6)" => Some((12, 3)); "javascript")]
+    #[test_case("Crash\n0   Signal   0x0000000100abcdef main + 12\n1   UIKit   0x0000000180abcdef foo + 34\n2   libdyld.dylib   0x0000000190abcdef start + 4" => Some((6, 3)); "ios")]
+    #[test_case("Oops\n\tat a.B.c(B.java:1)" => None; "too short")]
+    #[test_case("Just a message\nwith two lines" => None; "no stack trace")]
+    fn stack_trace_ok(message: &str) -> Option<(usize, usize)> {
+        stack_trace(message)
+    }
+
+    #[test_case(&[], 0 => Vec::<Vec<(usize, bool)>>::new(); "no matches")]
+    #[test_case(&[2, 7], 0 => vec![vec![(2, true)], vec![(7, true)]]; "without context")]
+    #[test_case(&[2, 7], 1 => vec![vec![(1, false), (2, true), (3, false)], vec![(6, false), (7, true), (8, false)]]; "separate groups")]
+    #[test_case(&[2, 5], 1 => vec![vec![(1, false), (2, true), (3, false), (4, false), (5, true), (6, false)]]; "adjacent groups")]
+    #[test_case(&[0, 9], 2 => vec![vec![(0, true), (1, false), (2, false)], vec![(7, false), (8, false), (9, true)]]; "at the edges")]
+    fn context_groups_ok(matches: &[usize], context: usize) -> Vec<Vec<(usize, bool)>> {
+        context_groups(matches, 10, |_| context)
+    }
+
+    #[test]
+    fn context_groups_ok_per_match() {
+        assert_eq!(
+            context_groups(&[3, 5], 10, |index| if index == 5 { 3 } else { 0 }),
+            vec![(2..=8)
+                .map(|index| (index, index == 3 || index == 5))
+                .collect::<Vec<_>>()]
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use percent_encoding::{percent_decode_str, utf8_percent_encode, NON_ALPHANUMERIC};
+
+/// Query parameter with the debug log URL; the fragment is the element ID of the log entry.
+const LOG_PARAM: &str = "log";
+/// Query parameter that is `1` if the page is embedded in another, see [`embedded`].
+const EMBED_PARAM: &str = "embed";
+
+/// Link to the `page` (without query and fragment) that loads the debug log at `url` and shows the
+/// log entry with the element ID.
+pub fn link(page: &str, url: &str, entry_id: &str) -> String {
+    format!(
+        "{}?{}={}#{}",
+        page,
+        LOG_PARAM,
+        utf8_percent_encode(url, NON_ALPHANUMERIC),
+        entry_id
+    )
+}
+
+/// The debug log URL and the element ID of the log entry (if any) of a permalink, given the
+/// `search` (query) and `hash` (fragment) of its location.
+pub fn parse(search: &str, hash: &str) -> Option<(String, Option<String>)> {
+    let url = search
+        .trim_start_matches('?')
+        .split('&')
+        .find_map(|pair| pair.strip_prefix(LOG_PARAM)?.strip_prefix('='))?;
+    let url = percent_decode_str(url).decode_utf8().ok()?.into_owned();
+
+    if url.is_empty() {
+        return None;
+    }
+
+    let entry_id = Some(hash.trim_start_matches('#'))
+        .filter(|id| !id.is_empty())
+        .map(str::to_owned);
+
+    Some((url, entry_id))
+}
+
+/// Whether the page is embedded in another (e.g. a support dashboard), given the `search` (query)
+/// of its location; then only the tabs and their content are shown, filling the page.
+pub fn embedded(search: &str) -> bool {
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .any(|pair| pair == format!("{}=1", EMBED_PARAM))
+}
+
+/// The current page, without query and fragment.
+pub fn current_page() -> Option<String> {
+    let location = web_sys::window()?.location();
+
+    Some(location.origin().ok()? + &location.pathname().ok()?)
+}
+
+/// The debug log URL and element ID of the log entry, if the current page was opened via a
+/// permalink.
+pub fn current() -> Option<(String, Option<String>)> {
+    let location = web_sys::window()?.location();
+
+    parse(&location.search().ok()?, &location.hash().ok()?)
+}
+
+/// Whether the current page is embedded in another.
+pub fn current_embedded() -> bool {
+    web_sys::window()
+        .and_then(|window| window.location().search().ok())
+        .map(|search| embedded(&search))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test]
+    fn link_ok() {
+        assert_eq!(
+            link(
+                "https://readlogs.example/",
+                "https://debuglogs.org/abc.zip",
+                "entry-12"
+            ),
+            "https://readlogs.example/?log=https%3A%2F%2Fdebuglogs%2Eorg%2Fabc%2Ezip#entry-12"
+        );
+    }
+
+    #[test_case("?log=https%3A%2F%2Fdebuglogs%2Eorg%2Fabc%2Ezip", "#entry-12" => Some(("https://debuglogs.org/abc.zip".to_owned(), Some("entry-12".to_owned()))); "with entry")]
+    #[test_case("?other=1&log=https%3A%2F%2Fdebuglogs%2Eorg%2Fabc", "" => Some(("https://debuglogs.org/abc".to_owned(), None)); "without entry")]
+    #[test_case("?log=", "#entry-12" => None; "empty url")]
+    #[test_case("", "" => None; "no query")]
+    fn parse_ok(search: &str, hash: &str) -> Option<(String, Option<String>)> {
+        parse(search, hash)
+    }
+
+    #[test_case("?embed=1" => true; "only")]
+    #[test_case("?log=https%3A%2F%2Fdebuglogs%2Eorg%2Fabc&embed=1" => true; "with log")]
+    #[test_case("?embed=0" => false; "disabled")]
+    #[test_case("?embedded=1" => false; "other param")]
+    #[test_case("" => false; "no query")]
+    fn embedded_ok(search: &str) -> bool {
+        embedded(search)
+    }
+}
@@ -0,0 +1,251 @@
+use chrono::{DateTime, Utc};
+
+use crate::{
+    parsers::{Content, PlatformMetadata},
+    FeatureFlag,
+};
+
+/// Tags of the Signal Android log entries about remote config refreshes.
+const REMOTE_CONFIG_TAGS: [&str; 2] = ["FeatureFlags", "RemoteConfig"];
+const BEFORE_MARKER: &str = "Before";
+const AFTER_MARKER: &str = "After";
+
+/// Remote config values by key, in the order they were logged.
+type Values = Vec<(String, String)>;
+
+/// A remote config value that changed when the app refreshed it, found by comparing the values
+/// it logged before and after the refresh, e.g. `[Memory] Before: {a=1}` and
+/// `[Memory] After : {a=2}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteConfigChange {
+    /// Number of the log entry with the new values, as shown in the `#` column.
+    pub number: usize,
+    pub timestamp: DateTime<Utc>,
+    pub original_timestamp: String,
+    /// Where the values are kept, e.g. `Memory` or `Disk`.
+    pub store: String,
+    pub key: String,
+    /// `None` if the value was added.
+    pub from: Option<String>,
+    /// `None` if the value was removed.
+    pub to: Option<String>,
+    /// Value of the flag in the information sections, i.e. when the debug log was submitted.
+    pub final_value: Option<String>,
+}
+
+impl RemoteConfigChange {
+    /// All changes in the log entries of `content`, in the order they were logged.
+    pub fn all(content: &Content) -> Vec<RemoteConfigChange> {
+        let flags = FeatureFlag::all(content);
+        let mut before: Vec<(String, Values)> = vec![];
+        let mut output = vec![];
+
+        for (index, entry) in content.log_entries().enumerate() {
+            let tag = match &entry.meta {
+                PlatformMetadata::AndroidLogcat { tag, .. }
+                | PlatformMetadata::AndroidLogger { tag, .. } => tag,
+                _ => continue,
+            };
+            if !REMOTE_CONFIG_TAGS.contains(&tag.as_str()) {
+                continue;
+            }
+
+            let (store, marker, values) = match Self::parse_values(&entry.message) {
+                Some(parsed) => parsed,
+                None => continue,
+            };
+
+            if marker == BEFORE_MARKER {
+                before.retain(|(s, _)| s != store);
+                before.push((store.to_owned(), values));
+                continue;
+            }
+
+            let old_values = match before.iter().position(|(s, _)| s == store) {
+                Some(position) => before.remove(position).1,
+                None => continue,
+            };
+
+            let find = |values: &[(String, String)], key: &str| {
+                values
+                    .iter()
+                    .find(|(k, _)| k == key)
+                    .map(|(_, value)| value.clone())
+            };
+
+            let mut keys = old_values
+                .iter()
+                .chain(&values)
+                .map(|(key, _)| key.as_str())
+                .collect::<Vec<_>>();
+            keys.sort_unstable();
+            keys.dedup();
+
+            for key in keys {
+                let (from, to) = (find(&old_values, key), find(&values, key));
+                if from == to {
+                    continue;
+                }
+
+                output.push(RemoteConfigChange {
+                    number: index + 1,
+                    timestamp: entry.timestamp,
+                    original_timestamp: entry.original_timestamp.clone(),
+                    store: store.to_owned(),
+                    key: key.to_owned(),
+                    from,
+                    to,
+                    final_value: Self::final_value(&flags, store, key),
+                });
+            }
+        }
+
+        output
+    }
+
+    /// Store, marker and values of a message like `[Disk]   After : {a=1, b=2}`.
+    fn parse_values(message: &str) -> Option<(&str, &str, Values)> {
+        let (store, rest) = message.trim().strip_prefix('[')?.split_once(']')?;
+        let (marker, values) = rest.split_once(':')?;
+        let marker = marker.trim();
+        if marker != BEFORE_MARKER && marker != AFTER_MARKER {
+            return None;
+        }
+
+        let values = values.trim().strip_prefix('{')?.strip_suffix('}')?;
+        let values = values
+            .split(", ")
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (key.to_owned(), value.to_owned())
+            })
+            .collect();
+
+        Some((store, marker, values))
+    }
+
+    /// Value of the flag with the `key`, preferably from an information section about the
+    /// `store`, e.g. `Memory Values`.
+    fn final_value(flags: &[FeatureFlag], store: &str, key: &str) -> Option<String> {
+        let store = store.to_lowercase();
+        let mut flags = flags.iter().filter(|flag| flag.key == key);
+
+        flags
+            .clone()
+            .find(|flag| flag.section.to_lowercase().contains(&store))
+            .or_else(|| flags.next())
+            .and_then(|flag| flag.value.as_ref())
+            .map(ToString::to_string)
+    }
+
+    /// Whether the value it changed to is the one the debug log was submitted with.
+    pub fn is_final(&self) -> bool {
+        self.final_value.is_some() && self.final_value == self.to
+    }
+
+    /// Whether the change should be shown, given a (case-insensitive) `query` for its key.
+    pub fn matches(&self, query: &str) -> bool {
+        self.key
+            .to_lowercase()
+            .contains(&query.trim().to_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::parsers::{InfoEntry, LogEntry, Section, Value};
+
+    use super::*;
+
+    fn test_entry(tag: &str, message: &str) -> LogEntry {
+        LogEntry {
+            meta: PlatformMetadata::AndroidLogger {
+                version: "5.0.0".to_owned(),
+                thread_id: "main".to_owned(),
+                tag: tag.to_owned(),
+            },
+            ..LogEntry::test(message, 0)
+        }
+    }
+
+    #[test_case("[Memory] Before: {a=1, b=2}" => Some(("Memory", "Before", "a=1 b=2".to_owned())); "before")]
+    #[test_case("[Disk]   After : {a=1:2,3:4}" => Some(("Disk", "After", "a=1:2,3:4".to_owned())); "after")]
+    #[test_case("[Memory] After : {}" => Some(("Memory", "After", String::new())); "empty")]
+    #[test_case("[Memory] Something: {a=1}" => None; "other marker")]
+    #[test_case("Refreshed the config" => None; "other message")]
+    fn parse_values_ok(message: &str) -> Option<(&str, &str, String)> {
+        RemoteConfigChange::parse_values(message).map(|(store, marker, values)| {
+            let values = values
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect::<Vec<_>>();
+
+            (store, marker, values.join(" "))
+        })
+    }
+
+    #[test]
+    fn all_ok() {
+        let content = Content {
+            information: vec![Section {
+                name: "FEATURE FLAGS".to_owned(),
+                content: vec![],
+                subsections: vec![
+                    Section {
+                        name: "Memory Values".to_owned(),
+                        content: vec![InfoEntry::KeyValue(
+                            "a".to_owned(),
+                            Value::Generic("2".to_owned()),
+                        )],
+                        subsections: vec![],
+                    },
+                    Section {
+                        name: "Disk Values".to_owned(),
+                        content: vec![InfoEntry::KeyValue(
+                            "a".to_owned(),
+                            Value::Generic("3".to_owned()),
+                        )],
+                        subsections: vec![],
+                    },
+                ],
+            }],
+            logs: vec![Section {
+                name: "LOGGER".to_owned(),
+                content: vec![
+                    test_entry("FeatureFlags", "[Memory] Before: {a=1, b=true}"),
+                    test_entry("Other", "[Memory] After : {a=5}"),
+                    test_entry("FeatureFlags", "[Memory] After : {a=2, c=x}"),
+                    test_entry("FeatureFlags", "[Disk]   After : {a=3}"),
+                ],
+                subsections: vec![],
+            }],
+        };
+
+        assert_eq!(
+            RemoteConfigChange::all(&content)
+                .into_iter()
+                .map(|change| (
+                    change.number,
+                    change.key,
+                    change.from,
+                    change.to,
+                    change.final_value
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    3,
+                    "a".to_owned(),
+                    Some("1".to_owned()),
+                    Some("2".to_owned()),
+                    Some("2".to_owned())
+                ),
+                (3, "b".to_owned(), Some("true".to_owned()), None, None),
+                (3, "c".to_owned(), None, Some("x".to_owned()), None),
+            ]
+        );
+    }
+}
@@ -1,6 +1,14 @@
 use yew::prelude::*;
 
-use crate::components::{Link, Title, TitleLevel};
+use crate::{
+    components::{Button, ButtonSize, Link, Title, TitleLevel},
+    tr, Text,
+};
+
+/// Element ID of the title of a section named `title`.
+pub fn title_id(title: &str) -> String {
+    title.to_lowercase().replace(' ', "-")
+}
 
 #[derive(Debug)]
 pub struct RenderedLogSection {
@@ -13,6 +21,11 @@ pub struct RenderedLogSection {
     pub html: Html,
 
     pub subsections: Vec<RenderedLogSection>,
+
+    /// Whether the entries and subsections are hidden, leaving only the title.
+    pub collapsed: bool,
+    /// Called when the button next to the title is clicked; `None` if it can't be collapsed.
+    pub on_toggle_collapsed: Option<Callback<MouseEvent>>,
 }
 
 impl RenderedLogSection {
@@ -34,12 +47,32 @@ impl RenderedLogSection {
             html! {}
         };
 
-        let title = if show_title {
-            html! { self.title(self.level, Some(self.title_id())) }
-        } else {
-            html! {}
+        let title = match &self.on_toggle_collapsed {
+            Some(on_toggle_collapsed) if show_title => html! {
+                <div class={classes!("flex", "items-center", "gap-2")}>
+                    { self.title(self.level, Some(self.title_id())) }
+                    <Button
+                        classes={classes!("rounded-2xl")}
+                        size={ButtonSize::Small}
+                        icon={classes!("fas", if self.collapsed { "fa-chevron-right" } else { "fa-chevron-down" })}
+                        text={tr(if self.collapsed { Text::Expand } else { Text::Collapse })}
+                        on_click={on_toggle_collapsed.clone()}
+                    />
+                </div>
+            },
+            _ if show_title => html! { self.title(self.level, Some(self.title_id())) },
+            _ => html! {},
         };
 
+        if self.collapsed {
+            return html! {
+                <>
+                    { table_of_contents }
+                    { title }
+                </>
+            };
+        }
+
         html! {
             <>
                 { table_of_contents }
@@ -68,7 +101,7 @@ impl RenderedLogSection {
     }
 
     fn title_id(&self) -> String {
-        self.title.to_lowercase().replace(' ', "-")
+        title_id(&self.title)
     }
 
     fn title(&self, level: TitleLevel, id: Option<String>) -> Html {
@@ -113,6 +146,8 @@ impl Default for RenderedLogSection {
             total_count: 0,
             html: html! {},
             subsections: vec![],
+            collapsed: false,
+            on_toggle_collapsed: None,
         }
     }
 }
@@ -0,0 +1,230 @@
+use std::collections::BTreeSet;
+
+use derive_more::Display;
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+use crate::{
+    components::TextSize, parsers::LogColumn, prefers_dark_theme, set_page_class, Language,
+};
+
+const SETTINGS_STORAGE_KEY: &str = "settings";
+/// Choices for [`Settings::large_log_limit_mib`].
+pub const LARGE_LOG_LIMITS_MIB: [Option<usize>; 5] =
+    [Some(25), Some(50), Some(100), Some(250), None];
+
+/// Color scheme of the page.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Default, EnumIter, Serialize, Deserialize)]
+pub enum Theme {
+    /// Follows the preference of the browser (or operating system).
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+impl Theme {
+    pub fn is_dark(&self) -> bool {
+        match self {
+            Theme::System => prefers_dark_theme(),
+            Theme::Light => false,
+            Theme::Dark => true,
+        }
+    }
+}
+
+/// Preferences that are kept across sessions, in `localStorage`; missing ones (e.g. added in a
+/// later version) get their default value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Language of the UI; logs and exports aren't translated.
+    pub language: Language,
+    pub theme: Theme,
+    /// Whether log levels use colors that can be told apart with color vision deficiencies.
+    pub color_blind_levels: bool,
+    /// Whether the rows of the logs table have no vertical padding.
+    pub compact_log_rows: bool,
+    /// Size of the monospace text of the logs table and the raw text.
+    pub text_size: TextSize,
+    /// Whether long log messages wrap, instead of being scrolled horizontally.
+    pub wrap_log_messages: bool,
+    /// Columns of the logs table that are hidden.
+    pub hidden_log_columns: BTreeSet<LogColumn>,
+    /// Optional columns of the logs table (see [`LogColumn::is_optional`]) that are shown.
+    pub shown_log_columns: BTreeSet<LogColumn>,
+    /// Number of entries shown before and after every matching log entry.
+    pub context_lines: usize,
+    /// Whether identifiers are redacted from the start, before anything is copied or exported.
+    pub redact_by_default: bool,
+    /// Whether notes are stored (for every log URL), to survive reloads.
+    pub remember_annotations: bool,
+    /// Size in MiB above which a debug log is only parsed once confirmed, as parsing it may make
+    /// the page unresponsive for a while; `None` to parse any debug log right away.
+    pub large_log_limit_mib: Option<usize>,
+    /// Hosts other than debuglogs.org that debug logs may be loaded from (e.g. `localhost:8080`
+    /// or a company's file server), separated by commas or spaces.
+    pub allowed_hosts: String,
+    /// URL template of a CORS proxy that a debug log is fetched through if fetching it directly
+    /// fails, with `{url}` replaced by its encoded URL (or with its URL appended); empty to not
+    /// use one.
+    pub cors_proxy: String,
+    /// Whether pasting a valid debug log URL into the empty input starts reading it right away.
+    pub start_on_paste: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            language: Language::default(),
+            theme: Theme::default(),
+            color_blind_levels: false,
+            compact_log_rows: false,
+            text_size: TextSize::default(),
+            wrap_log_messages: true,
+            hidden_log_columns: BTreeSet::new(),
+            shown_log_columns: BTreeSet::new(),
+            context_lines: 0,
+            redact_by_default: false,
+            remember_annotations: false,
+            large_log_limit_mib: Some(100),
+            allowed_hosts: String::new(),
+            cors_proxy: String::new(),
+            start_on_paste: true,
+        }
+    }
+}
+
+impl Settings {
+    pub fn load() -> Self {
+        LocalStorage::get(SETTINGS_STORAGE_KEY).unwrap_or_default()
+    }
+
+    /// Columns of the logs table that aren't shown: the hidden ones, and the optional ones that
+    /// weren't chosen.
+    pub fn hidden_columns(&self) -> BTreeSet<LogColumn> {
+        LogColumn::iter()
+            .filter(|column| {
+                if column.is_optional() {
+                    !self.shown_log_columns.contains(column)
+                } else {
+                    self.hidden_log_columns.contains(column)
+                }
+            })
+            .collect()
+    }
+
+    /// Shows the column of the logs table if it isn't shown, or else hides it.
+    pub fn toggle_log_column(&mut self, column: LogColumn) {
+        let columns = if column.is_optional() {
+            &mut self.shown_log_columns
+        } else {
+            &mut self.hidden_log_columns
+        };
+
+        if !columns.remove(&column) {
+            columns.insert(column);
+        }
+    }
+
+    pub fn large_log_limit_bytes(&self) -> Option<usize> {
+        self.large_log_limit_mib.map(|mib| mib * 1024 * 1024)
+    }
+
+    pub fn allowed_hosts(&self) -> Vec<&str> {
+        self.allowed_hosts
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|host| !host.is_empty())
+            .collect()
+    }
+
+    pub fn cors_proxy(&self) -> Option<&str> {
+        Some(self.cors_proxy.trim()).filter(|cors_proxy| !cors_proxy.is_empty())
+    }
+
+    pub fn save(&self) {
+        let _ = LocalStorage::set(SETTINGS_STORAGE_KEY, self);
+    }
+
+    /// Applies the settings that affect the whole page, through classes of its root element.
+    pub fn apply(&self) {
+        self.language.select();
+        set_page_class("dark", self.theme.is_dark());
+        set_page_class("colorblind", self.color_blind_levels);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_ok_partial() {
+        let settings: Settings =
+            serde_json::from_str(r#"{"compact_log_rows":true,"text_size":"Large"}"#).unwrap();
+
+        assert_eq!(
+            settings,
+            Settings {
+                compact_log_rows: true,
+                text_size: TextSize::Large,
+                large_log_limit_mib: Some(100),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn serialize_ok_round_trip() {
+        let settings = Settings {
+            theme: Theme::Dark,
+            hidden_log_columns: BTreeSet::from([LogColumn::Thread]),
+            context_lines: 3,
+            large_log_limit_mib: None,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::from_str::<Settings>(&serde_json::to_string(&settings).unwrap()).unwrap(),
+            settings
+        );
+    }
+
+    #[test]
+    fn hidden_columns_ok() {
+        let mut settings = Settings::default();
+        assert_eq!(
+            settings.hidden_columns(),
+            BTreeSet::from([LogColumn::Delta])
+        );
+
+        settings.toggle_log_column(LogColumn::Delta);
+        settings.toggle_log_column(LogColumn::Thread);
+        assert_eq!(
+            settings.hidden_columns(),
+            BTreeSet::from([LogColumn::Thread])
+        );
+
+        settings.toggle_log_column(LogColumn::Delta);
+        assert_eq!(
+            settings.hidden_columns(),
+            BTreeSet::from([LogColumn::Delta, LogColumn::Thread])
+        );
+    }
+
+    #[test_case("" => ""; "empty")]
+    #[test_case("logs.example.com" => "logs.example.com"; "single")]
+    #[test_case(" logs.example.com, localhost:8080 ,,pastebin.com" => "logs.example.com|localhost:8080|pastebin.com"; "separated")]
+    fn allowed_hosts_ok(allowed_hosts: &str) -> String {
+        Settings {
+            allowed_hosts: allowed_hosts.to_owned(),
+            ..Default::default()
+        }
+        .allowed_hosts()
+        .join("|")
+    }
+}
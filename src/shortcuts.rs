@@ -0,0 +1,65 @@
+use yew::prelude::*;
+
+use crate::{
+    components::{Modal, Table, TableItem, TableRow},
+    is_shortcut, tr, EntryJump, LogLevel, LogMsg, Msg, Side, Tab, Text,
+};
+
+/// Keys and what they do, as listed by `view_shortcuts`.
+const SHORTCUTS: [(&str, Text); 9] = [
+    ("/", Text::ShortcutFocusSearch),
+    ("J / K", Text::ShortcutSelectEntry),
+    ("E / Shift+E", Text::ShortcutSelectWarning),
+    ("F", Text::ShortcutSelectFirstError),
+    ("1 / 2 / 3", Text::ShortcutShowTab),
+    ("[ / ]", Text::ShortcutSwitchFile),
+    ("Home / End", Text::ShortcutGoToEdge),
+    ("?", Text::ShortcutToggleList),
+    ("Esc", Text::ShortcutClose),
+];
+
+/// The message for a keyboard shortcut, if it is one.
+pub fn msg(event: &KeyboardEvent) -> Option<Msg> {
+    if !is_shortcut(event) {
+        return None;
+    }
+
+    let msg = match event.key().as_str() {
+        "/" => Msg::FocusSearch,
+        "j" => Msg::SelectEntry(EntryJump::Next, LogLevel::Trace),
+        "k" => Msg::SelectEntry(EntryJump::Previous, LogLevel::Trace),
+        "e" => Msg::SelectEntry(EntryJump::Next, LogLevel::Warn),
+        "E" => Msg::SelectEntry(EntryJump::Previous, LogLevel::Warn),
+        "f" => Msg::SelectEntry(EntryJump::First, LogLevel::Error),
+        "1" => Msg::UpdateTab(Tab::Information),
+        "2" => Msg::UpdateTab(Tab::Logs),
+        "3" => Msg::UpdateTab(Tab::Raw),
+        "[" => Msg::Log(Side::Main, LogMsg::SwitchFile(-1)),
+        "]" => Msg::Log(Side::Main, LogMsg::SwitchFile(1)),
+        "?" => Msg::UpdateShortcutsShown,
+        "Escape" => Msg::CloseModal,
+        _ => return None,
+    };
+
+    Some(msg)
+}
+
+/// An overlay that lists all keyboard shortcuts.
+pub fn view_shortcuts(on_close: Callback<MouseEvent>) -> Html {
+    html! {
+        <Modal heading={tr(Text::KeyboardShortcuts)} {on_close}>
+            <Table>
+                <tbody>
+                    {
+                        for SHORTCUTS.iter().map(|(keys, description)| html! {
+                            <TableRow>
+                                <TableItem classes={classes!("font-mono", "whitespace-nowrap")}>{ keys }</TableItem>
+                                <TableItem>{ tr(*description) }</TableItem>
+                            </TableRow>
+                        })
+                    }
+                </tbody>
+            </Table>
+        </Modal>
+    }
+}
@@ -0,0 +1,126 @@
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+use crate::Platform;
+
+const IOS_REPOSITORY: &str = "signalapp/Signal-iOS";
+const ANDROID_REPOSITORY: &str = "signalapp/Signal-Android";
+const DESKTOP_REPOSITORY: &str = "signalapp/Signal-Desktop";
+
+const fn repository(platform: Platform) -> &'static str {
+    match platform {
+        Platform::Android => ANDROID_REPOSITORY,
+        Platform::Ios => IOS_REPOSITORY,
+        Platform::Desktop => DESKTOP_REPOSITORY,
+    }
+}
+
+/// Searches the code of a GitHub `repository` (always on its default branch).
+fn code_search(repository: &str, query: &str) -> String {
+    format!(
+        "https://github.com/search?q={}&type=code",
+        utf8_percent_encode(&format!("repo:{} {}", repository, query), NON_ALPHANUMERIC)
+    )
+}
+
+/// Link to the `line` of a Signal iOS source `file`, at the tag of the app `version` if known.
+/// Most log entries only name the file without its folder, so it can only be searched for.
+pub fn ios_file(version: Option<&str>, file: &str, line: &str) -> String {
+    if file.contains('/') {
+        format!(
+            "https://github.com/{}/blob/{}/{}#L{}",
+            IOS_REPOSITORY,
+            version.unwrap_or("main"),
+            file.trim_start_matches('/'),
+            line
+        )
+    } else {
+        code_search(IOS_REPOSITORY, &format!("path:{}", file))
+    }
+}
+
+/// Link to a search for the method of a Signal iOS `symbol`, e.g. `-[OWSMessageManager
+/// handleEnvelope:]` or `MessageSender.sendMessage(_:)`.
+pub fn ios_symbol(symbol: &str) -> Option<String> {
+    let method = match symbol
+        .trim_start_matches(&['-', '+'][..])
+        .strip_prefix('[')
+        .and_then(|symbol| symbol.strip_suffix(']'))
+    {
+        Some(objc) => objc.split_whitespace().nth(1)?.split(':').next()?,
+        None => symbol.split('(').next()?.rsplit('.').next()?,
+    };
+
+    if method.is_empty() || !method.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return None;
+    }
+
+    Some(code_search(IOS_REPOSITORY, method))
+}
+
+/// Link to the sources of a Signal Android release `version`.
+pub fn android_version(version: &str) -> String {
+    format!(
+        "https://github.com/{}/tree/v{}",
+        ANDROID_REPOSITORY,
+        version.trim_start_matches('v')
+    )
+}
+
+/// Link to a search for the class that logged with `tag`, if it looks like a class name (Signal
+/// Android tags its log entries with the name of the class, shortened to 23 characters).
+pub fn android_tag(tag: &str) -> Option<String> {
+    let is_class_name = tag.starts_with(|c: char| c.is_ascii_uppercase())
+        && tag.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    is_class_name.then(|| code_search(ANDROID_REPOSITORY, &format!("symbol:{}", tag)))
+}
+
+/// Link to a new issue in the GitHub repository of the Signal app for `platform`, with the
+/// Markdown `body` prefilled.
+pub fn new_issue(platform: Platform, body: &str) -> String {
+    format!(
+        "https://github.com/{}/issues/new?body={}",
+        repository(platform),
+        utf8_percent_encode(body, NON_ALPHANUMERIC)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(Some("5.26.3.0"), "SignalServiceKit/src/Util/Item.swift", "12" => "https://github.com/signalapp/Signal-iOS/blob/5.26.3.0/SignalServiceKit/src/Util/Item.swift#L12".to_owned(); "with path")]
+    #[test_case(None, "path/to/Item.m", "3" => "https://github.com/signalapp/Signal-iOS/blob/main/path/to/Item.m#L3".to_owned(); "without version")]
+    #[test_case(Some("5.26.3.0"), "NSString+SSK.m", "12" => "https://github.com/search?q=repo%3Asignalapp%2FSignal%2DiOS%20path%3ANSString%2BSSK%2Em&type=code".to_owned(); "without path")]
+    fn ios_file_ok(version: Option<&str>, file: &str, line: &str) -> String {
+        ios_file(version, file, line)
+    }
+
+    #[test_case("-[Item handleSomething:withOther:]" => Some("https://github.com/search?q=repo%3Asignalapp%2FSignal%2DiOS%20handleSomething&type=code".to_owned()); "objective-c")]
+    #[test_case("MessageSender.sendMessage(_:)" => Some("https://github.com/search?q=repo%3Asignalapp%2FSignal%2DiOS%20sendMessage&type=code".to_owned()); "swift")]
+    #[test_case("closure #1 in foo" => None; "unknown")]
+    fn ios_symbol_ok(symbol: &str) -> Option<String> {
+        ios_symbol(symbol)
+    }
+
+    #[test_case("5.26.3" => "https://github.com/signalapp/Signal-Android/tree/v5.26.3".to_owned(); "basic")]
+    fn android_version_ok(version: &str) -> String {
+        android_version(version)
+    }
+
+    #[test_case("MessageContentProcessor" => Some("https://github.com/search?q=repo%3Asignalapp%2FSignal%2DAndroid%20symbol%3AMessageContentProcessor&type=code".to_owned()); "class name")]
+    #[test_case("chatty" => None; "lowercase")]
+    #[test_case("Some tag" => None; "with space")]
+    fn android_tag_ok(tag: &str) -> Option<String> {
+        android_tag(tag)
+    }
+
+    #[test_case(Platform::Android, "" => "https://github.com/signalapp/Signal-Android/issues/new?body=".to_owned(); "empty")]
+    #[test_case(Platform::Ios, "### Log\n" => "https://github.com/signalapp/Signal-iOS/issues/new?body=%23%23%23%20Log%0A".to_owned(); "ios")]
+    #[test_case(Platform::Desktop, "a&b" => "https://github.com/signalapp/Signal-Desktop/issues/new?body=a%26b".to_owned(); "desktop")]
+    fn new_issue_ok(platform: Platform, body: &str) -> String {
+        new_issue(platform, body)
+    }
+}
@@ -0,0 +1,102 @@
+use chrono::prelude::*;
+use yew::prelude::*;
+
+use crate::{
+    components::{Badge, Message},
+    i18n, tr, Text,
+};
+
+pub use readlogs_core::summary::LogSummary;
+
+/// Logs that end longer ago than this can hardly contain a recently reported issue.
+const STALE_AFTER_DAYS: i64 = 7;
+/// Logs that cover less than this may have been captured too late.
+const SHORT_COVERAGE_MINUTES: i64 = 10;
+
+pub trait LogSummaryView {
+    fn view(&self) -> Html;
+}
+
+impl LogSummaryView for LogSummary {
+    fn view(&self) -> Html {
+        let badges = [
+            (Text::Version, &self.version),
+            (Text::Device, &self.device),
+            (Text::OperatingSystem, &self.os),
+            (Text::Locale, &self.locale),
+            (Text::Captured, &self.captured),
+        ];
+
+        html! {
+            <div class={classes!("flex", "flex-wrap", "gap-2", "mb-4")}>
+                {
+                    for badges.iter().filter_map(|(label, value)| value.as_ref().map(|value| html! {
+                        <Badge
+                            classes={classes!("bg-brand-bg-message", "dark:bg-brand-dark-bg-message", "text-sm")}
+                            text={format!("{}: {}", tr(*label), value)}
+                        />
+                    }))
+                }
+            </div>
+        }
+    }
+}
+
+/// Warnings about a log whose entries span from `first` to `last`, as seen at `now`: whether it
+/// ends long ago, or covers only a short time.
+pub fn coverage_warnings(
+    first: DateTime<Utc>,
+    last: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Vec<String> {
+    let mut warnings = vec![];
+
+    let age = now - last;
+    if age.num_days() >= STALE_AFTER_DAYS {
+        warnings.push(i18n::stale_log_warning(age.num_days()));
+    }
+
+    let coverage = last - first;
+    if coverage.num_minutes() < SHORT_COVERAGE_MINUTES {
+        warnings.push(i18n::short_log_warning(
+            coverage.num_minutes(),
+            coverage.num_seconds(),
+        ));
+    }
+
+    warnings
+}
+
+/// A banner with the coverage warnings for log entries from `first` to `last`, if there are any.
+pub fn view_coverage_warnings(first: DateTime<Utc>, last: DateTime<Utc>) -> Html {
+    let now = Utc.timestamp_millis(js_sys::Date::now() as i64);
+    let warnings = coverage_warnings(first, last, now);
+
+    if warnings.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <Message classes={classes!("mb-4")} warning=true>
+            { for warnings.iter().map(|warning| html! { <p>{ warning }</p> }) }
+        </Message>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(0, 60 => Vec::<String>::new(); "recent and long enough")]
+    #[test_case(47 * 24 * 60, 60 => vec!["This log ends 47 days ago.".to_owned()]; "stale")]
+    #[test_case(0, 3 => vec!["This log only covers 3 minutes.".to_owned()]; "short")]
+    fn coverage_warnings_ok(minutes_ago: i64, covered_minutes: i64) -> Vec<String> {
+        let now = Utc.ymd(1234, 5, 6).and_hms(12, 0, 0);
+        let last = now - chrono::Duration::minutes(minutes_ago);
+        let first = last - chrono::Duration::minutes(covered_minutes);
+
+        coverage_warnings(first, last, now)
+    }
+}
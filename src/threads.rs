@@ -0,0 +1,187 @@
+use crate::parsers::{Content, InfoEntry, Section, Value};
+
+/// Word in the name of sections that list threads (e.g. `THREADS` and `BLOCKED THREADS`).
+const THREAD_SECTION_MARKER: &str = "threads";
+const BLOCKED_STATE: &str = "BLOCKED";
+
+/// A thread of the app at the time the debug log was captured, from the thread dump sections.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DumpedThread {
+    pub id: String,
+    pub name: String,
+    /// e.g. `BLOCKED`, only known for threads with a stack trace.
+    pub state: Option<String>,
+    /// Stack frames, innermost first.
+    pub frames: Vec<String>,
+}
+
+impl DumpedThread {
+    /// All threads of `content`, in the order they are first listed; threads that are listed
+    /// more than once (e.g. by name, then with a stack trace) are merged.
+    pub fn all(content: &Content) -> Vec<DumpedThread> {
+        let mut output: Vec<DumpedThread> = vec![];
+
+        let mut add = |thread: DumpedThread| match output.iter_mut().find(|t| t.id == thread.id) {
+            Some(existing) => {
+                if existing.state.is_none() {
+                    existing.state = thread.state;
+                }
+                if existing.frames.is_empty() {
+                    existing.frames = thread.frames;
+                }
+            }
+            None => output.push(thread),
+        };
+
+        let thread_sections = content
+            .information
+            .iter()
+            .filter(|section| section.name.to_lowercase().contains(THREAD_SECTION_MARKER));
+
+        for section in thread_sections {
+            for entry in &section.content {
+                if let InfoEntry::KeyValue(id, Value::Generic(name)) = entry {
+                    if id.chars().all(|c| c.is_ascii_digit()) {
+                        add(DumpedThread {
+                            id: id.clone(),
+                            name: name.clone(),
+                            state: None,
+                            frames: vec![],
+                        });
+                    }
+                }
+            }
+
+            for subsection in &section.subsections {
+                if let Some(thread) = Self::from_section(subsection) {
+                    add(thread);
+                }
+            }
+        }
+
+        output
+    }
+
+    /// A thread from a section titled like `[1234] Name (STATE)`, with a stack frame per entry.
+    fn from_section(section: &Section<InfoEntry>) -> Option<DumpedThread> {
+        let (id, rest) = section.name.strip_prefix('[')?.split_once("] ")?;
+
+        let (name, state) = match rest
+            .strip_suffix(')')
+            .and_then(|rest| rest.rsplit_once(" ("))
+        {
+            Some((name, state)) => (name, Some(state.to_owned())),
+            None => (rest, None),
+        };
+
+        let frames = section
+            .content
+            .iter()
+            .filter_map(|entry| match entry {
+                InfoEntry::Generic(frame) => Some(frame.trim().to_owned()),
+                _ => None,
+            })
+            .collect();
+
+        Some(DumpedThread {
+            id: id.trim().to_owned(),
+            name: name.to_owned(),
+            state,
+            frames,
+        })
+    }
+
+    /// Whether the thread is blocked, e.g. waiting for a lock held by another thread.
+    pub fn is_blocked(&self) -> bool {
+        matches!(&self.state, Some(state) if state.eq_ignore_ascii_case(BLOCKED_STATE))
+            || self
+                .frames
+                .iter()
+                .any(|frame| frame.contains("waiting to lock"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test]
+    fn all_ok() {
+        let content = Content {
+            information: vec![
+                Section {
+                    name: "THREADS".to_owned(),
+                    content: vec![
+                        InfoEntry::KeyValue("1".to_owned(), Value::Generic("main".to_owned())),
+                        InfoEntry::KeyValue(
+                            "9876".to_owned(),
+                            Value::Generic("AbcDefGhi".to_owned()),
+                        ),
+                    ],
+                    subsections: vec![],
+                },
+                Section {
+                    name: "BLOCKED THREADS".to_owned(),
+                    content: vec![],
+                    subsections: vec![Section {
+                        name: "[9876] AbcDefGhi (BLOCKED)".to_owned(),
+                        content: vec![
+                            InfoEntry::Generic("abc.def.Abc$Cba.run(DEF.java:456)".to_owned()),
+                            InfoEntry::Generic("abc.def.Def.run(ABC.java:123)".to_owned()),
+                        ],
+                        subsections: vec![],
+                    }],
+                },
+            ],
+            logs: vec![],
+        };
+
+        assert_eq!(
+            DumpedThread::all(&content),
+            vec![
+                DumpedThread {
+                    id: "1".to_owned(),
+                    name: "main".to_owned(),
+                    state: None,
+                    frames: vec![],
+                },
+                DumpedThread {
+                    id: "9876".to_owned(),
+                    name: "AbcDefGhi".to_owned(),
+                    state: Some("BLOCKED".to_owned()),
+                    frames: vec![
+                        "abc.def.Abc$Cba.run(DEF.java:456)".to_owned(),
+                        "abc.def.Def.run(ABC.java:123)".to_owned(),
+                    ],
+                },
+            ]
+        );
+    }
+
+    #[test_case("[123] Signal Catcher (RUNNABLE)" => Some(("123".to_owned(), "Signal Catcher".to_owned(), Some("RUNNABLE".to_owned()))); "with state")]
+    #[test_case("[123] OkHttp (pool) worker" => Some(("123".to_owned(), "OkHttp (pool) worker".to_owned(), None)); "without state")]
+    #[test_case("Not a thread" => None; "not a thread")]
+    fn from_section_ok(name: &str) -> Option<(String, String, Option<String>)> {
+        DumpedThread::from_section(&Section {
+            name: name.to_owned(),
+            content: vec![],
+            subsections: vec![],
+        })
+        .map(|thread| (thread.id, thread.name, thread.state))
+    }
+
+    #[test_case(Some("BLOCKED"), "abc.def.Abc.run(ABC.java:1)" => true; "blocked state")]
+    #[test_case(None, "- waiting to lock <0x0abc> (a java.lang.Object)" => true; "waiting to lock")]
+    #[test_case(Some("WAITING"), "abc.def.Abc.run(ABC.java:1)" => false; "not blocked")]
+    fn is_blocked_ok(state: Option<&str>, frame: &str) -> bool {
+        DumpedThread {
+            id: "1".to_owned(),
+            name: "main".to_owned(),
+            state: state.map(str::to_owned),
+            frames: vec![frame.to_owned()],
+        }
+        .is_blocked()
+    }
+}
@@ -1,40 +1,370 @@
-#[macro_export]
-macro_rules! impl_from_str {
-    ($fn:path => $ty:ty) => {
-        impl ::std::str::FromStr for $ty {
-            type Err = ::anyhow::Error;
-
-            fn from_str(input: &str) -> ::std::result::Result<Self, Self::Err> {
-                let (remainder, output) = $fn(input).map_err(|error| {
-                    ::anyhow::anyhow!("could not parse `{}` using fn `{}`: {:#?}", stringify!($ty), stringify!($fn), error)
-                })?;
-
-                ::anyhow::ensure!(
-                    remainder.is_empty(),
-                    "could not parse entire input:\n\nRemainder: {:#?}\n\nOutput: {:#?}\n\nInput: {:#?}",
-                    remainder,
-                    output,
-                    input
-                );
-
-                ::std::result::Result::Ok(output)
+use std::{cmp::Ordering, ops::Range};
+
+use js_sys::{Function, Promise, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::Event;
+
+/// Formats a size in bytes for display, e.g. `1.5 MiB`.
+pub fn format_size(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[unit])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+/// Formats a duration for display using its two largest units, e.g. `2 h 5 min`.
+pub fn format_duration(duration: chrono::Duration) -> String {
+    let seconds = duration.num_seconds().max(0);
+    let parts = [
+        (seconds / 86_400, "d"),
+        (seconds / 3_600 % 24, "h"),
+        (seconds / 60 % 60, "min"),
+        (seconds % 60, "s"),
+    ];
+
+    let parts = parts
+        .iter()
+        .skip_while(|(value, _)| *value == 0)
+        .take(2)
+        .filter(|(value, _)| *value != 0)
+        .map(|(value, unit)| format!("{} {}", value, unit))
+        .collect::<Vec<_>>();
+
+    if parts.is_empty() {
+        "0 s".to_owned()
+    } else {
+        parts.join(" ")
+    }
+}
+
+/// Formats the time elapsed since a previous log entry compactly, e.g. `+2.4s` or `+13m`; entries
+/// that are out of order have negative ones.
+pub fn format_delta(delta: chrono::Duration) -> String {
+    let sign = if delta < chrono::Duration::zero() {
+        '-'
+    } else {
+        '+'
+    };
+    let millis = delta.num_milliseconds().abs();
+
+    match millis {
+        0..=999 => format!("{}{}ms", sign, millis),
+        1_000..=9_999 => format!("{}{:.1}s", sign, millis as f64 / 1000.0),
+        10_000..=59_999 => format!("{}{}s", sign, millis / 1000),
+        60_000..=3_599_999 => format!("{}{}m", sign, millis / 60_000),
+        3_600_000..=86_399_999 => format!("{}{}h", sign, millis / 3_600_000),
+        _ => format!("{}{}d", sign, millis / 86_400_000),
+    }
+}
+
+/// 1-based number of the line of `text` that contains the byte `offset`.
+pub fn line_number(text: &str, offset: usize) -> usize {
+    text.as_bytes()[..offset.min(text.len())]
+        .iter()
+        .filter(|byte| **byte == b'\n')
+        .count()
+        + 1
+}
+
+/// Byte offset of the start of the 1-based `line` of `text`, if it has that many lines.
+pub fn line_start(text: &str, line: usize) -> Option<usize> {
+    match line {
+        0 => None,
+        1 => Some(0),
+        _ => text
+            .match_indices('\n')
+            .nth(line - 2)
+            .map(|(index, _)| index + 1),
+    }
+}
+
+/// Byte offsets of the starts of all lines of `text`.
+pub fn line_starts(text: &str) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+        .collect()
+}
+
+/// Removes all but the last `max_bytes` of the text, and the rest of the line that is cut, so that
+/// it starts with a whole line (unless that line alone is too long).
+pub fn keep_tail(text: &mut String, max_bytes: usize) {
+    if text.len() <= max_bytes {
+        return;
+    }
+
+    let mut start = text.len() - max_bytes;
+    while !text.is_char_boundary(start) {
+        start += 1;
+    }
+    if !text[..start].ends_with('\n') {
+        if let Some(newline) = text[start..].find('\n') {
+            if newline + 1 < text.len() - start {
+                start += newline + 1;
             }
         }
+    }
+
+    text.drain(..start);
+}
+
+/// Byte ranges of non-overlapping occurrences of `query` in `text`, ignoring ASCII case.
+pub fn find_ignore_ascii_case(text: &str, query: &str) -> Vec<Range<usize>> {
+    if query.is_empty() {
+        return vec![];
+    }
+
+    // ASCII lowercasing keeps byte offsets, unlike Unicode lowercasing.
+    let query = query.to_ascii_lowercase();
+
+    text.to_ascii_lowercase()
+        .match_indices(&query)
+        .map(|(start, _)| start..start + query.len())
+        .collect()
+}
+
+/// Byte ranges of `http(s)://` URLs in `text`, without trailing punctuation.
+pub fn find_urls(text: &str) -> Vec<Range<usize>> {
+    let mut urls = vec![];
+    let mut searched_until = 0;
+
+    while let Some(found) = text[searched_until..].find("http") {
+        let start = searched_until + found;
+        let rest = &text[start..];
+
+        if !(rest.starts_with("http://") || rest.starts_with("https://")) {
+            searched_until = start + "http".len();
+            continue;
+        }
+
+        let length = rest
+            .find(|c: char| c.is_whitespace() || "\"'<>()[]{}`".contains(c))
+            .unwrap_or(rest.len());
+        let url = rest[..length].trim_end_matches(&['.', ',', ';', ':', '!', '?'][..]);
+
+        if url.ends_with("//") {
+            searched_until = start + length;
+            continue;
+        }
+
+        urls.push(start..start + url.len());
+        searched_until = start + length;
+    }
+
+    urls
+}
+
+/// Compares `a` and `b` as numbers if both are (ignoring surrounding whitespace and a trailing `%`),
+/// and as text ignoring ASCII case otherwise.
+pub fn compare_naturally(a: &str, b: &str) -> Ordering {
+    let number = |text: &str| text.trim().trim_end_matches('%').parse::<f64>().ok();
+
+    match (number(a), number(b)) {
+        (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        _ => a
+            .to_ascii_lowercase()
+            .cmp(&b.to_ascii_lowercase())
+            .then_with(|| a.cmp(b)),
+    }
+}
+
+/// Whether the key was pressed without modifiers (except Shift) and not while typing into a form
+/// field, i.e. may be meant as a keyboard shortcut.
+pub fn is_shortcut(event: &web_sys::KeyboardEvent) -> bool {
+    if event.ctrl_key() || event.meta_key() || event.alt_key() {
+        return false;
+    }
+
+    let target = event
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok());
+
+    !matches!(
+        target.map(|target| target.tag_name()).as_deref(),
+        Some("INPUT" | "SELECT" | "TEXTAREA")
+    )
+}
+
+/// Whether the browser (or operating system) prefers a dark color scheme.
+pub fn prefers_dark_theme() -> bool {
+    web_sys::window()
+        .and_then(|window| window.match_media("(prefers-color-scheme: dark)").ok())
+        .flatten()
+        .is_some_and(|query| query.matches())
+}
+
+/// Adds the class to the root element of the page, or removes it; classes like `dark` switch the
+/// colors of the whole page.
+pub fn set_page_class(class: &str, enabled: bool) {
+    let element = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.document_element());
+
+    if let Some(element) = element {
+        let _ = element.class_list().toggle_with_force(class, enabled);
+    }
+}
+
+/// Sets the language of the page, given as a BCP 47 code.
+pub fn set_page_language(code: &str) {
+    let element = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.document_element());
+
+    if let Some(element) = element {
+        let _ = element.set_attribute("lang", code);
+    }
+}
+
+/// Scrolls the page to its top, or to its bottom if `end`.
+pub fn scroll_page(end: bool) {
+    let window = match web_sys::window() {
+        Some(window) => window,
+        None => return,
+    };
+
+    let top = match window
+        .document()
+        .and_then(|document| document.document_element())
+    {
+        Some(element) if end => element.scroll_height(),
+        _ => 0,
     };
+
+    window.scroll_to_with_x_and_y(0.0, top as f64);
+}
+
+/// The plain text that is pasted with a `paste` event, read through `clipboardData` (as
+/// `ClipboardEvent` is an unstable API in `web-sys`).
+pub fn pasted_text(event: &Event) -> Option<String> {
+    let data = Reflect::get(event, &JsValue::from_str("clipboardData")).ok()?;
+    let get_data = Reflect::get(&data, &JsValue::from_str("getData"))
+        .ok()?
+        .dyn_into::<Function>()
+        .ok()?;
+
+    get_data
+        .call1(&data, &JsValue::from_str("text"))
+        .ok()?
+        .as_string()
+}
+
+/// Writes `text` to the clipboard using the asynchronous Clipboard API.
+pub async fn copy_to_clipboard(text: &str) -> Result<(), JsValue> {
+    let navigator = web_sys::window()
+        .ok_or_else(|| JsValue::from_str("no window"))?
+        .navigator();
+    let clipboard = Reflect::get(&navigator, &JsValue::from_str("clipboard"))?;
+    let write_text =
+        Reflect::get(&clipboard, &JsValue::from_str("writeText"))?.dyn_into::<Function>()?;
+
+    let promise = write_text
+        .call1(&clipboard, &JsValue::from_str(text))?
+        .dyn_into::<Promise>()?;
+
+    JsFuture::from(promise).await.map(|_| ())
 }
 
-pub fn parsing_test<'a, T: std::fmt::Debug>(
-    f: impl FnOnce(&'a str) -> nom::IResult<&'a str, T>,
-    input: &'a str,
-) -> T {
-    let (remainder, result) = f(input).unwrap();
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(0 => "0 B")]
+    #[test_case(1023 => "1023 B")]
+    #[test_case(1536 => "1.5 KiB")]
+    #[test_case(5 * 1024 * 1024 => "5.0 MiB")]
+    fn format_size_ok(bytes: usize) -> String {
+        format_size(bytes)
+    }
+
+    #[test_case(0 => "0 s")]
+    #[test_case(45 => "45 s")]
+    #[test_case(2 * 3600 + 5 * 60 + 7 => "2 h 5 min")]
+    #[test_case(3 * 86_400 + 59 => "3 d")]
+    fn format_duration_ok(seconds: i64) -> String {
+        format_duration(chrono::Duration::seconds(seconds))
+    }
+
+    #[test_case(0 => "+0ms")]
+    #[test_case(12 => "+12ms")]
+    #[test_case(2_400 => "+2.4s")]
+    #[test_case(42_900 => "+42s")]
+    #[test_case(13 * 60_000 + 5_000 => "+13m")]
+    #[test_case(5 * 3_600_000 => "+5h")]
+    #[test_case(3 * 86_400_000 => "+3d")]
+    #[test_case(-1_500 => "-1.5s"; "out of order")]
+    fn format_delta_ok(millis: i64) -> String {
+        format_delta(chrono::Duration::milliseconds(millis))
+    }
+
+    #[test_case("a\nb\nc\n", 10 => "a\nb\nc\n"; "short enough")]
+    #[test_case("first\nsecond\nthird\n", 9 => "third\n"; "cut line")]
+    #[test_case("first\nsecond\nthird\n", 13 => "second\nthird\n"; "at line start")]
+    #[test_case("ab\nlong line", 5 => " line"; "single long line")]
+    #[test_case("ä\nöü", 3 => "ü"; "char boundary")]
+    fn keep_tail_ok(text: &str, max_bytes: usize) -> String {
+        let mut text = text.to_owned();
+        keep_tail(&mut text, max_bytes);
+        text
+    }
+
+    #[test_case("9", "10" => Ordering::Less; "numbers")]
+    #[test_case("2.5%", "10%" => Ordering::Less; "percentages")]
+    #[test_case("b", "A" => Ordering::Greater; "ignoring case")]
+    #[test_case("10", "9a" => Ordering::Less; "text")]
+    fn compare_naturally_ok(a: &str, b: &str) -> Ordering {
+        compare_naturally(a, b)
+    }
+
+    #[test_case("See https://example.org/a?b=c." => vec!["https://example.org/a?b=c"]; "trailing period")]
+    #[test_case("(http://a.example, https://b.example/x)" => vec!["http://a.example", "https://b.example/x"]; "in parentheses")]
+    #[test_case("httpx and https:// alone" => Vec::<String>::new(); "not urls")]
+    fn find_urls_ok(text: &str) -> Vec<String> {
+        find_urls(text)
+            .into_iter()
+            .map(|range| text[range].to_owned())
+            .collect()
+    }
+
+    #[test_case("abc", 0 => 1; "start")]
+    #[test_case("abc\ndef\nghi", 5 => 2; "second line")]
+    #[test_case("abc\n", 4 => 2; "after last newline")]
+    fn line_number_ok(text: &str, offset: usize) -> usize {
+        line_number(text, offset)
+    }
 
-    if !remainder.is_empty() {
-        panic!(
-            "incomplete parsing, got: {:#?}, remainder: {:?}",
-            result, remainder
-        );
+    #[test_case("abc\ndef\nghi", 1 => Some(0); "first line")]
+    #[test_case("abc\ndef\nghi", 3 => Some(8); "last line")]
+    #[test_case("abc\ndef\nghi", 4 => None; "too large")]
+    #[test_case("abc", 0 => None; "zero")]
+    fn line_start_ok(text: &str, line: usize) -> Option<usize> {
+        line_start(text, line)
     }
 
-    result
+    #[test_case("" => vec![0]; "empty")]
+    #[test_case("abc\ndef\n" => vec![0, 4, 8]; "trailing newline")]
+    fn line_starts_ok(text: &str) -> Vec<usize> {
+        line_starts(text)
+    }
+
+    #[test_case("Abc abc ABC", "aBc" => vec![0..3, 4..7, 8..11]; "ignores case")]
+    #[test_case("aaaa", "aa" => vec![0..2, 2..4]; "non-overlapping")]
+    #[test_case("Ä abc", "ABC" => vec![3..6]; "offsets after non-ASCII")]
+    #[test_case("abc", "" => Vec::<Range<usize>>::new(); "empty query")]
+    fn find_ignore_ascii_case_ok(text: &str, query: &str) -> Vec<Range<usize>> {
+        find_ignore_ascii_case(text, query)
+    }
 }
@@ -1,150 +1,813 @@
-use std::rc::Rc;
+use std::{collections::BTreeSet, rc::Rc};
 
 use strum::IntoEnumIterator;
 use wasm_bindgen::JsCast;
-use web_sys::{Event, HtmlSelectElement};
+use web_sys::{Element, Event, HtmlElement, HtmlSelectElement};
 use yew::prelude::*;
 
 use crate::{components::*, *};
 
 impl super::Model {
     pub fn view_inner(&self, ctx: &Context<Self>) -> Html {
-        let file_picker = match &self.state {
+        let mut wrapper_classes = classes!("py-4", "bg-brand-bg", "dark:bg-brand-dark-bg");
+
+        if self.embedded {
+            // Fills the page, leaving room for the display options, as there is no footer.
+            wrapper_classes.push(classes!("min-h-screen", "pb-48"));
+        } else {
+            wrapper_classes.push(classes!("mb-4"));
+
+            if !self.ui_expanded && self.comparison.is_none() {
+                wrapper_classes.push(classes!(
+                    "max-w-5xl",
+                    "print:max-w-none",
+                    "mx-auto",
+                    "lg:mt-4",
+                    "lg:rounded-2xl",
+                ));
+            }
+        }
+
+        let mut columns_classes = classes!("grid", "gap-y-8");
+
+        if self.comparison.is_some() {
+            columns_classes.push(classes!("lg:grid-cols-2"));
+        }
+
+        let information_diff = match (&self.main.state, &self.comparison, self.tab) {
+            (
+                State::Ready(_),
+                Some(
+                    comparison @ DebugLog {
+                        state: State::Ready(_),
+                        ..
+                    },
+                ),
+                Tab::Information,
+            ) => Some(
+                self.main
+                    .active_file()
+                    .view_information_diff(comparison.active_file()),
+            ),
+            _ => None,
+        };
+        let show_files = information_diff.is_none();
+
+        html! {
+            <ContextProvider<Toaster> context={self.toaster.clone()}>
+                <div class={wrapper_classes}>
+                    {
+                        if self.embedded {
+                            html! {}
+                        } else {
+                            self.view_session_tabs(ctx)
+                        }
+                    }
+
+                    <div class={columns_classes}>
+                        { for self.logs().map(|(side, log)| self.view_log(side, log, show_files, ctx)) }
+                    </div>
+
+                    <div class="mx-4 prose dark:prose-invert prose-sm max-w-max mt-8">
+                        { information_diff.unwrap_or_default() }
+                    </div>
+                </div>
+
+                {
+                    if self.embedded {
+                        html! {}
+                    } else {
+                        self.view_footer(ctx)
+                    }
+                }
+
+                { self.view_display_config(ctx) }
+
+                { self.view_scroll_buttons() }
+
+                {
+                    if self.shortcuts_shown {
+                        shortcuts::view_shortcuts(ctx.link().callback(|_| Msg::CloseModal))
+                    } else {
+                        html! {}
+                    }
+                }
+
+                { self.view_entry_details(ctx) }
+
+                { self.view_settings(ctx) }
+
+                <Toasts
+                    toasts={self.toasts.clone()}
+                    on_dismiss={ctx.link().callback(Msg::DismissToast)}
+                />
+            </ContextProvider<Toaster>>
+        }
+    }
+
+    /// Tabs of the sessions, to switch between debug logs that are open at once; shown once there
+    /// is more than one, or a debug log was loaded.
+    fn view_session_tabs(&self, ctx: &Context<Self>) -> Html {
+        if self.sessions.is_empty() && !self.main.state.is_ready() {
+            return html! {};
+        }
+
+        let view_session = |id: usize| {
+            let log = match self.sessions.get(&id) {
+                Some(session) => &session.main,
+                None => &self.main,
+            };
+            let busy = matches!(
+                log.status,
+                Status::Fetching { .. } | Status::Extracting { .. }
+            );
+
+            html! {
+                <div class="flex">
+                    <Button
+                        classes={classes!("rounded-l-2xl")}
+                        size={ButtonSize::Small}
+                        on_click={ctx.link().callback(move |_| Msg::SwitchSession(id))}
+                        active={id == self.session_id}
+                        role="tab"
+                        icon={busy.then(|| classes!("fas", "fa-spinner", "fa-spin"))}
+                        text={self.session_title(log)}
+                    />
+                    <Button
+                        classes={classes!("rounded-r-2xl")}
+                        size={ButtonSize::Small}
+                        on_click={ctx.link().callback(move |_| Msg::CloseSession(id))}
+                        icon={classes!("fas", "fa-times")}
+                        title={tr(Text::CloseTab)}
+                    />
+                </div>
+            }
+        };
+
+        html! {
+            <div class="flex flex-wrap gap-2 mx-4 mb-8 print:hidden" role="tablist" aria-label={tr(Text::OpenDebugLogs)}>
+                { for self.session_ids().into_iter().map(view_session) }
+                <Button
+                    classes={classes!("rounded-2xl")}
+                    size={ButtonSize::Small}
+                    on_click={ctx.link().callback(|_| Msg::OpenSession)}
+                    icon={classes!("fas", "fa-plus")}
+                    title={tr(Text::NewTab)}
+                />
+            </div>
+        }
+    }
+
+    /// Name of the tab of a session: the label of its debug log in the history, or else the
+    /// debug log's platform and the start of its key.
+    fn session_title(&self, log: &DebugLog) -> String {
+        let reference = match &log.remote_object {
+            Some(reference) => reference,
+            None => return tr(Text::NewTab).to_owned(),
+        };
+
+        let url = reference.debuglogs_url();
+        match self
+            .history
+            .entries()
+            .iter()
+            .find(|entry| entry.url == url && !entry.label.is_empty())
+        {
+            Some(entry) => entry.label.clone(),
+            None => format!(
+                "{} {}",
+                reference.platform(),
+                reference.key().chars().take(8).collect::<String>()
+            ),
+        }
+    }
+
+    /// Details of the log entry that was opened from the Logs tab, if any.
+    fn view_entry_details(&self, ctx: &Context<Self>) -> Html {
+        let (side, id) = match &self.entry_details {
+            Some(entry_details) => entry_details,
+            None => return html! {},
+        };
+
+        match self.log(*side) {
+            Some(log) if log.state.is_ready() => html! {
+                <Modal
+                    classes={classes!("!max-w-4xl")}
+                    heading={tr(Text::LogEntry)}
+                    on_close={ctx.link().callback(|_| Msg::CloseModal)}
+                >
+                    {
+                        log.active_file().view_entry_details(
+                            id,
+                            self.redact,
+                            &ctx.link().batch_callback(|(tab, id)| {
+                                vec![Msg::CloseModal, Msg::ShowElement(tab, id)]
+                            }),
+                        )
+                    }
+                </Modal>
+            },
+            _ => html! {},
+        }
+    }
+
+    /// Floating buttons to go to the start or end of the logs; the raw text has its own.
+    fn view_scroll_buttons(&self) -> Html {
+        if !self.tab.is_logs() || !self.logs().any(|(_, log)| log.state.is_ready()) {
+            return html! {};
+        }
+
+        html! {
+            <div class={classes!("fixed", "bottom-32", "right-4", "z-20", "flex", "flex-col", "gap-2", "print:hidden")}>
+                <Button
+                    classes={classes!("rounded-2xl", "shadow")}
+                    on_click={Callback::from(|_| scroll_page(false))}
+                    icon={classes!("fas", "fa-angle-double-up")}
+                    title={tr(Text::GoToTop)}
+                />
+                <Button
+                    classes={classes!("rounded-2xl", "shadow")}
+                    on_click={Callback::from(|_| scroll_page(true))}
+                    icon={classes!("fas", "fa-angle-double-down")}
+                    title={tr(Text::GoToBottom)}
+                />
+            </div>
+        }
+    }
+
+    pub fn view_log(
+        &self,
+        side: Side,
+        log: &DebugLog,
+        show_file: bool,
+        ctx: &Context<Self>,
+    ) -> Html {
+        let file_picker = match &log.state {
             State::Ready(Object::Multiple {
                 files,
-                active_filename,
+                active_filenames,
+                ..
             }) => html! {
                 <FilePicker
                     classes={classes!("mb-8")}
-                    files={files.keys().cloned().collect::<Vec<_>>()}
-                    selected_file={active_filename}
-                    on_file_selected={ctx.link().callback(Msg::UpdateActiveFile)}
+                    files={files
+                        .iter()
+                        .map(|(name, file)| (Rc::clone(name), file.summary().clone()))
+                        .collect::<Vec<_>>()}
+                    selected_files={active_filenames.clone()}
+                    on_selection_changed={ctx.link().callback(move |filenames| {
+                        Msg::Log(side, LogMsg::UpdateActiveFiles(filenames))
+                    })}
                 />
             },
             _ => html! {},
         };
 
-        let active_file = match &self.state {
-            State::Ready(_) => self.active_file().view(self.tab, &self.active_query),
+        let active_file = match &log.state {
+            State::Ready(Object::Multiple { files, .. })
+                if show_file && self.search_all_files && self.tab == Tab::Logs =>
+            {
+                html! {
+                    <>
+                        <Title level={TitleLevel::H1} text={tr(Text::SearchResults)}/>
+                        {
+                            for files.iter().map(|(filename, file)| {
+                                let filename = Rc::clone(filename);
+
+                                file.view_search_results(
+                                    &self.active_query,
+                                    ctx.link().callback(move |id| {
+                                        Msg::Log(side, LogMsg::ShowEntry(Rc::clone(&filename), id))
+                                    }),
+                                )
+                            })
+                        }
+                    </>
+                }
+            }
+            State::Ready(_) if show_file => log.active_file().view(
+                self.tab,
+                &self.active_query,
+                &parsers::InfoViewOptions {
+                    filter: self.info_filter.clone(),
+                    collapsed_groups: if self.printing {
+                        BTreeSet::new()
+                    } else {
+                        self.collapsed_info_groups.clone()
+                    },
+                    on_set_groups_collapsed: ctx
+                        .link()
+                        .callback(|(ids, collapsed)| Msg::SetInfoGroupsCollapsed(ids, collapsed)),
+                    pinned_keys: self.pinned_keys.clone(),
+                    on_toggle_pinned_key: ctx.link().callback(Msg::TogglePinnedKey),
+                },
+                &parsers::LogViewOptions {
+                    text: Default::default(),
+                    version: None,
+                    id_prefix: String::new(),
+                    redact: self.redact,
+                    highlight_sensitive: self.highlight_sensitive,
+                    linkify_urls: self.linkify_urls,
+                    newest_first: self.newest_first,
+                    selected_entries: self.selected_entries.clone(),
+                    on_click_entry: ctx
+                        .link()
+                        .callback(move |(id, mode)| Msg::Log(side, LogMsg::ClickEntry(id, mode))),
+                    on_show_raw_line: ctx.link().callback(Msg::GoToLine),
+                    collapsed_sections: if self.printing {
+                        BTreeSet::new()
+                    } else {
+                        self.collapsed_log_sections.clone()
+                    },
+                    on_set_sections_collapsed: ctx.link().callback(|(names, collapsed)| {
+                        Msg::SetLogSectionsCollapsed(names, collapsed)
+                    }),
+                    context_entries: if self.printing {
+                        BTreeSet::new()
+                    } else {
+                        self.context_entries.clone()
+                    },
+                    context_lines: self.settings.context_lines,
+                    bookmarks: self.bookmarks.clone(),
+                    on_toggle_bookmark: ctx.link().callback(Msg::ToggleBookmark),
+                    annotations: log.annotations.clone(),
+                    editing_annotation: self.editing_annotation.clone(),
+                    on_edit_annotation: ctx.link().callback(Msg::EditAnnotation),
+                    on_update_annotation: ctx.link().callback(move |(id, note)| {
+                        Msg::Log(side, LogMsg::UpdateAnnotation(id, note))
+                    }),
+                    on_show_details: ctx
+                        .link()
+                        .callback(move |id| Msg::ShowEntryDetails(side, id)),
+                    hidden_columns: self.settings.hidden_columns(),
+                    on_toggle_column: ctx.link().callback(Msg::ToggleLogColumn),
+                    wrap_messages: self.settings.wrap_log_messages || self.printing,
+                    column_widths: self.log_column_widths.clone(),
+                    on_resize_column: ctx
+                        .link()
+                        .callback(|(column, width)| Msg::ResizeLogColumn(column, width)),
+                    compact: self.settings.compact_log_rows,
+                    text_size: self.settings.text_size,
+                    toaster: self.toaster.clone(),
+                    on_toggle_context: ctx.link().callback(Msg::ToggleEntryContext),
+                },
+                &RawViewOptions {
+                    line: self.raw_line,
+                    query: self.active_raw_query.clone(),
+                    current_match: self.raw_match,
+                    on_show_line_in_logs: ctx
+                        .link()
+                        .callback(move |line| Msg::Log(side, LogMsg::ShowLine(line))),
+                    text_size: self.settings.text_size,
+                },
+                &ctx.link().callback(|(tab, id)| Msg::ShowElement(tab, id)),
+            ),
             _ => html! {},
         };
 
-        let mut wrapper_classes = classes!("mb-4", "py-4", "bg-brand-bg", "dark:bg-brand-dark-bg");
-
-        if !self.ui_expanded {
-            wrapper_classes.push(classes!(
-                "max-w-5xl",
-                "mx-auto",
-                "lg:mt-4",
-                "lg:rounded-2xl",
-            ));
-        }
-
         html! {
-            <>
-                <div class={wrapper_classes}>
-                    <div class="mx-4">
-                        { self.view_main_input(ctx) }
-                        { self.view_help(ctx) }
-
-                        { file_picker }
-                    </div>
+            <div class="min-w-0">
+                <div class="mx-4 print:hidden">
+                    {
+                        if self.embedded {
+                            html! {}
+                        } else {
+                            self.view_main_input(side, log, ctx)
+                        }
+                    }
+                    { self.view_help(side, log, ctx) }
 
-                    <div class="mx-4 prose dark:prose-invert prose-sm max-w-max mt-8">
-                        { active_file }
-                    </div>
+                    { file_picker }
                 </div>
 
-                { self.view_footer() }
+                <div class="mx-4 prose dark:prose-invert prose-sm max-w-max mt-8">
+                    { active_file }
+                </div>
 
-                { self.view_display_config(ctx) }
-            </>
+                { self.view_metrics(log) }
+            </div>
         }
     }
 
-    pub fn view_main_input(&self, ctx: &Context<Self>) -> Html {
+    /// Sizes and parse time of the loaded debug log, and its number of log entries.
+    fn view_metrics(&self, log: &DebugLog) -> Html {
+        let metrics = match (&log.state, &log.status, log.metrics) {
+            (State::Ready(_), Status::Idle, Some(metrics)) => metrics,
+            _ => return html! {},
+        };
+
+        let fetched = match metrics.received {
+            Some(received) => format!("{} {}", tr(Text::Fetched), format_size(received)),
+            None => tr(Text::FromCache).to_owned(),
+        };
+
         html! {
-            <div class="flex mb-8">
-                <Input
-                    ref={self.debug_log_input.clone()}
-                    classes={classes!("rounded-l-2xl")}
-                    value={self.debug_log_url.clone()}
-                    on_change={ctx.link().callback(Msg::UpdateUrl)}
-                    on_submit_maybe={ctx.link().batch_callback(|actually: bool| actually.then(|| Msg::Start))}
-                    placeholder="https://debuglogs.org/..."
-                    disabled={self.state.is_fetching()}
-                    autofocus={true}
+            <p class="mx-4 mt-8 text-xs opacity-75" role="status">
+                {
+                    [
+                        fetched,
+                        format!("{} {}", format_size(metrics.size), tr(Text::OfText)),
+                        format!("{} {:.0} ms", tr(Text::ParsedIn), metrics.parse_ms),
+                        format!("{} {}", log.entry_count(), tr(Text::LogEntriesCount)),
+                    ]
+                    .join(" · ")
+                }
+            </p>
+        }
+    }
+
+    pub fn view_main_input(&self, side: Side, log: &DebugLog, ctx: &Context<Self>) -> Html {
+        let compare_button = match side {
+            Side::Main => html! {
+                <Button
+                    size={ButtonSize::Large}
+                    on_click={ctx.link().callback(|_| Msg::UpdateComparison)}
+                    active={self.comparison.is_some()}
+                    icon={classes!("fas", "fa-columns")}
+                    text={tr(if self.comparison.is_some() {
+                        Text::StopComparing
+                    } else {
+                        Text::Compare
+                    })}
                 />
+            },
+            Side::Comparison => html! {},
+        };
+
+        let valid = log.parse_url(&self.settings.allowed_hosts()).is_ok();
+
+        let on_paste = {
+            let start_on_paste = self.settings.start_on_paste && log.url.trim().is_empty();
+            let allowed_hosts = self
+                .settings
+                .allowed_hosts()
+                .into_iter()
+                .map(str::to_owned)
+                .collect::<Vec<_>>();
+
+            ctx.link().batch_callback(move |event: Event| {
+                let text = pasted_text(&event).unwrap_or_default();
+                let allowed_hosts = allowed_hosts.iter().map(String::as_str).collect::<Vec<_>>();
+
+                if !start_on_paste || RemoteObject::from_url(&text, &allowed_hosts, None).is_err() {
+                    return vec![];
+                }
 
-                { self.view_submit_button(ButtonSize::Large, ctx) }
+                event.prevent_default();
+                vec![
+                    Msg::Log(side, LogMsg::UpdateUrl(text.trim().to_owned())),
+                    Msg::Log(side, LogMsg::Start),
+                ]
+            })
+        };
+
+        html! {
+            <div class="mb-8">
+                <div class="flex">
+                    <Input
+                        ref={log.input.clone()}
+                        classes={classes!("rounded-l-2xl")}
+                        value={log.url.clone()}
+                        on_change={ctx.link().callback(move |url| Msg::Log(side, LogMsg::UpdateUrl(url)))}
+                        on_submit_maybe={ctx.link().batch_callback(move |actually: bool| {
+                            if actually && valid {
+                                Some(Msg::Log(side, LogMsg::Start))
+                            } else {
+                                None
+                            }
+                        })}
+                        {on_paste}
+                        placeholder="https://debuglogs.org/..."
+                        autofocus={side == Side::Main}
+                    />
+
+                    { self.view_platform_select(side, log, ctx) }
+                    { self.view_submit_button(ButtonSize::Large, side, log, ctx) }
+                    { compare_button }
+                </div>
+                { self.view_url_hint(log) }
             </div>
         }
     }
 
-    pub fn view_help(&self, ctx: &Context<Self>) -> Html {
-        match &self.state {
-            State::NoData => html! {
+    pub fn view_help(&self, side: Side, log: &DebugLog, ctx: &Context<Self>) -> Html {
+        match (&log.state, &log.status) {
+            // Debug logs are loaded by the embedding page instead.
+            (State::NoData, Status::Idle) if !self.embedded => html! {
+                <>
                 <Message>
-                    { "Please enter a Signal " }
-                    <Link href="https://support.signal.org/hc/en-us/articles/360007318591" text="debug log"/>
-                    {" URL and press " }
-                    <span>{ self.view_submit_button(ButtonSize::Small, ctx) }</span>
-                    { " or " }
+                    {
+                        match side {
+                            Side::Main => tr(Text::EnterDebugLog),
+                            Side::Comparison => tr(Text::EnterAnotherDebugLog),
+                        }
+                    }
+                    <Link href="https://support.signal.org/hc/en-us/articles/360007318591" text={tr(Text::DebugLog)}/>
+                    { tr(Text::UrlAndPress) }
+                    <span>{ self.view_submit_button(ButtonSize::Small, side, log, ctx) }</span>
+                    { tr(Text::Or) }
                     <Badge classes={classes!("bg-brand-bg", "dark:bg-brand-dark-bg")} text="Enter ⏎" />
                     { "." }
                 </Message>
+                if side == Side::Main {
+                    { self.view_history(ctx) }
+                }
+                </>
             },
-            State::Fetching => html! {
+            (
+                _,
+                Status::Fetching {
+                    retries,
+                    progress,
+                    proxy,
+                    ..
+                },
+            ) => html! {
                 <Message
-                    heading="Progress"
-                    text="Fetching and parsing..."
-                    classes={classes!("animate-pulse")}
-                />
+                    heading={tr(Text::Progress)}
+                    text={match retries {
+                        0 => tr(Text::FetchingAndParsing).to_owned(),
+                        _ => format!("{} ({} {}/{})", tr(Text::FetchingAndParsing), tr(Text::Retry), retries, fetch::MAX_RETRIES),
+                    }}
+                    classes={classes!("mb-8")}
+                >
+                    {
+                        match proxy {
+                            Some(proxy) => html! {
+                                <p>{ format!("{} {}", tr(Text::FetchingThroughProxy), proxy) }</p>
+                            },
+                            None => html! {},
+                        }
+                    }
+                    { progress.as_ref().map(view_progress).unwrap_or_default() }
+                    <Button
+                        classes={classes!("rounded-2xl")}
+                        size={ButtonSize::Small}
+                        icon={classes!("fas", "fa-stop")}
+                        text={tr(Text::Cancel)}
+                        on_click={ctx.link().callback(move |_| Msg::Log(side, LogMsg::Cancel))}
+                    />
+                </Message>
             },
-            State::Error(e) => html! {
-                <Message error={true} heading="Error">
-                    <CodeBlock text={Rc::new(format!("Error: {:?}", e))}/>
+            (
+                _,
+                Status::Extracting {
+                    count, extracted, ..
+                },
+            ) => html! {
+                <Message heading={tr(Text::Progress)} classes={classes!("mb-8")}>
+                    <p>
+                        { format!("{} {} / {}", tr(Text::ExtractingFiles), extracted, count) }
+                        <progress class="block w-full" max={count.to_string()} value={extracted.to_string()} />
+                    </p>
+                    <Button
+                        classes={classes!("rounded-2xl")}
+                        size={ButtonSize::Small}
+                        icon={classes!("fas", "fa-stop")}
+                        text={tr(Text::Cancel)}
+                        on_click={ctx.link().callback(move |_| Msg::Log(side, LogMsg::Cancel))}
+                    />
                 </Message>
             },
+            (_, Status::Oversized { size, .. }) => {
+                let limit = self.settings.large_log_limit_mib.unwrap_or_default();
+
+                html! {
+                    <Message classes={classes!("mb-8")} warning={true} heading={tr(Text::LargeDebugLog)}>
+                        <p>{ format!("{} {}", tr(Text::LargeDebugLogWarning), format_size(*size)) }</p>
+                        <div class="flex gap-2">
+                            <Button
+                                classes={classes!("rounded-2xl")}
+                                size={ButtonSize::Small}
+                                text={format!("{} {} MiB", tr(Text::LoadLast), limit)}
+                                on_click={ctx.link().callback(move |_| Msg::Log(side, LogMsg::LoadOversized(true)))}
+                            />
+                            <Button
+                                classes={classes!("rounded-2xl")}
+                                size={ButtonSize::Small}
+                                text={tr(Text::LoadEverything)}
+                                on_click={ctx.link().callback(move |_| Msg::Log(side, LogMsg::LoadOversized(false)))}
+                            />
+                            <Button
+                                classes={classes!("rounded-2xl")}
+                                size={ButtonSize::Small}
+                                icon={classes!("fas", "fa-times")}
+                                text={tr(Text::Cancel)}
+                                on_click={ctx.link().callback(move |_| Msg::Log(side, LogMsg::Cancel))}
+                            />
+                        </div>
+                    </Message>
+                }
+            }
+            (_, Status::Failed(e)) => {
+                let fetch_error = e.downcast_ref::<FetchError>();
+
+                html! {
+                    <Message classes={classes!("mb-8")} error={true} heading={tr(Text::Error)}>
+                        {
+                            match fetch_error {
+                                Some(fetch_error) => html! { <p>{ tr(fetch_error.guidance()) }</p> },
+                                None => html! {},
+                            }
+                        }
+                        <CodeBlock text={Rc::new(format!("{}: {:?}", tr(Text::Error), e))}/>
+                        {
+                            if log.state.is_ready() {
+                                html! { <p>{ tr(Text::PreviousLogKept) }</p> }
+                            } else {
+                                html! {}
+                            }
+                        }
+                        <div class="flex gap-2">
+                            {
+                                if fetch_error.is_some_and(FetchError::is_transient) {
+                                    html! {
+                                        <Button
+                                            classes={classes!("rounded-2xl")}
+                                            size={ButtonSize::Small}
+                                            icon={classes!("fas", "fa-redo")}
+                                            text={tr(Text::TryAgain)}
+                                            on_click={ctx.link().callback(move |_| Msg::Log(side, LogMsg::Start))}
+                                        />
+                                    }
+                                } else {
+                                    html! {}
+                                }
+                            }
+                            <Button
+                                classes={classes!("rounded-2xl")}
+                                size={ButtonSize::Small}
+                                icon={classes!("fas", "fa-times")}
+                                text={tr(Text::Dismiss)}
+                                on_click={ctx.link().callback(move |_| Msg::Log(side, LogMsg::DismissError))}
+                            />
+                        </div>
+                    </Message>
+                }
+            }
             _ => html! {},
         }
     }
 
-    pub fn view_submit_button(&self, size: ButtonSize, ctx: &Context<Self>) -> Html {
+    /// Starts fetching, once the entered URL can be parsed.
+    pub fn view_submit_button(
+        &self,
+        size: ButtonSize,
+        side: Side,
+        log: &DebugLog,
+        ctx: &Context<Self>,
+    ) -> Html {
         html! {
             <Button
                 {size}
-                on_click={ctx.link().callback(|_| Msg::Start)}
-                disabled={self.state.is_fetching()}
-                text="Read"
+                disabled={log.parse_url(&self.settings.allowed_hosts()).is_err()}
+                on_click={ctx.link().callback(move |_| Msg::Log(side, LogMsg::Start))}
+                text={tr(Text::Read)}
             />
         }
     }
 
+    /// What the entered URL was recognized as, or why it can't be read.
+    fn view_url_hint(&self, log: &DebugLog) -> Html {
+        if log.url.trim().is_empty() {
+            return html! {};
+        }
+
+        let (text, classes) = match log.parse_url(&self.settings.allowed_hosts()) {
+            Ok(reference) if reference.is_direct() => {
+                (tr(Text::RecognizedDirectUrl).to_owned(), classes!())
+            }
+            Ok(reference) => (
+                match reference.version() {
+                    Some(version) => format!(
+                        "{} {} {}",
+                        tr(Text::RecognizedDebugLog),
+                        reference.platform(),
+                        version
+                    ),
+                    None => format!("{} {}", tr(Text::RecognizedDebugLog), reference.platform()),
+                },
+                classes!(),
+            ),
+            Err(e) => (
+                format!("{} {}", tr(Text::UrlNotRecognized), e),
+                classes!("text-red-600", "dark:text-red-400"),
+            ),
+        };
+
+        html! {
+            <p class={classes!("mt-1", "px-4", "text-sm", classes)} aria-live="polite">{ text }</p>
+        }
+    }
+
     pub fn view_display_config(&self, ctx: &Context<Self>) -> Html {
-        if !self.state.is_ready() {
+        if !self.logs().any(|(_, log)| log.state.is_ready()) {
             return html! {};
         }
 
         html! {
             <Toolbar
-                classes_outer={classes!("fixed", "bottom-0")}
+                classes_outer={classes!("fixed", "bottom-0", "print:hidden")}
                 classes_mid={classes!("rounded-t-2xl")}
+                label={tr(Text::DisplayOptions)}
             >
                 <div class="flex flex-col gap-y-2 grow">
                     { self.view_search_toolbar_row(ctx) }
 
                     <div class="flex grow">
-                        <div class="flex grow">
+                        <div
+                            class="flex grow"
+                            role="tablist"
+                            aria-label={tr(Text::Tabs)}
+                            onkeydown={Callback::from(select_adjacent_tab)}
+                        >
                             { for Tab::iter().map(|tab| self.view_tab_button(tab, ctx)) }
                         </div>
 
-                        <div>
+                        { self.view_section_select(ctx) }
+
+                        <div class="flex">
+                            <Button
+                                classes={classes!("ml-2", "rounded-2xl")}
+                                on_click={ctx.link().callback(|_| Msg::UpdateSensitiveHighlighting)}
+                                active={self.highlight_sensitive}
+                                icon={classes!("fas", "fa-highlighter")}
+                                text={tr(Text::HighlightIdentifiers)}
+                            />
+
+                            <Button
+                                classes={classes!("ml-2", "rounded-2xl")}
+                                on_click={ctx.link().callback(|_| Msg::UpdateSettingsShown)}
+                                active={self.settings_shown}
+                                icon={classes!("fas", "fa-cog")}
+                                title={tr(Text::Settings)}
+                            />
+
+                            <Button
+                                classes={classes!("ml-2", "rounded-2xl")}
+                                on_click={ctx.link().callback(|_| Msg::UpdateShortcutsShown)}
+                                active={self.shortcuts_shown}
+                                icon={classes!("fas", "fa-keyboard")}
+                                title={format!("{} (?)", tr(Text::KeyboardShortcuts))}
+                            />
+
+                            <Button
+                                classes={classes!("ml-2", "rounded-2xl")}
+                                on_click={ctx.link().callback(|_| Msg::Print)}
+                                icon={classes!("fas", "fa-print")}
+                                title={tr(Text::Print)}
+                            />
+
+                            {
+                                if self.settings.remember_annotations || self.logs().any(|(_, log)| !log.annotations.is_empty()) {
+                                    html! {
+                                        <Button
+                                            classes={classes!("ml-2", "rounded-2xl")}
+                                            on_click={ctx.link().callback(|_| Msg::UpdateAnnotationRemembering)}
+                                            active={self.settings.remember_annotations}
+                                            icon={classes!("fas", "fa-sticky-note")}
+                                            text={tr(Text::RememberNotes)}
+                                        />
+                                    }
+                                } else {
+                                    html! {}
+                                }
+                            }
+
+                            <Button
+                                classes={classes!("ml-2", "rounded-2xl")}
+                                on_click={ctx.link().callback(|_| Msg::UpdateUrlLinking)}
+                                active={self.linkify_urls}
+                                icon={classes!("fas", "fa-link")}
+                                text={tr(Text::Links)}
+                            />
+
+                            <Button
+                                classes={classes!("ml-2", "rounded-2xl")}
+                                on_click={ctx.link().callback(|_| Msg::UpdateMessageWrapping)}
+                                active={self.settings.wrap_log_messages}
+                                icon={classes!("fas", "fa-paragraph")}
+                                text={tr(Text::Wrap)}
+                            />
+
+                            <Button
+                                classes={classes!("ml-2", "rounded-2xl")}
+                                on_click={ctx.link().callback(|_| Msg::UpdateRedaction)}
+                                active={self.redact}
+                                icon={classes!("fas", "fa-user-secret")}
+                                text={tr(if self.redact {
+                                    Text::Redacting
+                                } else {
+                                    Text::Redact
+                                })}
+                            />
+
                             <Button
                                 classes={classes!(
                                     "hidden",
-                                    "lg:block",
+                                    (!self.embedded).then_some("lg:block"),
                                     "ml-2",
+                                    "rounded-2xl",
                                 )}
                                 on_click={ctx.link().callback(|_| Msg::UpdateUiExpanded)}
                                 icon={classes!("fas", if self.ui_expanded {
@@ -152,22 +815,105 @@ impl super::Model {
                                 } else {
                                     "fa-expand-alt"
                                 })}
-                                text={if self.ui_expanded {
-                                    "Collapse UI"
+                                text={tr(if self.ui_expanded {
+                                    Text::CollapseUi
                                 } else {
-                                    "Expand UI"
-                                }.to_owned()}
+                                    Text::ExpandUi
+                                })}
                             />
                         </div>
                     </div>
+
+                    { self.view_coverage() }
                 </div>
             </Toolbar>
         }
     }
 
+    /// Table of contents of the current tab of the main log, to jump to one of its sections.
+    fn view_section_select(&self, ctx: &Context<Self>) -> Html {
+        if !self.main.state.is_ready() {
+            return html! {};
+        }
+
+        let outline = self.main.active_file().outline(self.tab);
+
+        if outline.len() < 2 {
+            return html! {};
+        }
+
+        let classes = classes!(
+            "ml-2",
+            "max-w-[12rem]",
+            "rounded-2xl",
+            "border-brand-border",
+            "dark:border-brand-dark-border",
+            "shadow-sm",
+            "focus:border-brand-border",
+            "dark:focus:border-brand-dark-border",
+            "focus:ring",
+            "focus:ring-brand-focus",
+            "dark:focus:ring-brand-dark-focus",
+            "focus:ring-opacity-50",
+            "transition",
+            "duration-200",
+            "bg-brand-bg",
+            "dark:bg-brand-dark-bg",
+        );
+
+        html! {
+            <select
+                onchange={ctx.link().callback(|event: Event| {
+                    let select = event.target().unwrap().dyn_into::<HtmlSelectElement>().unwrap();
+                    let id = select.value();
+
+                    // Reset, so that the same section can be jumped to again.
+                    select.set_value("");
+
+                    Msg::JumpToSection(id)
+                })}
+                class={classes}
+            >
+                <option value="" selected={true} disabled={true}>{ tr(Text::JumpToSection) }</option>
+                {
+                    for outline.iter().map(|entry| html! {
+                        <option value={entry.id.clone()}>
+                            { format!("{}{} ({})", "\u{a0}\u{a0}".repeat(entry.depth), entry.name, entry.count) }
+                        </option>
+                    })
+                }
+            </select>
+        }
+    }
+
+    /// Entry count and covered time span of the shown file of every loaded log.
+    fn view_coverage(&self) -> Html {
+        let coverage = self
+            .logs()
+            .filter(|(_, log)| log.state.is_ready())
+            .filter_map(|(side, log)| {
+                let coverage = log.active_file().summary().coverage()?;
+
+                Some(match (side, &self.comparison) {
+                    (_, None) => coverage,
+                    (Side::Main, Some(_)) => format!("{}: {}", tr(Text::Main), coverage),
+                    (Side::Comparison, Some(_)) => {
+                        format!("{}: {}", tr(Text::Comparison), coverage)
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        html! {
+            <div class={classes!("flex", "flex-col", "text-xs", "text-center")}>
+                { for coverage.iter().map(|coverage| html! { <span>{ coverage }</span> }) }
+            </div>
+        }
+    }
+
     pub fn view_search_toolbar_row(&self, ctx: &Context<Self>) -> Html {
-        match (&self.state, &self.tab) {
-            (State::Ready(_), Tab::Logs) => {
+        match &self.tab {
+            Tab::Logs => {
                 let min_log_level_classes = classes!(
                     self.pending_query.min_log_level.color(),
                     "rounded-l-2xl",
@@ -188,47 +934,684 @@ impl super::Model {
                 );
 
                 html! {
-                    <div class="flex grow">
-                        <select
-                            value={self.pending_query.min_log_level.to_string()}
-                            onchange={ctx.link().callback(|event: Event|
-                                Msg::UpdateMinLogLevel(event.target().unwrap().dyn_into::<HtmlSelectElement>().unwrap().value())
-                            )}
-                            class={min_log_level_classes}
-                        >
+                    <div class="flex grow gap-4">
+                        <div class="flex grow">
+                            <select
+                                value={self.pending_query.min_log_level.to_string()}
+                                onchange={ctx.link().callback(|event: Event|
+                                    Msg::UpdateMinLogLevel(event.target().unwrap().dyn_into::<HtmlSelectElement>().unwrap().value())
+                                )}
+                                class={min_log_level_classes}
+                            >
+                                {
+                                    for LogLevel::iter()
+                                        .filter(|variant| {
+                                            self.logs()
+                                                .filter_map(|(_, log)| log.remote_object.as_ref())
+                                                .any(|remote_object| variant.applicable_to_platform(remote_object.platform()))
+                                        })
+                                        .map(|variant| html! {
+                                            <option selected={variant == self.pending_query.min_log_level}>{ variant }</option>
+                                        })
+                                }
+                            </select>
+
+                            <Input
+                                ref={self.search_input.clone()}
+                                value={self.pending_query.string.clone()}
+                                on_change={ctx.link().callback(Msg::UpdateQuery)}
+                                on_submit_maybe={ctx.link().batch_callback(|actually: bool| actually.then_some(Msg::ApplySearchQuery))}
+                                placeholder={i18n::search_logs_placeholder(&self.pending_query.min_log_level.to_string())}
+                            />
+
+                            { self.view_section_scope_filter(ctx) }
+                            { self.view_android_filters(ctx) }
+                            { self.view_desktop_filters(ctx) }
+
+                            <Button
+                                on_click={ctx.link().callback(|_| Msg::ApplySearchQuery)}
+                                icon={classes!("fas", if self.pending_query == self.active_query {
+                                    "fa-check"
+                                } else {
+                                    "fa-search"
+                                })}
+                                disabled={self.pending_query == self.active_query}
+                            />
+
                             {
-                                for LogLevel::iter()
-                                    .filter(|variant| variant.applicable_to_platform(self.remote_object.as_ref().unwrap().platform()))
-                                    .map(|variant| html! {
-                                        <option selected={variant == self.pending_query.min_log_level}>{ variant }</option>
-                                    })
+                                if self.logs().any(|(_, log)| matches!(log.state, State::Ready(Object::Multiple { .. }))) {
+                                    html! {
+                                        <Button
+                                            on_click={ctx.link().callback(|_| Msg::UpdateSearchAllFiles)}
+                                            active={self.search_all_files}
+                                            icon={classes!("fas", "fa-folder-open")}
+                                            text={tr(Text::AllFiles)}
+                                        />
+                                    }
+                                } else {
+                                    html! {}
+                                }
                             }
-                        </select>
 
-                        <Input
-                            value={self.pending_query.string.clone()}
-                            on_change={ctx.link().callback(Msg::UpdateQuery)}
-                            on_submit_maybe={ctx.link().batch_callback(|actually: bool| actually.then(|| Msg::ApplySearchQuery))}
-                            placeholder={
-                                "Search ".to_owned()
-                                    + &self.pending_query.min_log_level.to_string().to_lowercase()
-                                    + " logs..."
+                            {
+                                if self.comparison.is_none() && self.main.state.is_ready() {
+                                    self.main.active_file().view_csv_export_button(&self.active_query, self.redact)
+                                } else {
+                                    html! {}
+                                }
                             }
+                        </div>
+
+                        <Button
+                            classes={classes!("rounded-2xl")}
+                            on_click={ctx.link().callback(|_| Msg::UpdateSortOrder)}
+                            active={self.newest_first}
+                            icon={classes!("fas", if self.newest_first {
+                                "fa-sort-amount-up"
+                            } else {
+                                "fa-sort-amount-down-alt"
+                            })}
+                            text={tr(Text::NewestFirst)}
                         />
 
+                        { self.view_context_lines_select(ctx) }
+
+                        { self.view_entry_jump_buttons(ctx) }
+                        { self.view_entry_number_input(ctx) }
+                    </div>
+                }
+            }
+            Tab::Raw => html! {
+                <div class="flex grow gap-4">
+                    <div class="flex grow">
+                        <Input
+                            ref={self.search_input.clone()}
+                            classes={classes!("rounded-l-2xl")}
+                            value={self.pending_raw_query.clone()}
+                            on_change={ctx.link().callback(Msg::UpdateRawQuery)}
+                            on_submit_maybe={ctx.link().batch_callback(|actually: bool| {
+                                if actually {
+                                    Some(Msg::ApplyRawQuery)
+                                } else {
+                                    None
+                                }
+                            })}
+                            placeholder={tr(Text::SearchRawText)}
+                        />
                         <Button
-                            on_click={ctx.link().callback(|_| Msg::ApplySearchQuery)}
-                            icon={classes!("fas", if self.pending_query == self.active_query {
-                                "fa-check"
+                            on_click={ctx.link().callback(|_| Msg::MoveRawMatch(-1))}
+                            icon={classes!("fas", "fa-chevron-up")}
+                            disabled={self.active_raw_query.is_empty()}
+                        />
+                        <Button
+                            classes={classes!("rounded-r-2xl")}
+                            on_click={ctx.link().callback(|_| Msg::ApplyRawQuery)}
+                            icon={classes!("fas", if self.pending_raw_query == self.active_raw_query {
+                                "fa-chevron-down"
                             } else {
                                 "fa-search"
                             })}
-                            disabled={self.pending_query == self.active_query}
                         />
                     </div>
+
+                    <div class="flex">
+                        <Input
+                            classes={classes!("rounded-l-2xl")}
+                            value={self.pending_raw_line.clone()}
+                            on_change={ctx.link().callback(Msg::UpdateRawLine)}
+                            on_submit_maybe={ctx.link().batch_callback(|actually: bool| {
+                                if actually {
+                                    Some(Msg::ApplyRawLine)
+                                } else {
+                                    None
+                                }
+                            })}
+                            placeholder={tr(Text::GoToLine)}
+                        />
+                        <Button
+                            classes={classes!("rounded-r-2xl")}
+                            on_click={ctx.link().callback(|_| Msg::ApplyRawLine)}
+                            icon={classes!("fas", "fa-arrow-right")}
+                        />
+                    </div>
+                </div>
+            },
+            Tab::Information => html! {
+                <div class="flex grow">
+                    <Input
+                        ref={self.search_input.clone()}
+                        classes={classes!("rounded-2xl")}
+                        value={self.info_filter.clone()}
+                        on_change={ctx.link().callback(Msg::UpdateInfoFilter)}
+                        placeholder={tr(Text::FilterInformation)}
+                    />
+                </div>
+            },
+        }
+    }
+
+    /// A dialog with all settings, which are remembered in the browser.
+    fn view_settings(&self, ctx: &Context<Self>) -> Html {
+        if !self.settings_shown {
+            return html! {};
+        }
+
+        let option = |text: String, active: bool, settings: Settings| {
+            html! {
+                <Button
+                    classes={classes!("mr-1", "rounded-2xl")}
+                    size={ButtonSize::Small}
+                    {active}
+                    {text}
+                    on_click={ctx.link().callback(move |_| Msg::UpdateSettings(settings.clone()))}
+                />
+            }
+        };
+        let toggle = |enabled: bool, change: fn(&mut Settings)| {
+            let mut settings = self.settings.clone();
+            change(&mut settings);
+
+            option(
+                tr(if enabled { Text::On } else { Text::Off }).to_owned(),
+                enabled,
+                settings,
+            )
+        };
+
+        let current = &self.settings;
+        let rows = [
+            (
+                Text::Language,
+                html! {
+                    for Language::iter().map(|language| option(
+                        language.native_name().to_owned(),
+                        current.language == language,
+                        Settings { language, ..current.clone() },
+                    ))
+                },
+            ),
+            (
+                Text::Theme,
+                html! {
+                    for Theme::iter().map(|theme| option(
+                        tr(match theme {
+                            Theme::System => Text::ThemeSystem,
+                            Theme::Light => Text::ThemeLight,
+                            Theme::Dark => Text::ThemeDark,
+                        })
+                        .to_owned(),
+                        current.theme == theme,
+                        Settings { theme, ..current.clone() },
+                    ))
+                },
+            ),
+            (
+                Text::ColorBlindLevels,
+                toggle(current.color_blind_levels, |settings| {
+                    settings.color_blind_levels = !settings.color_blind_levels;
+                }),
+            ),
+            (
+                Text::CompactRows,
+                toggle(current.compact_log_rows, |settings| {
+                    settings.compact_log_rows = !settings.compact_log_rows;
+                }),
+            ),
+            (
+                Text::TextSize,
+                html! {
+                    for TextSize::iter().map(|text_size| option(
+                        tr(match text_size {
+                            TextSize::Small => Text::TextSizeSmall,
+                            TextSize::Medium => Text::TextSizeMedium,
+                            TextSize::Large => Text::TextSizeLarge,
+                        })
+                        .to_owned(),
+                        current.text_size == text_size,
+                        Settings { text_size, ..current.clone() },
+                    ))
+                },
+            ),
+            (
+                Text::WrapLongMessages,
+                toggle(current.wrap_log_messages, |settings| {
+                    settings.wrap_log_messages = !settings.wrap_log_messages;
+                }),
+            ),
+            (
+                Text::SearchResultContext,
+                self.view_context_lines_select(ctx),
+            ),
+            (
+                Text::RedactByDefault,
+                toggle(current.redact_by_default, |settings| {
+                    settings.redact_by_default = !settings.redact_by_default;
+                }),
+            ),
+            (
+                Text::SizeLimit,
+                html! {
+                    for LARGE_LOG_LIMITS_MIB.into_iter().map(|large_log_limit_mib| option(
+                        match large_log_limit_mib {
+                            Some(mib) => format!("{} MiB", mib),
+                            None => tr(Text::NoLimit).to_owned(),
+                        },
+                        current.large_log_limit_mib == large_log_limit_mib,
+                        Settings { large_log_limit_mib, ..current.clone() },
+                    ))
+                },
+            ),
+            (
+                Text::StartOnPaste,
+                toggle(current.start_on_paste, |settings| {
+                    settings.start_on_paste = !settings.start_on_paste;
+                }),
+            ),
+            (
+                Text::AllowedHosts,
+                html! {
+                    <Input
+                        classes={classes!("w-full", "py-1", "rounded-2xl", "border")}
+                        value={current.allowed_hosts.clone()}
+                        placeholder={tr(Text::AllowedHostsPlaceholder)}
+                        on_change={ctx.link().callback(Msg::UpdateAllowedHosts)}
+                    />
+                },
+            ),
+            (
+                Text::CorsProxy,
+                html! {
+                    <Input
+                        classes={classes!("w-full", "py-1", "rounded-2xl", "border")}
+                        value={current.cors_proxy.clone()}
+                        placeholder={format!("https://proxy.example.com/?url={}", fetch::PROXY_URL_PLACEHOLDER)}
+                        on_change={ctx.link().callback(Msg::UpdateCorsProxy)}
+                    />
+                },
+            ),
+            (
+                Text::CachedDebugLogs,
+                html! {
+                    <div class="flex items-center gap-2">
+                        <span class="grow">
+                            {
+                                match self.cache_usage {
+                                    Some(usage) => format!("{} {}, {}", usage.logs, tr(Text::DebugLogsCount), format_size(usage.bytes)),
+                                    None => "…".to_owned(),
+                                }
+                            }
+                        </span>
+                        <Button
+                            classes={classes!("rounded-2xl")}
+                            size={ButtonSize::Small}
+                            icon={classes!("fas", "fa-trash")}
+                            text={tr(Text::ClearCache)}
+                            disabled={self.cache_usage.unwrap_or_default().logs == 0}
+                            on_click={ctx.link().callback(|_| Msg::ClearCache)}
+                        />
+                    </div>
+                },
+            ),
+            (
+                Text::RememberNotes,
+                html! {
+                    <Button
+                        classes={classes!("rounded-2xl")}
+                        size={ButtonSize::Small}
+                        active={current.remember_annotations}
+                        text={tr(if current.remember_annotations { Text::On } else { Text::Off })}
+                        on_click={ctx.link().callback(|_| Msg::UpdateAnnotationRemembering)}
+                    />
+                },
+            ),
+        ];
+
+        html! {
+            <Modal heading={tr(Text::Settings)} on_close={ctx.link().callback(|_| Msg::CloseModal)}>
+                <Table>
+                    <tbody>
+                        {
+                            for rows.into_iter().map(|(name, control)| html! {
+                                <TableRow>
+                                    <TableItem classes={classes!("whitespace-nowrap")}>{ tr(name) }</TableItem>
+                                    <TableItem>{ control }</TableItem>
+                                </TableRow>
+                            })
+                        }
+                    </tbody>
+                </Table>
+                <p class="text-xs">
+                    { tr(Text::SettingsRemembered) }
+                </p>
+            </Modal>
+        }
+    }
+
+    /// Debug logs that were loaded recently, to open them again.
+    fn view_history(&self, ctx: &Context<Self>) -> Html {
+        let entries = self.history.entries();
+        if entries.is_empty() {
+            return html! {};
+        }
+
+        let view_entry = |entry: &HistoryEntry| {
+            let url = entry.url.clone();
+            let on_label_change = ctx
+                .link()
+                .callback(move |label| Msg::UpdateHistoryLabel(url.clone(), label));
+            let url = entry.url.clone();
+            let on_open = ctx.link().batch_callback(move |_| {
+                vec![
+                    Msg::Log(Side::Main, LogMsg::UpdateUrl(url.clone())),
+                    Msg::Log(Side::Main, LogMsg::Start),
+                ]
+            });
+            let url = entry.url.clone();
+            let on_remove = ctx
+                .link()
+                .callback(move |_| Msg::RemoveFromHistory(url.clone()));
+
+            html! {
+                <TableRow>
+                    <TableItem>
+                        <Input
+                            classes={classes!("w-full", "py-1", "rounded-2xl", "border")}
+                            value={entry.label.clone()}
+                            placeholder={tr(Text::Label)}
+                            on_change={on_label_change}
+                        />
+                    </TableItem>
+                    <TableItem classes={classes!("whitespace-nowrap")}>{ entry.platform }</TableItem>
+                    <TableItem classes={classes!("whitespace-nowrap")}>{ entry.loaded_at() }</TableItem>
+                    <TableItem classes={classes!("break-all")}>{ &entry.url }</TableItem>
+                    <TableItem classes={classes!("whitespace-nowrap")}>
+                        <div class="flex gap-2">
+                            <Button
+                                classes={classes!("rounded-2xl")}
+                                size={ButtonSize::Small}
+                                icon={classes!("fas", "fa-folder-open")}
+                                text={tr(Text::Open)}
+                                on_click={on_open}
+                            />
+                            <Button
+                                classes={classes!("rounded-2xl")}
+                                size={ButtonSize::Small}
+                                icon={classes!("fas", "fa-trash")}
+                                title={tr(Text::Remove)}
+                                on_click={on_remove}
+                            />
+                        </div>
+                    </TableItem>
+                </TableRow>
+            }
+        };
+
+        html! {
+            <Message classes={classes!("mb-8")} heading={tr(Text::RecentlyOpened)}>
+                <Table>
+                    <tbody>
+                        { for entries.iter().map(view_entry) }
+                    </tbody>
+                </Table>
+            </Message>
+        }
+    }
+
+    /// A select for the platform of a URL that is only a key, which doesn't tell it.
+    fn view_platform_select(&self, side: Side, log: &DebugLog, ctx: &Context<Self>) -> Html {
+        if !needs_platform(&log.url) {
+            return html! {};
+        }
+
+        html! {
+            <select
+                title={tr(Text::ChoosePlatform)}
+                aria-label={tr(Text::ChoosePlatform)}
+                onchange={ctx.link().callback(move |event: Event| {
+                    let value = event.target().unwrap().dyn_into::<HtmlSelectElement>().unwrap().value();
+                    Msg::Log(side, LogMsg::UpdatePlatform(Platform::iter().find(|platform| platform.to_string() == value)))
+                })}
+                class={classes!(
+                    "border-y",
+                    "border-brand-border",
+                    "dark:border-brand-dark-border",
+                    "focus:outline-none",
+                    "focus:ring",
+                    "focus:ring-brand-focus",
+                    "dark:focus:ring-brand-dark-focus",
+                    "focus:ring-opacity-50",
+                    "transition",
+                    "duration-200",
+                    "bg-brand-bg-text-field",
+                    "dark:bg-brand-dark-bg-text-field",
+                )}
+            >
+                <option value="" selected={log.platform.is_none()}>{ tr(Text::ChoosePlatform) }</option>
+                {
+                    for Platform::iter().map(|platform| html! {
+                        <option value={platform.to_string()} selected={log.platform == Some(platform)}>
+                            { platform.to_string() }
+                        </option>
+                    })
+                }
+            </select>
+        }
+    }
+
+    /// A select for the number of entries to show before and after every search result.
+    fn view_context_lines_select(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <select
+                title={tr(Text::ContextLines)}
+                onchange={ctx.link().callback(|event: Event|
+                    Msg::UpdateContextLines(event.target().unwrap().dyn_into::<HtmlSelectElement>().unwrap().value())
+                )}
+                class={classes!(
+                    "rounded-2xl",
+                    "border-brand-border",
+                    "dark:border-brand-dark-border",
+                    "shadow-sm",
+                    "focus:border-brand-border",
+                    "dark:focus:border-brand-dark-border",
+                    "focus:ring",
+                    "focus:ring-brand-focus",
+                    "dark:focus:ring-brand-dark-focus",
+                    "focus:ring-opacity-50",
+                    "transition",
+                    "duration-200",
+                    "bg-brand-bg",
+                    "dark:bg-brand-dark-bg",
+                )}
+            >
+                {
+                    for (0..=MAX_CONTEXT_LINES).map(|lines| html! {
+                        <option value={lines.to_string()} selected={lines == self.settings.context_lines}>
+                            { format!("{}: {}", tr(Text::Context), lines) }
+                        </option>
+                    })
+                }
+            </select>
+        }
+    }
+
+    fn view_entry_jump_buttons(&self, ctx: &Context<Self>) -> Html {
+        if !self.main.state.is_ready() {
+            return html! {};
+        }
+
+        html! {
+            <div class="flex">
+                <Button
+                    on_click={ctx.link().callback(|_| Msg::SelectEntry(EntryJump::First, LogLevel::Error))}
+                    icon={classes!("fas", "fa-bug")}
+                    text={tr(Text::FirstError)}
+                    title={tr(Text::JumpToFirstError)}
+                />
+                <Button
+                    on_click={ctx.link().callback(|_| Msg::SelectEntry(EntryJump::Previous, LogLevel::Warn))}
+                    icon={classes!("fas", "fa-chevron-up")}
+                    title={tr(Text::PreviousWarning)}
+                />
+                <Button
+                    on_click={ctx.link().callback(|_| Msg::SelectEntry(EntryJump::Next, LogLevel::Warn))}
+                    icon={classes!("fas", "fa-chevron-down")}
+                    title={tr(Text::NextWarning)}
+                />
+            </div>
+        }
+    }
+
+    /// Input to go to the log entry of the main log with a number, as shown in the logs table.
+    fn view_entry_number_input(&self, ctx: &Context<Self>) -> Html {
+        if !self.main.state.is_ready() {
+            return html! {};
+        }
+
+        html! {
+            <div class="flex">
+                <Input
+                    classes={classes!("rounded-l-2xl", "w-40")}
+                    value={self.pending_entry_number.clone()}
+                    on_change={ctx.link().callback(Msg::UpdateEntryNumber)}
+                    on_submit_maybe={ctx.link().batch_callback(|actually: bool| actually.then_some(Msg::ApplyEntryNumber))}
+                    placeholder={tr(Text::GoToEntry)}
+                />
+                <Button
+                    classes={classes!("rounded-r-2xl")}
+                    on_click={ctx.link().callback(|_| Msg::ApplyEntryNumber)}
+                    icon={classes!("fas", "fa-arrow-right")}
+                />
+            </div>
+        }
+    }
+
+    fn view_section_scope_filter(&self, ctx: &Context<Self>) -> Html {
+        if !self.main.state.is_ready() {
+            return html! {};
+        }
+
+        let outline = self.main.active_file().outline(Tab::Logs);
+
+        if outline.len() < 2 {
+            return html! {};
+        }
+
+        let classes = classes!(
+            "max-w-[10rem]",
+            "border-brand-border",
+            "dark:border-brand-dark-border",
+            "!border-l-0",
+            "shadow-sm",
+            "focus:border-brand-border",
+            "dark:focus:border-brand-dark-border",
+            "focus:ring",
+            "focus:ring-brand-focus",
+            "dark:focus:ring-brand-dark-focus",
+            "focus:ring-opacity-50",
+            "transition",
+            "duration-200",
+            "bg-brand-bg",
+            "dark:bg-brand-dark-bg",
+        );
+
+        html! {
+            <select
+                onchange={ctx.link().callback(|event: Event|
+                    Msg::UpdateSectionScope(event.target().unwrap().dyn_into::<HtmlSelectElement>().unwrap().value())
+                )}
+                class={classes}
+            >
+                <option value="" selected={self.pending_query.section.is_empty()}>{ tr(Text::AllSections) }</option>
+                {
+                    for outline.iter().map(|entry| html! {
+                        <option value={entry.name.clone()} selected={self.pending_query.section == entry.name}>
+                            { format!("{}{}", "\u{a0}\u{a0}".repeat(entry.depth), entry.name) }
+                        </option>
+                    })
                 }
+            </select>
+        }
+    }
+
+    fn view_android_filters(&self, ctx: &Context<Self>) -> Html {
+        let any_android = self
+            .logs()
+            .filter_map(|(_, log)| log.remote_object.as_ref())
+            .any(|remote_object| remote_object.platform().is_android());
+
+        if !any_android {
+            return html! {};
+        }
+
+        let on_submit_maybe = ctx.link().batch_callback(|actually: bool| {
+            if actually {
+                Some(Msg::ApplySearchQuery)
+            } else {
+                None
             }
-            _ => html! {},
+        });
+
+        html! {
+            <>
+                <Input
+                    classes={classes!("!grow-0", "w-28")}
+                    value={self.pending_query.tag.clone()}
+                    on_change={ctx.link().callback(Msg::UpdateTagFilter)}
+                    on_submit_maybe={on_submit_maybe.clone()}
+                    placeholder={tr(Text::Tag)}
+                />
+                <Input
+                    classes={classes!("!grow-0", "w-24")}
+                    value={self.pending_query.thread.clone()}
+                    on_change={ctx.link().callback(Msg::UpdateThreadFilter)}
+                    {on_submit_maybe}
+                    placeholder={tr(Text::Thread)}
+                />
+            </>
+        }
+    }
+
+    fn view_desktop_filters(&self, ctx: &Context<Self>) -> Html {
+        let any_desktop = self
+            .logs()
+            .filter_map(|(_, log)| log.remote_object.as_ref())
+            .any(|remote_object| remote_object.platform().is_desktop());
+
+        if !any_desktop {
+            return html! {};
+        }
+
+        let classes = classes!(
+            "border-brand-border",
+            "dark:border-brand-dark-border",
+            "!border-l-0",
+            "shadow-sm",
+            "focus:border-brand-border",
+            "dark:focus:border-brand-dark-border",
+            "focus:ring",
+            "focus:ring-brand-focus",
+            "dark:focus:ring-brand-dark-focus",
+            "focus:ring-opacity-50",
+            "transition",
+            "duration-200",
+            "bg-brand-bg",
+            "dark:bg-brand-dark-bg",
+        );
+
+        html! {
+            <select
+                onchange={ctx.link().callback(|event: Event|
+                    Msg::UpdateProcessFilter(event.target().unwrap().dyn_into::<HtmlSelectElement>().unwrap().value())
+                )}
+                class={classes}
+            >
+                <option value="" selected={self.pending_query.process.is_empty()}>{ tr(Text::AllProcesses) }</option>
+                {
+                    for parsers::DESKTOP_PROCESSES.iter().map(|process| html! {
+                        <option value={*process} selected={self.pending_query.process == *process}>{ process }</option>
+                    })
+                }
+            </select>
         }
     }
 
@@ -239,23 +1622,83 @@ impl super::Model {
                 size={ButtonSize::Medium}
                 on_click={ctx.link().callback(move |_| Msg::UpdateTab(tab))}
                 active={self.tab == tab}
+                role="tab"
                 icon={tab.icon()}
-                text={tab.to_string()}
+                text={tab.label()}
             />
         }
     }
 
-    pub fn view_footer(&self) -> Html {
+    pub fn view_footer(&self, ctx: &Context<Self>) -> Html {
         html! {
-            <footer class="bg-brand-bg-footer dark:bg-brand-dark-bg-footer mb-24 px-8 pb-12 pt-6 text-center">
+            <footer class="bg-brand-bg-footer dark:bg-brand-dark-bg-footer mb-24 px-8 pb-12 pt-6 text-center print:hidden">
                 <article class="prose dark:prose-invert prose-sm mx-auto">
                     <p>
                         <Link href="https://github.com/u32i64/readlogs" text="Readlogs" no_referrer={false} no_follow={false}/>
-                        { " is an unofficial project. It is not affiliated with the Signal Technology Foundation or Signal Messenger, LLC." }
+                        { tr(Text::Unofficial) }
                     </p>
                     <p><code>{ env!("VERGEN_GIT_SHA_SHORT") }</code></p>
+                    <Button
+                        classes={classes!("rounded-2xl")}
+                        size={ButtonSize::Small}
+                        on_click={ctx.link().callback(|_| Msg::UpdateSettingsShown)}
+                        icon={classes!("fas", "fa-cog")}
+                        text={tr(Text::Settings)}
+                    />
                 </article>
             </footer>
         }
     }
 }
+
+/// Focuses and selects the tab before or after the focused one of a tab list with the left and
+/// right arrow keys, wrapping around at either end.
+fn select_adjacent_tab(event: KeyboardEvent) {
+    let tab = match event.target_dyn_into::<Element>() {
+        Some(tab) => tab,
+        None => return,
+    };
+
+    let adjacent = match event.key().as_str() {
+        "ArrowLeft" => tab
+            .previous_element_sibling()
+            .or_else(|| tab.parent_element()?.last_element_child()),
+        "ArrowRight" => tab
+            .next_element_sibling()
+            .or_else(|| tab.parent_element()?.first_element_child()),
+        _ => return,
+    };
+
+    if let Some(adjacent) = adjacent.and_then(|adjacent| adjacent.dyn_into::<HtmlElement>().ok()) {
+        event.prevent_default();
+        let _ = adjacent.focus();
+        adjacent.click();
+    }
+}
+
+/// How much of a debug log was downloaded, with a bar if its size is known.
+fn view_progress(progress: &fetch::Progress) -> Html {
+    let size = match progress.total() {
+        Some(total) => format!(
+            "{} / {} ({} %)",
+            format_size(progress.received),
+            format_size(total),
+            progress.percent().unwrap_or_default()
+        ),
+        None => format_size(progress.received),
+    };
+
+    html! {
+        <p>
+            { format!("{} {}", tr(Text::Downloaded), size) }
+            {
+                match progress.total() {
+                    Some(total) => html! {
+                        <progress class="block w-full" max={total.to_string()} value={progress.received.to_string()} />
+                    },
+                    None => html! {},
+                }
+            }
+        </p>
+    }
+}
@@ -1,4 +1,5 @@
 const defaultTheme = require("tailwindcss/defaultTheme");
+const plugin = require("tailwindcss/plugin");
 const colors = require("tailwindcss/colors");
 
 const baseColor = colors.gray;
@@ -8,9 +9,15 @@ module.exports = {
     "./index.html",
     "./src/**/*.rs",
   ],
+  darkMode: "class",
   plugins: [
     require("@tailwindcss/typography"),
     require("@tailwindcss/forms"),
+    // Alternative colors for log levels, used when the `colorblind` class is set on the page.
+    plugin(({ addVariant }) => {
+      addVariant("colorblind", ".colorblind &");
+      addVariant("dark-colorblind", ".dark.colorblind &");
+    }),
   ],
   theme: {
     extend: {